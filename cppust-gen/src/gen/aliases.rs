@@ -0,0 +1,282 @@
+use std::io::Write;
+
+use syn::{GenericParam, ItemType};
+
+use super::{
+    config::{Config, UserAlias},
+    docs::Docs,
+    enum_hdr,
+    error::{Error, Result},
+    types,
+};
+
+impl UserAlias {
+    /// The alias declared by `item` into `header`, generic parameters other than types without
+    /// default have no alias template counterpart
+    pub fn of(item: &ItemType, namespace: &[String], header: &str) -> Result<Self> {
+        let params: Result<Vec<_>> = item
+            .generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Type(param) if param.default.is_none() => Ok(param.ident.to_string()),
+                GenericParam::Type(param) => Err(Error::TypeAliasError(
+                    item.ident.to_string(),
+                    format!(
+                        "default of generic parameter {} is not supported",
+                        param.ident
+                    ),
+                )),
+                GenericParam::Lifetime(param) => Err(Error::TypeAliasError(
+                    item.ident.to_string(),
+                    format!("lifetime parameter {} is not supported", param.lifetime),
+                )),
+                GenericParam::Const(param) => Err(Error::TypeAliasError(
+                    item.ident.to_string(),
+                    format!("const parameter {} is not supported", param.ident),
+                )),
+            })
+            .collect();
+
+        Ok(Self {
+            namespace: namespace.to_vec(),
+            header: header.to_owned(),
+            params: params?,
+            ty: (*item.ty).clone(),
+        })
+    }
+}
+
+/// Orders `items` so that each comes after the aliases it refers to, as Rust allows referring
+/// to aliases declared later
+pub fn order<'a>(items: &[&'a ItemType]) -> Vec<&'a ItemType> {
+    let mut pending: Vec<_> = items.to_vec();
+    let mut ordered = Vec::with_capacity(items.len());
+
+    while !pending.is_empty() {
+        let index = pending
+            .iter()
+            .position(|item| {
+                !pending.iter().any(|other| {
+                    other.ident != item.ident
+                        && types::type_uses_type(&item.ty, &other.ident.to_string())
+                })
+            })
+            // cycles are rejected by rustc
+            .unwrap_or(0);
+        ordered.push(pending.remove(index));
+    }
+
+    ordered
+}
+
+/// Writes `<module>_types.hpp`
+pub fn write(f: &mut dyn Write, items: &[&ItemType], config: &Config) -> Result<()> {
+    let namespace = &config.namespace;
+
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+
+    writeln!(f, "#pragma once")?;
+    writeln!(f)?;
+
+    write!(f, "{}", config.library_defines())?;
+    write_includes(f, items)?;
+
+    // generated classes only need to be declared, their headers include this one
+    let mut classes: Vec<_> = config
+        .classes
+        .keys()
+        .filter(|ident| {
+            items
+                .iter()
+                .any(|item| types::type_uses_type(&item.ty, ident))
+        })
+        .collect();
+    classes.sort();
+    for ident in classes.iter() {
+        enum_hdr::write_class_declaration(f, ident, config)?;
+    }
+    if !classes.is_empty() {
+        writeln!(f)?;
+    }
+
+    if !namespace.is_empty() {
+        writeln!(
+            f,
+            "{}",
+            namespace
+                .iter()
+                .map(|ns| format!("namespace {} {{", ns))
+                .collect::<Vec<String>>()
+                .join(" ")
+        )?;
+        writeln!(f)?;
+    }
+
+    write_declarations(f, items, config)?;
+
+    if !namespace.is_empty() {
+        writeln!(
+            f,
+            "{}",
+            namespace
+                .iter()
+                .map(|_| "}".to_owned())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The library and standard headers of the types the aliases stand for
+pub fn write_includes(f: &mut dyn Write, items: &[&ItemType]) -> Result<()> {
+    let uses_type = |ident: &str| {
+        items
+            .iter()
+            .any(|item| types::type_uses_type(&item.ty, ident))
+    };
+
+    writeln!(f, "#include <cppust/types.hpp>")?;
+    if uses_type("Option") {
+        writeln!(f, "#include <cppust/option.hpp>")?;
+    }
+    if uses_type("Result") {
+        writeln!(f, "#include <cppust/result.hpp>")?;
+    }
+    if uses_type("Vec") {
+        writeln!(f, "#include <cppust/vec.hpp>")?;
+    }
+    if items.iter().any(|item| types::type_uses_array(&item.ty)) {
+        writeln!(f, "#include <cppust/array.hpp>")?;
+    }
+    writeln!(f)?;
+
+    if uses_type("String") {
+        writeln!(f, "#include <string>")?;
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+/// The `using` declarations and alias templates of `items`, inside their namespace
+pub fn write_declarations(f: &mut dyn Write, items: &[&ItemType], config: &Config) -> Result<()> {
+    for item in order(items) {
+        let ident = item.ident.to_string();
+        let alias = &config.aliases[&ident];
+        let config = Config {
+            type_params: alias.params.clone(),
+            ..config.clone()
+        };
+
+        Docs::of_item(&item.attrs, &config).write(f, "")?;
+        if !alias.params.is_empty() {
+            writeln!(
+                f,
+                "template <{}>",
+                alias
+                    .params
+                    .iter()
+                    .map(|param| format!("typename {}", param))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        writeln!(
+            f,
+            "using {} = {};",
+            config.naming.class(&ident.as_str().into()),
+            types::type_to_cpp_type(&item.ty, &config)?
+        )?;
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use syn::{Item, ItemType};
+
+    use super::*;
+
+    fn type_items(file: &syn::File) -> Vec<&ItemType> {
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Type(item) => Some(item),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn translate(code: &str) -> Vec<String> {
+        let file = syn::parse_file(code).unwrap();
+        let items = type_items(&file);
+        let config = Config {
+            namespace: vec!["t".to_owned()],
+            aliases: items
+                .iter()
+                .map(|item| {
+                    let alias = UserAlias::of(item, &["t".to_owned()], "t/a_types.hpp");
+                    (item.ident.to_string(), alias.unwrap())
+                })
+                .collect::<HashMap<_, _>>(),
+            ..Config::default()
+        };
+
+        let mut output = Vec::new();
+        write_declarations(&mut output, &items, &config).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(
+            translate(
+                r#"
+                /// Parsed or failed
+                type Parsed<T> = Res<Option<T>, Id>;
+                type Res<T, E> = Result<T, E>;
+                type Id = u16;
+                type Payload = Vec<Id>;
+                "#
+            ),
+            vec![
+                "template <typename T, typename E>",
+                "using Res = ::cppust::Result<T, E>;",
+                "using Id = ::cppust::u16;",
+                "/// @brief Parsed or failed",
+                "template <typename T>",
+                "using Parsed = Res<::cppust::Option<T>, Id>;",
+                "using Payload = std::vector<Id>;",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_aliases() {
+        for code in [
+            "type Str<'a> = &'a str;",
+            "type Buf<const N: usize> = Vec<u8>;",
+            "type Or<T = u8> = Option<T>;",
+        ] {
+            let file = syn::parse_file(code).unwrap();
+            let item = type_items(&file)[0];
+            assert!(UserAlias::of(item, &[], "a_types.hpp").is_err(), "{}", code);
+        }
+    }
+}
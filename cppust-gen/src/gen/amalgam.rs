@@ -0,0 +1,303 @@
+use std::io::Write;
+
+use syn::{ItemEnum, ItemType};
+
+use super::{
+    aliases, attrs,
+    config::Config,
+    consts::{self, Constant},
+    docs::Docs,
+    enum_hdr, enum_impl,
+    error::{Error, Result},
+    types,
+};
+
+/// Orders `items` so that every enum comes after the enums its payloads refer to, otherwise
+/// keeping the order of the input
+pub fn order<'a, 'b>(
+    items: &'b [(&'a ItemEnum, Config)],
+) -> Result<Vec<&'b (&'a ItemEnum, Config)>> {
+    let mut pending: Vec<_> = items.iter().collect();
+    let mut ordered = Vec::with_capacity(items.len());
+
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|(item, config)| {
+            types::user_types(item, config).iter().all(|ident| {
+                !pending
+                    .iter()
+                    .any(|(other, _)| other.ident == ident.as_str())
+            })
+        });
+
+        match ready {
+            Some(index) => ordered.push(pending.remove(index)),
+            None => {
+                return Err(Error::ConfigError(
+                    "amalgamate".to_owned(),
+                    format!(
+                        "{} store each other by value and cannot be ordered",
+                        pending
+                            .iter()
+                            .map(|(item, _)| item.ident.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ))
+            }
+        }
+    }
+
+    Ok(ordered)
+}
+
+/// Writes `<name>.hpp` holding the classes of all `items`, which must be ordered and share a
+/// namespace, along with the constants and type aliases of the input
+pub fn write_header(
+    f: &mut dyn Write,
+    items: &[&(&ItemEnum, Config)],
+    constants: &[Constant],
+    type_items: &[&ItemType],
+    config: &Config,
+) -> Result<()> {
+    let namespace = &config.namespace;
+
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+
+    writeln!(f, "#pragma once")?;
+    writeln!(f)?;
+
+    write!(f, "{}", config.library_defines())?;
+    write_includes(f, items, constants, type_items, config)?;
+
+    open_namespace(f, namespace)?;
+
+    // declared up front so that user code and references between payloads never depend on the
+    // order of the definitions
+    for (item, config) in items.iter().copied() {
+        writeln!(
+            f,
+            "class {};",
+            config.naming.class(&attrs::enum_name(item)?)
+        )?;
+    }
+    writeln!(f)?;
+
+    aliases::write_declarations(f, type_items, config)?;
+    consts::write_constants(f, constants, config)?;
+
+    for (item, config) in items.iter().copied() {
+        Docs::of(&item.attrs, item, config).write(f, "")?;
+        writeln!(
+            f,
+            "class {} {{",
+            config.naming.class(&attrs::enum_name(item)?)
+        )?;
+        enum_hdr::write_class_body(f, item, config)?;
+        writeln!(f, "}};")?;
+        writeln!(f)?;
+    }
+
+    // inline definitions follow all classes, as members may use any of them
+    for (item, config) in items
+        .iter()
+        .copied()
+        .filter(|(_, config)| config.header_only)
+    {
+        enum_impl::write_members(f, item, config)?;
+        writeln!(f)?;
+    }
+
+    close_namespace(f, namespace)?;
+
+    for (item, config) in items.iter().copied() {
+        enum_hdr::write_fmt_body(f, item, config)?;
+    }
+
+    Ok(())
+}
+
+/// Writes `<name>.cpp` defining the members of the `items` which are not header-only
+pub fn write_source(
+    f: &mut dyn Write,
+    name: &str,
+    items: &[&(&ItemEnum, Config)],
+    config: &Config,
+) -> Result<()> {
+    let namespace = &config.namespace;
+
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+
+    writeln!(
+        f,
+        "#include \"{}.hpp\"",
+        namespace
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(name))
+            .collect::<Vec<_>>()
+            .join("/")
+    )?;
+    writeln!(f)?;
+
+    open_namespace(f, namespace)?;
+    for (item, config) in items
+        .iter()
+        .copied()
+        .filter(|(_, config)| !config.header_only)
+    {
+        enum_impl::write_members(f, item, config)?;
+        writeln!(f)?;
+    }
+    close_namespace(f, namespace)?;
+
+    Ok(())
+}
+
+/// The library and standard includes of all `items`, `constants` and `type_items`, each once,
+/// the generated headers are replaced by the declarations of the same file
+fn write_includes(
+    f: &mut dyn Write,
+    items: &[&(&ItemEnum, Config)],
+    constants: &[Constant],
+    type_items: &[&ItemType],
+    config: &Config,
+) -> Result<()> {
+    let mut cppust_includes: Vec<String> = Vec::new();
+    let mut std_includes: Vec<String> = Vec::new();
+
+    let mut includes = Vec::new();
+    consts::write_includes(&mut includes, constants, config)?;
+    aliases::write_includes(&mut includes, type_items)?;
+    for (item, config) in items.iter().copied() {
+        enum_hdr::write_includes(&mut includes, item, config)?;
+    }
+
+    for line in String::from_utf8_lossy(&includes).lines() {
+        let group = if line.starts_with("#include <cppust/") {
+            &mut cppust_includes
+        } else if line.starts_with("#include <") {
+            &mut std_includes
+        } else {
+            continue;
+        };
+        if !group.iter().any(|include| include == line) {
+            group.push(line.to_owned());
+        }
+    }
+
+    for group in [cppust_includes, std_includes] {
+        for include in group.iter() {
+            writeln!(f, "{}", include)?;
+        }
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn open_namespace(f: &mut dyn Write, namespace: &[String]) -> Result<()> {
+    if !namespace.is_empty() {
+        writeln!(
+            f,
+            "{}",
+            namespace
+                .iter()
+                .map(|ns| format!("namespace {} {{", ns))
+                .collect::<Vec<String>>()
+                .join(" ")
+        )?;
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn close_namespace(f: &mut dyn Write, namespace: &[String]) -> Result<()> {
+    if !namespace.is_empty() {
+        writeln!(
+            f,
+            "{}",
+            namespace
+                .iter()
+                .map(|_| "}".to_owned())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )?;
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::gen::config::UserClass;
+
+    fn items(code: &str) -> Vec<(ItemEnum, Config)> {
+        let file = syn::parse_file(code).unwrap();
+        let items: Vec<ItemEnum> = file
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                syn::Item::Enum(item) => Some(item),
+                _ => None,
+            })
+            .collect();
+
+        let mut config = Config::default();
+        for item in items.iter() {
+            config.classes.insert(
+                item.ident.to_string(),
+                UserClass {
+                    namespace: Vec::new(),
+                    name: (&item.ident).into(),
+                    layout: false,
+                    pod: false,
+                },
+            );
+        }
+
+        items
+            .into_iter()
+            .map(|item| (item, config.clone()))
+            .collect()
+    }
+
+    fn order_of(code: &str) -> Result<Vec<String>> {
+        let items = items(code);
+        let items: Vec<_> = items
+            .iter()
+            .map(|(item, config)| (item, config.clone()))
+            .collect();
+        Ok(order(&items)?
+            .iter()
+            .map(|(item, _)| item.ident.to_string())
+            .collect())
+    }
+
+    #[test]
+    fn test_order() {
+        assert_eq!(
+            order_of(
+                "enum A { B(B), C(Vec<C>) } enum B { D(D) } enum C { X } enum D { Y, Z(Vec<D>) }"
+            )
+            .unwrap(),
+            vec!["C", "D", "B", "A"]
+        );
+        assert_eq!(
+            order_of("enum A { X } enum B { Y }").unwrap(),
+            vec!["A", "B"]
+        );
+        assert!(order_of("enum A { B(B) } enum B { A(Option<A>) }").is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use syn::{Attribute, Field, ImplItemMethod, ItemEnum, Lit, Meta, NestedMeta, Variant};
+
+use super::{
+    error::{Error, Result},
+    names::IdentName,
+};
+
+/// Generation options from `#[cppust(...)]` on an enum
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnumAttrs {
+    /// `skip`, nothing is generated for the enum
+    pub skip: bool,
+    /// `rename = "..."`, the name styled into the class and file names instead of the Rust ident
+    pub rename: Option<String>,
+    /// `namespace = "a::b"`, replaces the namespace given to the generator
+    pub namespace: Option<Vec<String>>,
+    /// `header_only`, members are defined `inline` in `<file>.impl.hpp` instead of `<file>.gen.cpp`
+    pub header_only: bool,
+    /// `layout`, the class follows the `#[repr(C)]` or `#[repr(C, Int)]` layout of the enum
+    pub layout: bool,
+}
+
+impl EnumAttrs {
+    pub fn of(item: &ItemEnum) -> Result<Self> {
+        let mut attrs = Self::default();
+
+        for (key, value) in cppust_attrs(
+            &item.attrs,
+            "enum",
+            &["skip", "rename", "namespace", "header_only", "layout"],
+        )? {
+            match (key.as_str(), value) {
+                ("skip", None) => attrs.skip = true,
+                ("header_only", None) => attrs.header_only = true,
+                ("layout", None) => attrs.layout = true,
+                ("rename", Some(value)) => attrs.rename = Some(identifier(&key, value)?),
+                ("namespace", Some(value)) => {
+                    let namespace = value
+                        .split("::")
+                        .map(|ns| identifier(&key, ns.to_owned()))
+                        .collect::<Result<Vec<_>>>()?;
+                    attrs.namespace = Some(namespace);
+                }
+                (_, value) => return Err(misused(&key, value.is_some())),
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Generation options from `#[cppust(...)]` on a variant
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VariantAttrs {
+    /// `rename = "..."`, the name styled into the factory, accessors and tag instead of the Rust
+    /// ident, `Debug` still prints the Rust name
+    pub rename: Option<String>,
+}
+
+impl VariantAttrs {
+    pub fn of(variant: &Variant) -> Result<Self> {
+        let mut attrs = Self::default();
+
+        for (key, value) in cppust_attrs(&variant.attrs, "variant", &["rename"])? {
+            match (key.as_str(), value) {
+                ("rename", Some(value)) => attrs.rename = Some(identifier(&key, value)?),
+                (_, value) => return Err(misused(&key, value.is_some())),
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Generation options from `#[cppust(...)]` on a field
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldAttrs {
+    /// `type = "std::span<const u8>"`, the C++ type used verbatim for the field, which must be
+    /// declared by the includes of `<file>.hpp`
+    pub ty: Option<String>,
+}
+
+impl FieldAttrs {
+    pub fn of(field: &Field) -> Result<Self> {
+        let mut attrs = Self::default();
+
+        for (key, value) in cppust_attrs(&field.attrs, "field", &["type"])? {
+            match (key.as_str(), value) {
+                ("type", Some(value)) if value.trim().is_empty() => {
+                    return Err(Error::AttrError(key, "must not be empty".to_owned()))
+                }
+                ("type", Some(value)) => attrs.ty = Some(value.trim().to_owned()),
+                (_, value) => return Err(misused(&key, value.is_some())),
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Generation options from `#[cppust(...)]` on a method of an inherent `impl` block
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MethodAttrs {
+    /// `skip`, the method is not declared
+    pub skip: bool,
+    /// `delete`, the method is declared `= delete`
+    pub delete: bool,
+}
+
+impl MethodAttrs {
+    pub fn of(method: &ImplItemMethod) -> Result<Self> {
+        let mut attrs = Self::default();
+
+        for (key, value) in cppust_attrs(&method.attrs, "method", &["skip", "delete"])? {
+            match (key.as_str(), value) {
+                ("skip", None) => attrs.skip = true,
+                ("delete", None) => attrs.delete = true,
+                (_, value) => return Err(misused(&key, value.is_some())),
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Validates the `#[cppust(...)]` attributes of an enum, its variants and their fields
+pub fn validate(item: &ItemEnum) -> Result<()> {
+    EnumAttrs::of(item)?;
+    for variant in item.variants.iter() {
+        VariantAttrs::of(variant)?;
+        for field in variant.fields.iter() {
+            FieldAttrs::of(field)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Base name of the generated class of `item`
+pub fn enum_name(item: &ItemEnum) -> Result<IdentName> {
+    Ok(match EnumAttrs::of(item)?.rename {
+        Some(rename) => rename.as_str().into(),
+        None => (&item.ident).into(),
+    })
+}
+
+/// Base name of the generated factory, accessors and tag of `variant`
+pub fn variant_name(variant: &Variant) -> Result<IdentName> {
+    Ok(match VariantAttrs::of(variant)?.rename {
+        Some(rename) => rename.as_str().into(),
+        None => (&variant.ident).into(),
+    })
+}
+
+/// `key` or `key = "value"` entries of all `#[cppust(...)]` attributes, in order
+fn cppust_attrs(
+    attrs: &[Attribute],
+    owner: &str,
+    allowed: &[&str],
+) -> Result<Vec<(String, Option<String>)>> {
+    let mut entries: Vec<(String, Option<String>)> = Vec::new();
+
+    for attr in attrs.iter().filter(|a| a.path.is_ident("cppust")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            _ => {
+                return Err(Error::AttrError(
+                    "cppust".to_owned(),
+                    "expected #[cppust(key, key = \"value\", ...)]".to_owned(),
+                ))
+            }
+        };
+
+        for nested in list.nested.iter() {
+            let (path, value) = match nested {
+                NestedMeta::Meta(Meta::Path(path)) => (path, None),
+                NestedMeta::Meta(Meta::NameValue(meta)) => match &meta.lit {
+                    Lit::Str(value) => (&meta.path, Some(value.value())),
+                    _ => {
+                        return Err(Error::AttrError(
+                            "cppust".to_owned(),
+                            "values must be string literals".to_owned(),
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(Error::AttrError(
+                        "cppust".to_owned(),
+                        "expected key or key = \"value\"".to_owned(),
+                    ))
+                }
+            };
+
+            let key = path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            if !allowed.contains(&key.as_str()) {
+                return Err(Error::AttrError(
+                    key,
+                    format!(
+                        "unknown key on {}, use one of {}",
+                        owner,
+                        allowed.join(", ")
+                    ),
+                ));
+            }
+            if entries.iter().any(|(k, _)| *k == key) {
+                return Err(Error::AttrError(key, "given more than once".to_owned()));
+            }
+            entries.push((key, value));
+        }
+    }
+
+    Ok(entries)
+}
+
+fn identifier(key: &str, value: String) -> Result<String> {
+    let valid = !value.is_empty()
+        && !value.starts_with(|c: char| c.is_ascii_digit())
+        && value.chars().all(|c| c == '_' || c.is_ascii_alphanumeric());
+
+    if valid {
+        Ok(value)
+    } else {
+        Err(Error::AttrError(
+            key.to_owned(),
+            format!("{} is not a valid identifier", value),
+        ))
+    }
+}
+
+fn misused(key: &str, has_value: bool) -> Error {
+    let reason = if has_value {
+        "takes no value"
+    } else {
+        "requires a value, e.g. key = \"...\""
+    };
+    Error::AttrError(key.to_owned(), reason.to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_enum_attrs() {
+        let item: ItemEnum = syn::parse_str(
+            r#"#[cppust(rename = "Status", namespace = "nmea::v2")]
+               #[cppust(header_only, layout)] enum E { A }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            EnumAttrs::of(&item).unwrap(),
+            EnumAttrs {
+                skip: false,
+                rename: Some("Status".to_owned()),
+                namespace: Some(vec!["nmea".to_owned(), "v2".to_owned()]),
+                header_only: true,
+                layout: true,
+            }
+        );
+        assert_eq!(enum_name(&item).unwrap(), "Status".into());
+
+        let item: ItemEnum = syn::parse_str("#[derive(Debug)] enum E { A }").unwrap();
+        assert_eq!(EnumAttrs::of(&item).unwrap(), EnumAttrs::default());
+    }
+
+    #[test]
+    fn test_invalid_attrs() {
+        for code in [
+            "#[cppust(unknown)] enum E { A }",
+            "#[cppust(type = \"int\")] enum E { A }",
+            "#[cppust(skip = \"yes\")] enum E { A }",
+            "#[cppust(layout = \"C\")] enum E { A }",
+            "#[cppust(rename)] enum E { A }",
+            "#[cppust(rename = \"two words\")] enum E { A }",
+            "#[cppust(namespace = \"a::\")] enum E { A }",
+            "#[cppust(skip, skip)] enum E { A }",
+            "#[cppust = \"skip\"] enum E { A }",
+            "#[cppust(rename = 1)] enum E { A }",
+            "enum E { #[cppust(skip)] A }",
+            "enum E { A(#[cppust(rename = \"B\")] u8) }",
+            "enum E { A(#[cppust(type = \"\")] u8) }",
+        ] {
+            let item: ItemEnum = syn::parse_str(code).unwrap();
+            assert!(validate(&item).is_err(), "{}", code);
+        }
+
+        let item: ItemEnum = syn::parse_str(
+            r#"enum E { #[cppust(rename = "Raw")] A(#[cppust(type = "std::span<const u8>")] Vec<u8>) }"#,
+        )
+        .unwrap();
+        assert!(validate(&item).is_ok());
+        assert_eq!(variant_name(&item.variants[0]).unwrap(), "Raw".into());
+        assert_eq!(
+            FieldAttrs::of(item.variants[0].fields.iter().next().unwrap())
+                .unwrap()
+                .ty,
+            Some("std::span<const u8>".to_owned())
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::{collections::HashMap, str::FromStr};
+
+use syn::{ImplItemMethod, Type};
+
+use super::{
+    error::{Error, Result},
+    names::{IdentName, NameKind, Naming},
+};
+
+/// The C++ standard the generated code targets
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CppStd {
+    Cpp11,
+    Cpp14,
+    Cpp17,
+    Cpp20,
+}
+
+impl CppStd {
+    /// Value of `__cplusplus` for this standard
+    pub fn cplusplus(&self) -> &'static str {
+        match self {
+            CppStd::Cpp11 => "201103L",
+            CppStd::Cpp14 => "201402L",
+            CppStd::Cpp17 => "201703L",
+            CppStd::Cpp20 => "202002L",
+        }
+    }
+}
+
+impl FromStr for CppStd {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim_start_matches("c++") {
+            "11" => Ok(CppStd::Cpp11),
+            "14" => Ok(CppStd::Cpp14),
+            "17" => Ok(CppStd::Cpp17),
+            "20" => Ok(CppStd::Cpp20),
+            _ => Err(Error::ConfigError(
+                "cpp_std".to_owned(),
+                format!("unsupported C++ standard {}, use 11, 14, 17 or 20", s),
+            )),
+        }
+    }
+}
+
+/// How the value of the generated class is stored
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// A hand-rolled tagged union, available for every standard
+    Union,
+    /// A wrapped `std::variant`, requires C++17
+    Variant,
+}
+
+impl FromStr for Backend {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "union" => Ok(Backend::Union),
+            "variant" => Ok(Backend::Variant),
+            _ => Err(Error::ConfigError(
+                "backend".to_owned(),
+                format!("unknown backend {}, use union or variant", s),
+            )),
+        }
+    }
+}
+
+/// A class generated from an enum of the input, which payloads may refer to
+#[derive(Clone, Debug)]
+pub struct UserClass {
+    pub namespace: Vec<String>,
+    pub name: IdentName,
+    /// Whether the class follows the Rust layout of the enum, see `#[cppust(layout)]`
+    pub layout: bool,
+    /// Whether the class is trivially copyable, see `pod::is_pod`
+    pub pod: bool,
+}
+
+/// The `#[repr(...)]` of an enum marked `#[cppust(layout)]`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Repr {
+    /// Rust integer type of the tag, `None` for the C `int` of a plain `#[repr(C)]`
+    pub tag: Option<String>,
+}
+
+/// A `type` alias of the input, which payloads may refer to
+#[derive(Clone, Debug)]
+pub struct UserAlias {
+    pub namespace: Vec<String>,
+    /// Path of the header declaring the alias, relative to the header directory
+    pub header: String,
+    /// Generic parameters, e.g. `T` of `type Res<T> = Result<T, Error>;`
+    pub params: Vec<String>,
+    pub ty: Type,
+}
+
+/// Options shared by all the writers
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub namespace: Vec<String>,
+    pub cpp_std: CppStd,
+    pub backend: Backend,
+    /// When disabled, checked accessors call `CPPUST_PANIC(msg)` instead of throwing
+    pub exceptions: bool,
+    /// When disabled, generated headers do not include `<iostream>`
+    pub iostream: bool,
+    pub naming: Naming,
+    /// Members are defined `inline` in a header instead of a source file
+    pub header_only: bool,
+    /// Headers are generated whole instead of including `<file>.inc.hpp` into the class, user
+    /// code goes into regions which are kept when regenerating
+    pub user_regions: bool,
+    /// Classes generated from the enums of the input, by Rust ident
+    pub classes: HashMap<String, UserClass>,
+    /// Type aliases of the input, by Rust ident
+    pub aliases: HashMap<String, UserAlias>,
+    /// Generic parameters in scope, which are written as is
+    pub type_params: Vec<String>,
+    /// Methods of the inherent `impl` blocks of the enum, declared into its class
+    pub methods: Vec<ImplItemMethod>,
+    /// Rust layout the class follows, tag first, when the enum is marked `#[cppust(layout)]`
+    pub repr: Option<Repr>,
+    /// All payloads are trivially copyable, so the class is too, with `constexpr` factories and
+    /// accessors defined in the header
+    pub pod: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            namespace: Vec::new(),
+            cpp_std: CppStd::Cpp11,
+            backend: Backend::Union,
+            exceptions: true,
+            iostream: true,
+            naming: Naming::default(),
+            header_only: false,
+            user_regions: false,
+            classes: HashMap::new(),
+            aliases: HashMap::new(),
+            type_params: Vec::new(),
+            methods: Vec::new(),
+            repr: None,
+            pod: false,
+        }
+    }
+}
+
+impl Config {
+    /// `ns1::ns2::` prefix to refer to generated types from the global namespace
+    pub fn namespace_prefix(&self) -> String {
+        self.namespace
+            .iter()
+            .map(|ns| format!("{}::", ns))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    /// Styled class name of the user type `ident`, which may be renamed by `#[cppust(rename)]`
+    pub fn class_name(&self, ident: &str) -> String {
+        match self.classes.get(ident) {
+            Some(class) => self.naming.class(&class.name),
+            None => self.naming.class(&ident.into()),
+        }
+    }
+
+    /// C++ name of the user type `ident`, qualified when it is generated into another namespace
+    pub fn class_ref(&self, ident: &str) -> String {
+        match self.classes.get(ident) {
+            Some(class) if class.namespace != self.namespace => format!(
+                "::{}::{}",
+                class.namespace.join("::"),
+                self.class_name(ident)
+            ),
+            _ => self.class_name(ident),
+        }
+    }
+
+    /// C++ name of the user type `ident` qualified from the global namespace, e.g. `::a::Status`
+    pub fn global_class_ref(&self, ident: &str) -> String {
+        match self.classes.get(ident) {
+            Some(class) if !class.namespace.is_empty() => format!(
+                "::{}::{}",
+                class.namespace.join("::"),
+                self.class_name(ident)
+            ),
+            _ => format!("::{}", self.class_name(ident)),
+        }
+    }
+
+    /// C++ name of the type alias `ident`, qualified when it is declared in another namespace
+    pub fn alias_ref(&self, ident: &str) -> String {
+        let name = self.naming.class(&ident.into());
+        match self.aliases.get(ident) {
+            Some(alias) if alias.namespace != self.namespace => {
+                format!("::{}::{}", alias.namespace.join("::"), name)
+            }
+            _ => name,
+        }
+    }
+
+    /// Specifier of member definitions, which are `inline` in header-only mode
+    pub fn inline(&self) -> &'static str {
+        if self.header_only {
+            "inline "
+        } else {
+            ""
+        }
+    }
+
+    /// Macros the generated headers define before including the library, `--no-iostream` keeps
+    /// `<iostream>` out of the library headers as well
+    pub fn library_defines(&self) -> &'static str {
+        if self.iostream {
+            ""
+        } else {
+            "#ifndef CPPUST_NO_IOSTREAM\n#define CPPUST_NO_IOSTREAM\n#endif\n\n"
+        }
+    }
+
+    /// C++ expression of the tag of `obj`, which is `""` for `this` or something like `"rhs."`
+    pub fn tag_expr(&self, obj: &str) -> String {
+        match self.backend {
+            Backend::Union => format!("{}tag_", obj),
+            Backend::Variant => format!("{}tag_()", obj),
+        }
+    }
+
+    /// C++ expression of the payload of the `index`-th variant of `obj`
+    pub fn value_expr(&self, obj: &str, index: usize, variant: &IdentName) -> String {
+        match self.backend {
+            Backend::Union => format!(
+                "{}union_.{}",
+                obj,
+                self.naming.name(NameKind::UnionMember, variant)
+            ),
+            Backend::Variant => format!("std::get<{}>({}value_)", index, obj),
+        }
+    }
+
+    /// C++ statement raising an unrecoverable error, `msg` is a C++ expression
+    pub fn panic(&self, msg: &str) -> String {
+        format!("{};", self.panic_expr(msg))
+    }
+
+    /// C++ expression raising an unrecoverable error, for the C++11 `constexpr` functions which
+    /// are a single `return`
+    pub fn panic_expr(&self, msg: &str) -> String {
+        if self.exceptions {
+            format!("throw std::runtime_error({})", msg)
+        } else {
+            format!("CPPUST_PANIC({})", msg)
+        }
+    }
+}
@@ -0,0 +1,543 @@
+use std::io::Write;
+
+use syn::{ext::IdentExt, Attribute, BinOp, Expr, Ident, Item, Lit, Type, TypeReference, UnOp};
+
+use super::{
+    config::Config,
+    docs::Docs,
+    error::{Error, Result},
+    types,
+};
+
+/// A `const` or `static` item of the input, generated as a `constexpr` value
+#[derive(Clone, Debug)]
+pub struct Constant<'a> {
+    pub ident: &'a Ident,
+    pub attrs: &'a [Attribute],
+    pub ty: &'a Type,
+    pub expr: &'a Expr,
+}
+
+impl<'a> Constant<'a> {
+    /// The constant of a `const` or `static` item, `None` for other items
+    pub fn of(item: &'a Item) -> Result<Option<Self>> {
+        Ok(match item {
+            Item::Const(item) => Some(Self {
+                ident: &item.ident,
+                attrs: &item.attrs,
+                ty: &item.ty,
+                expr: &item.expr,
+            }),
+            Item::Static(item) if item.mutability.is_some() => {
+                return Err(Error::ConstError(
+                    item.ident.to_string(),
+                    "static mut has no C++ constant counterpart".to_owned(),
+                ))
+            }
+            Item::Static(item) => Some(Self {
+                ident: &item.ident,
+                attrs: &item.attrs,
+                ty: &item.ty,
+                expr: &item.expr,
+            }),
+            _ => None,
+        })
+    }
+}
+
+/// Orders `constants` so that each comes after the constants its initializer refers to, as
+/// Rust allows referring to constants declared later
+pub fn order<'a, 'b>(constants: &'b [Constant<'a>]) -> Vec<&'b Constant<'a>> {
+    let mut pending: Vec<_> = constants.iter().collect();
+    let mut ordered = Vec::with_capacity(constants.len());
+
+    while !pending.is_empty() {
+        let index = pending
+            .iter()
+            .position(|constant| {
+                !pending.iter().any(|other| {
+                    other.ident != constant.ident && refers_to(constant.expr, other.ident)
+                })
+            })
+            // cycles are rejected by rustc, and by the C++ compiler if they get through
+            .unwrap_or(0);
+        ordered.push(pending.remove(index));
+    }
+
+    ordered
+}
+
+/// Writes `<module>_consts.hpp`
+pub fn write(f: &mut dyn Write, constants: &[Constant], config: &Config) -> Result<()> {
+    let namespace = &config.namespace;
+
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+
+    writeln!(f, "#pragma once")?;
+    writeln!(f)?;
+
+    write!(f, "{}", config.library_defines())?;
+    write_includes(f, constants, config)?;
+
+    if !namespace.is_empty() {
+        writeln!(
+            f,
+            "{}",
+            namespace
+                .iter()
+                .map(|ns| format!("namespace {} {{", ns))
+                .collect::<Vec<String>>()
+                .join(" ")
+        )?;
+        writeln!(f)?;
+    }
+
+    write_constants(f, constants, config)?;
+
+    if !namespace.is_empty() {
+        writeln!(
+            f,
+            "{}",
+            namespace
+                .iter()
+                .map(|_| "}".to_owned())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn write_includes(f: &mut dyn Write, constants: &[Constant], config: &Config) -> Result<()> {
+    writeln!(f, "#include <cppust/types.hpp>")?;
+    writeln!(f)?;
+    if constants.iter().any(|constant| uses_limits(constant.expr)) {
+        writeln!(f, "#include <limits>")?;
+        writeln!(f)?;
+    }
+
+    // constants typed by aliases, aliases do not refer to constants
+    let mut alias_headers: Vec<&str> = Vec::new();
+    for (ident, alias) in config.aliases.iter() {
+        let header = alias.header.as_str();
+        let used = constants
+            .iter()
+            .any(|constant| types::type_uses_type(constant.ty, ident));
+        if used && !alias_headers.contains(&header) {
+            alias_headers.push(header);
+        }
+    }
+    for header in alias_headers.iter() {
+        writeln!(f, "#include \"{}\"", header)?;
+    }
+    if !alias_headers.is_empty() {
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+/// The `constexpr` definitions of `constants`, inside their namespace
+pub fn write_constants(f: &mut dyn Write, constants: &[Constant], config: &Config) -> Result<()> {
+    for constant in order(constants) {
+        Docs::of_item(constant.attrs, config).write(f, "")?;
+        // a lone literal is converted by the initialization already
+        let ty = if is_int_lit(constant.expr) {
+            None
+        } else {
+            literal_type(constant.ty, config)
+        };
+        writeln!(
+            f,
+            "constexpr {} {} = {};",
+            const_type(constant, config)?,
+            config.naming.constant(&constant.ident.unraw().to_string()),
+            expr_to_cpp(constant.expr, ty.as_deref(), constants, config)
+                .map_err(|err| Error::ConstError(constant.ident.to_string(), err))?
+        )?;
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn const_type(constant: &Constant, config: &Config) -> Result<String> {
+    type_to_cpp(constant.ty, config).ok_or_else(|| {
+        Error::ConstError(
+            constant.ident.to_string(),
+            "only primitive types, bool, char, &str and aliases of them are supported".to_owned(),
+        )
+    })
+}
+
+fn type_to_cpp(ty: &Type, config: &Config) -> Option<String> {
+    match ty {
+        Type::Reference(TypeReference {
+            elem,
+            mutability: None,
+            ..
+        }) => match &**elem {
+            Type::Path(path) if path.path.is_ident("str") => Some("const char*".to_owned()),
+            _ => None,
+        },
+        Type::Path(path) if path.path.is_ident("bool") => Some("bool".to_owned()),
+        Type::Path(path) if path.path.is_ident("char") => Some("char32_t".to_owned()),
+        Type::Path(path) if path.path.get_ident().is_some_and(is_primitive) => {
+            types::type_to_cpp_type(ty, config).ok()
+        }
+        // the alias keeps its name, if it stands for a supported type
+        Type::Path(path) => {
+            let ident = path.path.get_ident()?.to_string();
+            let alias = config.aliases.get(&ident)?;
+            if !alias.params.is_empty() {
+                return None;
+            }
+            type_to_cpp(&alias.ty, config).map(|_| config.alias_ref(&ident))
+        }
+        _ => None,
+    }
+}
+
+fn is_primitive(ident: &Ident) -> bool {
+    [
+        "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64", "usize", "isize",
+    ]
+    .iter()
+    .any(|primitive| ident == primitive)
+}
+
+/// C++ type of the unsuffixed integer literals initializing a constant of type `ty`, which Rust
+/// infers from the constant while C++ takes them for `int`
+fn literal_type(ty: &Type, config: &Config) -> Option<String> {
+    let ident = match ty {
+        Type::Path(path) => path.path.get_ident()?,
+        _ => return None,
+    };
+    if is_primitive(ident) {
+        return match ident.to_string().as_str() {
+            "f32" | "f64" => None,
+            _ => types::type_to_cpp_type(ty, config).ok(),
+        };
+    }
+    match config.aliases.get(&ident.to_string()) {
+        Some(alias) if alias.params.is_empty() => literal_type(&alias.ty, config),
+        _ => None,
+    }
+}
+
+/// C++ expression of a constant initializer, binary operands are parenthesized since the
+/// precedence of bitwise and comparison operators differs from Rust; unsuffixed integer literals
+/// get the type `ty`, if any, as inferred by Rust
+fn expr_to_cpp(
+    expr: &Expr,
+    ty: Option<&str>,
+    constants: &[Constant],
+    config: &Config,
+) -> std::result::Result<String, String> {
+    let operand = |expr: &Expr, ty: Option<&str>| match expr {
+        Expr::Binary(_) | Expr::Cast(_) => {
+            Ok(format!("({})", expr_to_cpp(expr, ty, constants, config)?))
+        }
+        _ => expr_to_cpp(expr, ty, constants, config),
+    };
+
+    match expr {
+        Expr::Lit(lit) => lit_to_cpp(&lit.lit, ty, config),
+        Expr::Paren(paren) => Ok(format!(
+            "({})",
+            expr_to_cpp(&paren.expr, ty, constants, config)?
+        )),
+        Expr::Group(group) => expr_to_cpp(&group.expr, ty, constants, config),
+        Expr::Unary(unary) => match unary.op {
+            UnOp::Neg(_) => Ok(format!("-{}", operand(&unary.expr, ty)?)),
+            // `!` of integers is the bitwise not
+            UnOp::Not(_) if is_bool(&unary.expr, constants) => {
+                Ok(format!("!{}", operand(&unary.expr, ty)?))
+            }
+            // the result of `~` is promoted to `int` for narrower types like `u8`
+            UnOp::Not(_) if ty.is_none() && is_int_lit(&unary.expr) => {
+                Ok(format!("~{}", operand(&unary.expr, ty)?))
+            }
+            UnOp::Not(_) => {
+                let operand = operand(&unary.expr, ty)?;
+                Ok(format!("static_cast<decltype({0})>(~{0})", operand))
+            }
+            _ => Err("only -, ! and binary operators are supported".to_owned()),
+        },
+        Expr::Binary(binary) => {
+            // operands of comparisons have their own type, and so does the shift amount
+            let (left, right) = match binary.op {
+                BinOp::Add(_)
+                | BinOp::Sub(_)
+                | BinOp::Mul(_)
+                | BinOp::Div(_)
+                | BinOp::Rem(_)
+                | BinOp::BitAnd(_)
+                | BinOp::BitOr(_)
+                | BinOp::BitXor(_) => (ty, ty),
+                BinOp::Shl(_) | BinOp::Shr(_) => (ty, None),
+                _ => (None, None),
+            };
+            Ok(format!(
+                "{} {} {}",
+                operand(&binary.left, left)?,
+                bin_op_to_cpp(&binary.op)?,
+                operand(&binary.right, right)?
+            ))
+        }
+        Expr::Cast(cast) => {
+            let ty = match &*cast.ty {
+                Type::Path(path) if path.path.is_ident("bool") => "bool".to_owned(),
+                Type::Path(path) if path.path.is_ident("char") => "char32_t".to_owned(),
+                ty => types::type_to_cpp_type(ty, config).map_err(|err| err.to_string())?,
+            };
+            Ok(format!(
+                "static_cast<{}>({})",
+                ty,
+                expr_to_cpp(&cast.expr, None, constants, config)?
+            ))
+        }
+        Expr::Path(path) => {
+            let segments: Vec<_> = path.path.segments.iter().map(|s| &s.ident).collect();
+            match segments.as_slice() {
+                [ident] => match constants.iter().find(|constant| constant.ident == *ident) {
+                    Some(constant) => {
+                        Ok(config.naming.constant(&constant.ident.unraw().to_string()))
+                    }
+                    None => Err(format!("{} is not a constant of the input", ident)),
+                },
+                [ty, limit] if is_primitive(ty) => {
+                    let function = match limit.unraw().to_string().as_str() {
+                        "MIN" => "lowest",
+                        "MAX" => "max",
+                        "EPSILON" => "epsilon",
+                        _ => return Err(format!("{}::{} is not supported", ty, limit)),
+                    };
+                    Ok(format!(
+                        "std::numeric_limits<::cppust::{}>::{}()",
+                        ty, function
+                    ))
+                }
+                _ => Err("paths other than constants and MIN/MAX are not supported".to_owned()),
+            }
+        }
+        _ => Err("only literals, operators, casts and other constants are supported".to_owned()),
+    }
+}
+
+fn lit_to_cpp(
+    lit: &Lit,
+    ty: Option<&str>,
+    config: &Config,
+) -> std::result::Result<String, String> {
+    match lit {
+        // typed literals keep their type, which matters for `!0u32` and `1 << 40`
+        Lit::Int(int) if int.suffix().is_empty() => Ok(match ty {
+            Some(ty) => format!("{}({})", ty, int.base10_digits()),
+            None => int.base10_digits().to_owned(),
+        }),
+        Lit::Int(int) => {
+            let ty: Type = syn::parse_str(int.suffix()).map_err(|err| err.to_string())?;
+            let ty = types::type_to_cpp_type(&ty, config).map_err(|err| err.to_string())?;
+            Ok(format!("{}({})", ty, int.base10_digits()))
+        }
+        Lit::Float(float) if float.suffix() == "f32" => {
+            Ok(format!("{}f", float_digits(float.base10_digits())))
+        }
+        Lit::Float(float) => Ok(float_digits(float.base10_digits())),
+        Lit::Bool(b) => Ok(b.value.to_string()),
+        Lit::Char(c) => Ok(format!("U'{}'", escape_char(c.value(), '\''))),
+        Lit::Str(s) => Ok(format!(
+            "\"{}\"",
+            s.value()
+                .chars()
+                .map(|c| escape_char(c, '"'))
+                .collect::<String>()
+        )),
+        _ => Err("only integer, float, bool, char and string literals are supported".to_owned()),
+    }
+}
+
+/// `1.` and `1e3` are valid C++ too, but `1` would be an integer
+fn float_digits(digits: &str) -> String {
+    if digits.contains(['.', 'e', 'E']) {
+        digits.to_owned()
+    } else {
+        format!("{}.0", digits)
+    }
+}
+
+/// Escapes `c` for a C++ literal quoted by `quote`, non-ASCII characters as UTF-8 octal bytes
+/// since octal escapes, unlike hex ones, never run into the following characters
+fn escape_char(c: char, quote: char) -> String {
+    match c {
+        '\n' => "\\n".to_owned(),
+        '\r' => "\\r".to_owned(),
+        '\t' => "\\t".to_owned(),
+        '\\' => "\\\\".to_owned(),
+        c if c == quote => format!("\\{}", c),
+        ' '..='~' => c.to_string(),
+        c if quote == '\'' => format!("\\U{:08X}", c as u32),
+        c => c
+            .encode_utf8(&mut [0; 4])
+            .bytes()
+            .map(|b| format!("\\{:03o}", b))
+            .collect(),
+    }
+}
+
+fn bin_op_to_cpp(op: &BinOp) -> std::result::Result<&'static str, String> {
+    Ok(match op {
+        BinOp::Add(_) => "+",
+        BinOp::Sub(_) => "-",
+        BinOp::Mul(_) => "*",
+        BinOp::Div(_) => "/",
+        BinOp::Rem(_) => "%",
+        BinOp::And(_) => "&&",
+        BinOp::Or(_) => "||",
+        BinOp::BitXor(_) => "^",
+        BinOp::BitAnd(_) => "&",
+        BinOp::BitOr(_) => "|",
+        BinOp::Shl(_) => "<<",
+        BinOp::Shr(_) => ">>",
+        BinOp::Eq(_) => "==",
+        BinOp::Lt(_) => "<",
+        BinOp::Le(_) => "<=",
+        BinOp::Ne(_) => "!=",
+        BinOp::Ge(_) => ">=",
+        BinOp::Gt(_) => ">",
+        _ => return Err("compound assignments are not constant expressions".to_owned()),
+    })
+}
+
+/// Whether `expr` is a `bool`, which decides between logical and bitwise not
+fn is_bool(expr: &Expr, constants: &[Constant]) -> bool {
+    match expr {
+        Expr::Lit(lit) => matches!(lit.lit, Lit::Bool(_)),
+        Expr::Paren(paren) => is_bool(&paren.expr, constants),
+        Expr::Group(group) => is_bool(&group.expr, constants),
+        Expr::Unary(unary) => is_bool(&unary.expr, constants),
+        Expr::Binary(binary) => match binary.op {
+            BinOp::And(_)
+            | BinOp::Or(_)
+            | BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => true,
+            _ => is_bool(&binary.left, constants),
+        },
+        Expr::Path(path) => constants.iter().any(|constant| {
+            path.path.is_ident(constant.ident)
+                && matches!(constant.ty, Type::Path(ty) if ty.path.is_ident("bool"))
+        }),
+        _ => false,
+    }
+}
+
+/// Whether `expr` is an unsuffixed integer literal, an `int`
+fn is_int_lit(expr: &Expr) -> bool {
+    matches!(expr, Expr::Lit(lit) if matches!(&lit.lit, Lit::Int(int) if int.suffix().is_empty()))
+}
+
+/// Whether `expr` refers to the constant `ident`
+fn refers_to(expr: &Expr, ident: &Ident) -> bool {
+    match expr {
+        Expr::Path(path) => path.path.is_ident(ident),
+        Expr::Paren(paren) => refers_to(&paren.expr, ident),
+        Expr::Group(group) => refers_to(&group.expr, ident),
+        Expr::Unary(unary) => refers_to(&unary.expr, ident),
+        Expr::Binary(binary) => refers_to(&binary.left, ident) || refers_to(&binary.right, ident),
+        Expr::Cast(cast) => refers_to(&cast.expr, ident),
+        _ => false,
+    }
+}
+
+/// Whether `expr` uses `MIN`, `MAX` or `EPSILON`, which need `<limits>`
+fn uses_limits(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(path) => path.path.segments.len() == 2,
+        Expr::Paren(paren) => uses_limits(&paren.expr),
+        Expr::Group(group) => uses_limits(&group.expr),
+        Expr::Unary(unary) => uses_limits(&unary.expr),
+        Expr::Binary(binary) => uses_limits(&binary.left) || uses_limits(&binary.right),
+        Expr::Cast(cast) => uses_limits(&cast.expr),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn translate(code: &str) -> Vec<String> {
+        let file = syn::parse_file(code).unwrap();
+        let constants: Vec<_> = file
+            .items
+            .iter()
+            .filter_map(|item| Constant::of(item).unwrap())
+            .collect();
+        let mut output = Vec::new();
+        write_constants(&mut output, &constants, &Config::default()).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(
+            translate(
+                r#"
+                /// Longest sentence, see [`Sentence`]
+                pub const MAX_SENTENCE_LEN: usize = BODY_LEN + 2 * 3;
+                const BODY_LEN: usize = 76;
+                static MASK: u32 = !0u32 >> 4 & 0xff | 1 << 2;
+                const IS_LONG: bool = !(MAX_SENTENCE_LEN < 80) && true;
+                const NEG: i16 = -(i16::MAX as i16) - 1;
+                const RATIO: f32 = 1.0f32 / 3.5;
+                const TALKER: &str = "GP\t\"é\"";
+                const SEPARATOR: char = ',';
+                "#
+            ),
+            vec![
+                "constexpr ::cppust::usize BODY_LEN = 76;",
+                "/// @brief Longest sentence, see @ref Sentence",
+                "constexpr ::cppust::usize MAX_SENTENCE_LEN = BODY_LEN + (::cppust::usize(2) * ::cppust::usize(3));",
+                "constexpr ::cppust::u32 MASK = ((static_cast<decltype(::cppust::u32(0))>(~::cppust::u32(0)) >> 4) & ::cppust::u32(255)) | (::cppust::u32(1) << 2);",
+                "constexpr bool IS_LONG = !(MAX_SENTENCE_LEN < 80) && true;",
+                "constexpr ::cppust::i16 NEG = -(static_cast<::cppust::i16>(std::numeric_limits<::cppust::i16>::max())) - ::cppust::i16(1);",
+                "constexpr ::cppust::f32 RATIO = 1.0f / 3.5;",
+                "constexpr const char* TALKER = \"GP\\t\\\"\\303\\251\\\"\";",
+                "constexpr char32_t SEPARATOR = U',';",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_constants() {
+        for code in [
+            "static mut COUNT: u32 = 0;",
+            "const V: Vec<u8> = Vec::new();",
+            "const N: usize = len();",
+            "const N: usize = UNKNOWN;",
+            "const N: usize = usize::BITS;",
+        ] {
+            let file = syn::parse_file(code).unwrap();
+            let result = Constant::of(&file.items[0]).and_then(|constant| {
+                let constants = vec![constant.unwrap()];
+                write_constants(&mut Vec::new(), &constants, &Config::default())
+            });
+            assert!(result.is_err(), "{}", code);
+        }
+    }
+}
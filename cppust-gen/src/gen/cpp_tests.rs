@@ -0,0 +1,567 @@
+use std::io::Write;
+
+use syn::ext::IdentExt;
+use syn::{Fields, ItemEnum, Type, Variant};
+
+use super::{
+    attrs,
+    config::Config,
+    derives::{Derive, Derives},
+    error::{Error, Result},
+    names::{IdentName, NameKind},
+    samples::{self, Syntax},
+    types,
+};
+
+/// Sample values spelled in C++, referred to from the namespace of the config
+struct CppSyntax<'a>(&'a Config);
+
+impl CppSyntax<'_> {
+    fn cpp_type(&self, ty: &Type) -> Option<String> {
+        types::type_to_cpp_type(ty, self.0).ok()
+    }
+}
+
+impl Syntax for CppSyntax<'_> {
+    fn number(&self, ty: &Type, literal: &str) -> Option<String> {
+        Some(format!("{}({})", self.cpp_type(ty)?, literal))
+    }
+
+    fn char(&self) -> String {
+        "U'\\u03bb'".to_owned()
+    }
+
+    fn string(&self) -> String {
+        "std::string(kText)".to_owned()
+    }
+
+    fn vec(&self, item: &Type, sample: Option<String>) -> Option<String> {
+        let item_type = self.cpp_type(item)?;
+        Some(match sample {
+            Some(sample) => format!("vec_of<{}>({})", item_type, sample),
+            None => format!("std::vector<{}>()", item_type),
+        })
+    }
+
+    fn option(&self, ty: &Type, sample: Option<String>) -> Option<String> {
+        let option_type = self.cpp_type(ty)?;
+        Some(match sample {
+            Some(sample) => format!("{}::Some({})", option_type, sample),
+            None => format!("{}::None()", option_type),
+        })
+    }
+
+    fn result(&self, ty: &Type, sample: String, is_ok: bool) -> Option<String> {
+        let factory = if is_ok { "Ok" } else { "Err" };
+        Some(format!("{}::{}({})", self.cpp_type(ty)?, factory, sample))
+    }
+
+    fn variant(&self, variant: &Variant, item: &ItemEnum, samples: Vec<String>) -> Option<String> {
+        let config = self.0;
+        let factory = format!(
+            "{}::{}",
+            config.class_ref(&item.ident.to_string()),
+            config.naming.factory(&attrs::variant_name(variant).ok()?)
+        );
+
+        Some(match samples.len() {
+            0 | 1 => format!("{}({})", factory, samples.join("")),
+            // moved in as a tuple, as the payloads may not be copyable
+            _ => format!(
+                "{}({}({}))",
+                factory,
+                types::fields_to_cpp_type(&variant.fields, config).ok()?,
+                samples.join(", ")
+            ),
+        })
+    }
+
+    fn constructed(&self, cpp_type: &str) -> Option<String> {
+        Some(format!("{}()", cpp_type))
+    }
+}
+
+/// Writes `check_accessors()`, checking that only the accessors of the active variant give
+/// its payload
+fn write_accessor_checks(
+    f: &mut dyn Write,
+    item: &ItemEnum,
+    config: &Config,
+    class: &str,
+    variant_names: &[IdentName],
+) -> Result<()> {
+    writeln!(
+        f,
+        "// the payload accessors of `value`, which is the `index`-th variant"
+    )?;
+    writeln!(
+        f,
+        "void check_accessors({}& value, std::size_t index) {{",
+        class
+    )?;
+    writeln!(f, "    const {}& const_value = value;", class)?;
+    for (i, (variant, variant_name)) in item.variants.iter().zip(variant_names).enumerate() {
+        if let Fields::Unit = variant.fields {
+            continue;
+        }
+        let name = |kind| config.naming.name(kind, variant_name);
+        writeln!(f)?;
+        for value in ["value", "const_value"] {
+            writeln!(
+                f,
+                "    CHECK(({}.{}() != nullptr) == (index == {}));",
+                value,
+                name(NameKind::Ptr),
+                i
+            )?;
+            writeln!(
+                f,
+                "    CHECK({}.{}().is_some() == (index == {}));",
+                value,
+                name(NameKind::As),
+                i
+            )?;
+        }
+        writeln!(
+            f,
+            "    CHECK(sample(index).{}().is_some() == (index == {}));",
+            name(NameKind::Into),
+            i
+        )?;
+        writeln!(f, "    {{")?;
+        writeln!(f, "        {} taken = sample(index);", class)?;
+        writeln!(
+            f,
+            "        CHECK(taken.{}().is_some() == (index == {}));",
+            name(NameKind::Take),
+            i
+        )?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "    if (index == {}) {{", i)?;
+        for (lhs, rhs) in [
+            ("value.{Ref}()", "value.{Ptr}()"),
+            ("const_value.{Ref}()", "const_value.{Ptr}()"),
+            ("const_value.{RefUncheck}()", "const_value.{Ptr}()"),
+            ("value.{As}().unwrap_ref().get()", "value.{Ptr}()"),
+            ("value.{Expect}(\"{Rust}\")", "value.{Ptr}()"),
+        ] {
+            let expand = |expr: &str| {
+                expr.replace("{Ref}", &name(NameKind::Ref))
+                    .replace("{Ptr}", &name(NameKind::Ptr))
+                    .replace("{RefUncheck}", &name(NameKind::RefUncheck))
+                    .replace("{As}", &name(NameKind::As))
+                    .replace("{Expect}", &name(NameKind::Expect))
+                    .replace("{Rust}", &variant.ident.unraw().to_string())
+            };
+            writeln!(f, "        CHECK(&{} == {});", expand(lhs), expand(rhs))?;
+        }
+        if config.exceptions {
+            writeln!(f, "    }} else {{")?;
+            writeln!(
+                f,
+                "        CHECK(panics([&] {{ value.{}(); }}));",
+                name(NameKind::Ref)
+            )?;
+            writeln!(
+                f,
+                "        CHECK(panics([&] {{ const_value.{}(); }}));",
+                name(NameKind::Ref)
+            )?;
+        }
+        writeln!(f, "    }}")?;
+    }
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+/// Writes a C++ program checking the generated members of `item`: every variant is constructed
+/// from sample values and checked through its accessors, then copied, moved and assigned over
+/// every other variant, so running it under AddressSanitizer catches leaks and double frees
+pub fn write_test(
+    f: &mut dyn Write,
+    item: &ItemEnum,
+    config: &Config,
+    items: &[&(&ItemEnum, Config)],
+    header: &str,
+) -> Result<()> {
+    let error = |msg: String| Error::TestError(item.ident.to_string(), msg);
+    if item.variants.is_empty() {
+        return Err(error(
+            "enums without variants have no values to test".to_owned(),
+        ));
+    }
+
+    let derives = Derives::of(&item.attrs)?;
+    let class = config.naming.class(&attrs::enum_name(item)?);
+    let has = |derive| derives.has(derive);
+    let variant_names = item
+        .variants
+        .iter()
+        .map(attrs::variant_name)
+        .collect::<Result<Vec<_>>>()?;
+    let has_payloads = item
+        .variants
+        .iter()
+        .any(|variant| !matches!(variant.fields, Fields::Unit));
+
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f, "//")?;
+    writeln!(
+        f,
+        "// Checks the generated members of `{}`, build it with the generated sources and",
+        item.ident
+    )?;
+    writeln!(
+        f,
+        "// -fsanitize=address to also check that no payload leaks or is freed twice"
+    )?;
+    writeln!(f)?;
+    writeln!(f, "#include \"{}\"", header)?;
+    writeln!(f)?;
+    writeln!(f, "#include <cstdio>")?;
+    writeln!(f, "#include <cstring>")?;
+    if config.exceptions {
+        writeln!(f, "#include <stdexcept>")?;
+    }
+    if has(Derive::Debug) && config.iostream {
+        writeln!(f, "#include <sstream>")?;
+    }
+    writeln!(f, "#include <string>")?;
+    writeln!(f, "#include <utility>")?;
+    writeln!(f, "#include <vector>")?;
+    writeln!(f)?;
+
+    for ns in config.namespace.iter() {
+        writeln!(f, "namespace {} {{", ns)?;
+    }
+    writeln!(f, "namespace {{")?;
+    writeln!(f)?;
+    f.write_all(
+        br#"int failures = 0;
+
+void check(bool ok, const char* expr, int line) {
+    if (!ok) {
+        std::fprintf(stderr, "FAILED line %d: %s\n", line, expr);
+        failures += 1;
+    }
+}
+
+#define CHECK(expr) check((expr), #expr, __LINE__)
+
+// longer than the small string buffer, so strings are copied and moved on the heap
+const char* const kText = "a sample string longer than the small string buffer";
+
+template <typename T>
+std::vector<T> vec_of(T item) {
+    std::vector<T> items;
+    items.push_back(std::move(item));
+    return items;
+}
+
+"#,
+    )?;
+    if config.exceptions {
+        f.write_all(
+            br#"template <typename F>
+bool panics(F f) {
+    try {
+        f();
+    } catch (const std::runtime_error&) {
+        return true;
+    }
+    return false;
+}
+
+"#,
+        )?;
+    }
+
+    writeln!(f, "const std::size_t kVariants = {};", item.variants.len())?;
+    writeln!(f)?;
+    writeln!(f, "// a fresh value of the `index`-th variant")?;
+    writeln!(f, "{} sample(std::size_t index) {{", class)?;
+    writeln!(f, "    switch (index) {{")?;
+    for (i, variant) in item.variants.iter().enumerate() {
+        let sample =
+            samples::variant_sample(&CppSyntax(config), variant, item, config, items, 0)
+                .ok_or_else(|| error(format!("{} has no finite sample value", variant.ident)))?;
+        if i + 1 == item.variants.len() {
+            writeln!(f, "    default:")?;
+        } else {
+            writeln!(f, "    case {}:", i)?;
+        }
+        writeln!(f, "        return {};", sample)?;
+    }
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(
+        f,
+        "// whether `value` is the `index`-th variant, and no other"
+    )?;
+    writeln!(
+        f,
+        "bool is_variant(const {}& value, std::size_t index) {{",
+        class
+    )?;
+    for (i, variant_name) in variant_names.iter().enumerate() {
+        writeln!(
+            f,
+            "    {} value.{}() == (index == {}){}",
+            if i == 0 { "return" } else { "    &&" },
+            config.naming.name(NameKind::Is, variant_name),
+            i,
+            if i + 1 == variant_names.len() {
+                ";"
+            } else {
+                ""
+            }
+        )?;
+    }
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    if has_payloads {
+        write_accessor_checks(f, item, config, &class, &variant_names)?;
+    }
+
+    if has(Derive::Debug) {
+        writeln!(
+            f,
+            "// whether the debug output of `value` is `name` or `name(...)`"
+        )?;
+        writeln!(
+            f,
+            "bool debugs_as(const {}& value, const char* name) {{",
+            class
+        )?;
+        if config.iostream {
+            writeln!(f, "    std::ostringstream os;")?;
+            writeln!(f, "    os<<::cppust::as_debug(value);")?;
+            writeln!(f, "    std::string output = os.str();")?;
+        } else {
+            writeln!(f, "    char buf[256];")?;
+            writeln!(f, "    ::cppust::SliceWriter writer(buf, sizeof(buf));")?;
+            writeln!(f, "    writer<<::cppust::as_debug(value);")?;
+            writeln!(f, "    std::string output = writer.c_str();")?;
+        }
+        writeln!(f, "    std::size_t len = std::strlen(name);")?;
+        writeln!(
+            f,
+            "    return output.compare(0, len, name) == 0 \
+             && (output.size() == len || output[len] == '(');"
+        )?;
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+        writeln!(f, "const char* const kNames[] = {{")?;
+        for variant in item.variants.iter() {
+            writeln!(f, "    \"{}\",", variant.ident.unraw())?;
+        }
+        writeln!(f, "}};")?;
+        writeln!(f)?;
+    }
+
+    let eq = |f: &mut dyn Write, indent: &str, check: &str| -> Result<()> {
+        if has(Derive::PartialEq) {
+            writeln!(f, "{}CHECK({});", indent, check)?;
+        }
+        Ok(())
+    };
+
+    writeln!(f, "int run() {{")?;
+    writeln!(f, "    for (std::size_t i = 0; i < kVariants; ++i) {{")?;
+    writeln!(f, "        {} value = sample(i);", class)?;
+    writeln!(f, "        CHECK(is_variant(value, i));")?;
+    if has_payloads {
+        writeln!(f, "        check_accessors(value, i);")?;
+    }
+    if has(Derive::Debug) {
+        writeln!(f, "        CHECK(debugs_as(value, kNames[i]));")?;
+    }
+    eq(f, "        ", "value == sample(i)")?;
+    eq(f, "        ", "!(value != sample(i))")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "        // the moved-from value is still assignable and destructible"
+    )?;
+    writeln!(f, "        {} moved(std::move(value));", class)?;
+    writeln!(f, "        CHECK(is_variant(moved, i));")?;
+    writeln!(f, "        value = sample(i);")?;
+    writeln!(f, "        CHECK(is_variant(value, i));")?;
+    eq(f, "        ", "moved == value")?;
+    if has(Derive::Clone) {
+        writeln!(f)?;
+        writeln!(f, "        {} copy(value);", class)?;
+        writeln!(f, "        CHECK(is_variant(copy, i));")?;
+        writeln!(f, "        CHECK(is_variant(value, i));")?;
+        eq(f, "        ", "copy == value")?;
+        writeln!(f, "        const {}& same = copy;", class)?;
+        writeln!(f, "        copy = same;")?;
+        writeln!(f, "        CHECK(is_variant(copy, i));")?;
+        eq(f, "        ", "copy == value")?;
+    }
+    writeln!(f)?;
+    writeln!(
+        f,
+        "        // assignments between every pair of variants, from the `j`-th to the `i`-th"
+    )?;
+    writeln!(f, "        for (std::size_t j = 0; j < kVariants; ++j) {{")?;
+    eq(f, "            ", "(sample(i) == sample(j)) == (i == j)")?;
+    eq(f, "            ", "(sample(i) != sample(j)) == (i != j)")?;
+    writeln!(f, "            {} target = sample(j);", class)?;
+    writeln!(f, "            target = sample(i);")?;
+    writeln!(f, "            CHECK(is_variant(target, i));")?;
+    eq(f, "            ", "target == sample(i)")?;
+    writeln!(f, "            {} source = sample(j);", class)?;
+    writeln!(f, "            source = std::move(target);")?;
+    writeln!(f, "            CHECK(is_variant(source, i));")?;
+    writeln!(f, "            target = std::move(source);")?;
+    writeln!(f, "            CHECK(is_variant(target, i));")?;
+    if has(Derive::Clone) {
+        writeln!(f, "            {} copy = sample(j);", class)?;
+        writeln!(f, "            copy = value;")?;
+        writeln!(f, "            CHECK(is_variant(copy, i));")?;
+        eq(f, "            ", "copy == value")?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+
+    for (i, (variant, variant_name)) in item.variants.iter().zip(&variant_names).enumerate() {
+        if let Fields::Unit = variant.fields {
+            continue;
+        }
+        let samples = samples::field_samples(&CppSyntax(config), variant, config, items, 0)
+            .ok_or_else(|| error(format!("{} has no finite sample value", variant.ident)))?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "    // replacing every variant with `{}` in place",
+            variant.ident.unraw()
+        )?;
+        writeln!(f, "    for (std::size_t j = 0; j < kVariants; ++j) {{")?;
+        writeln!(f, "        {} value = sample(j);", class)?;
+        writeln!(
+            f,
+            "        value.{}({});",
+            config.naming.name(NameKind::Emplace, variant_name),
+            samples.join(", ")
+        )?;
+        writeln!(f, "        CHECK(is_variant(value, {}));", i)?;
+        eq(f, "        ", &format!("value == sample({})", i))?;
+        writeln!(f, "    }}")?;
+    }
+    writeln!(f)?;
+    writeln!(f, "    return failures;")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "}}")?;
+    for _ in config.namespace.iter() {
+        writeln!(f, "}}")?;
+    }
+    writeln!(f)?;
+    writeln!(f, "int main() {{")?;
+    writeln!(f, "    int failures = {}run();", config.namespace_prefix())?;
+    writeln!(f, "    if (failures != 0) {{")?;
+    writeln!(
+        f,
+        "        std::fprintf(stderr, \"{}: %d checks failed\\n\", failures);",
+        item.ident
+    )?;
+    writeln!(f, "        return 1;")?;
+    writeln!(f, "    }}")?;
+    writeln!(
+        f,
+        "    std::printf(\"{}: all checks passed\\n\");",
+        item.ident
+    )?;
+    writeln!(f, "    return 0;")?;
+    writeln!(f, "}}")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::gen::config::{UserAlias, UserClass};
+
+    fn config() -> Config {
+        let alias: syn::ItemType = syn::parse_str("type Res<T> = Result<T, String>;").unwrap();
+        let mut classes = HashMap::new();
+        classes.insert(
+            "Frame".to_owned(),
+            UserClass {
+                namespace: Vec::new(),
+                name: "Frame".into(),
+                layout: false,
+                pod: false,
+            },
+        );
+        Config {
+            classes,
+            aliases: std::iter::once((
+                "Res".to_owned(),
+                UserAlias::of(&alias, &[], "a_types.hpp").unwrap(),
+            ))
+            .collect(),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_samples() {
+        let item: ItemEnum = syn::parse_str(
+            "enum Frame { Nested(Vec<Frame>), Pair(i8, Option<char>), Poll(Res<f32>), Done }",
+        )
+        .unwrap();
+        let config = config();
+        let items = [(&item, config.clone())];
+        let items: Vec<_> = items.iter().collect();
+
+        let sample = |index: usize| {
+            samples::variant_sample(
+                &CppSyntax(&config),
+                &item.variants[index],
+                &item,
+                &config,
+                &items,
+                0,
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            sample(0),
+            "Frame::Nested(vec_of<Frame>(Frame::Nested(std::vector<Frame>())))"
+        );
+        assert_eq!(
+            sample(1),
+            "Frame::Pair(std::tuple<::cppust::i8, ::cppust::Option<char32_t>>(\
+             ::cppust::i8(-7), ::cppust::Option<char32_t>::Some(U'\\u03bb')))"
+        );
+        assert_eq!(
+            sample(2),
+            "Frame::Poll(::cppust::Result<::cppust::f32, std::string>::Ok(::cppust::f32(0.5)))"
+        );
+        assert_eq!(sample(3), "Frame::Done()");
+    }
+
+    #[test]
+    fn test_infinite_sample() {
+        let item: ItemEnum = syn::parse_str("enum Frame { Nested(u8, Frame) }").unwrap();
+        let config = config();
+        let items = [(&item, config.clone())];
+        let items: Vec<_> = items.iter().collect();
+
+        let mut output = Vec::new();
+        assert!(write_test(&mut output, &item, &config, &items, "frame.hpp").is_err());
+    }
+}
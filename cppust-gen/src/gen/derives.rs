@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use syn::{Attribute, Fields, ItemEnum, Meta, NestedMeta, Variant};
+
+use super::error::{Error, Result};
+
+/// Traits from `#[derive(...)]` that change the generated C++ code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Derive {
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Debug,
+    Default,
+}
+
+impl Derive {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "Clone" => Derive::Clone,
+            "Copy" => Derive::Copy,
+            "PartialEq" => Derive::PartialEq,
+            "Eq" => Derive::Eq,
+            "PartialOrd" => Derive::PartialOrd,
+            "Ord" => Derive::Ord,
+            "Hash" => Derive::Hash,
+            "Debug" => Derive::Debug,
+            "Default" => Derive::Default,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Derives(HashSet<Derive>);
+
+impl Derives {
+    /// Collects all known traits from the `#[derive(...)]` attributes,
+    /// derives we do not know about (e.g. `Serialize`) are ignored
+    pub fn of(attrs: &[Attribute]) -> Result<Self> {
+        let mut derives = HashSet::new();
+
+        for attr in attrs.iter().filter(|a| a.path.is_ident("derive")) {
+            if let Meta::List(list) = attr.parse_meta()? {
+                for nested in list.nested.iter() {
+                    if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                        let derive = path
+                            .segments
+                            .last()
+                            .and_then(|s| Derive::from_name(&s.ident.to_string()));
+
+                        if let Some(derive) = derive {
+                            derives.insert(derive);
+                        }
+                    }
+                }
+            }
+        }
+
+        let derives = Self(derives);
+
+        // generated operators are built on top of each other, so the derived
+        // (not hand written) supertraits are required
+        for (derive, requires) in [
+            (Derive::Copy, Derive::Clone),
+            (Derive::Eq, Derive::PartialEq),
+            (Derive::PartialOrd, Derive::PartialEq),
+            (Derive::Ord, Derive::PartialOrd),
+            (Derive::Ord, Derive::Eq),
+        ] {
+            if derives.has(derive) && !derives.has(requires) {
+                return Err(Error::DeriveError(
+                    format!("{:?}", derive),
+                    format!("requires #[derive({:?})]", requires),
+                ));
+            }
+        }
+
+        Ok(derives)
+    }
+
+    pub fn has(&self, derive: Derive) -> bool {
+        self.0.contains(&derive)
+    }
+}
+
+/// Finds the unit variant marked with `#[default]`, which is required by `#[derive(Default)]`
+pub fn default_variant(item: &ItemEnum) -> Result<&Variant> {
+    let variant = item
+        .variants
+        .iter()
+        .find(|v| v.attrs.iter().any(|a| a.path.is_ident("default")))
+        .ok_or_else(|| {
+            Error::DeriveError(
+                item.ident.to_string(),
+                "#[derive(Default)] requires a variant marked with #[default]".to_owned(),
+            )
+        })?;
+
+    if let Fields::Unit = variant.fields {
+        Ok(variant)
+    } else {
+        Err(Error::DeriveError(
+            item.ident.to_string(),
+            format!("#[default] variant {} must be a unit variant", variant.ident),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_derives_of() {
+        let item: syn::ItemEnum = syn::parse_str(
+            "#[derive(Clone, std::hash::Hash)] #[derive(serde::Serialize)] enum E { A }",
+        )
+        .unwrap();
+        let derives = Derives::of(&item.attrs).unwrap();
+
+        assert!(derives.has(Derive::Clone));
+        assert!(derives.has(Derive::Hash));
+        assert!(!derives.has(Derive::PartialEq));
+
+        let item: syn::ItemEnum = syn::parse_str("enum E { A }").unwrap();
+        assert_eq!(Derives::of(&item.attrs).unwrap(), Derives::default());
+
+        let item: syn::ItemEnum = syn::parse_str("#[derive(PartialOrd)] enum E { A }").unwrap();
+        assert!(Derives::of(&item.attrs).is_err());
+    }
+
+    #[test]
+    fn test_default_variant() {
+        let item: syn::ItemEnum = syn::parse_str("enum E { A(u8), #[default] B }").unwrap();
+        assert_eq!(default_variant(&item).unwrap().ident, "B");
+
+        let item: syn::ItemEnum = syn::parse_str("enum E { #[default] A(u8), B }").unwrap();
+        assert!(default_variant(&item).is_err());
+
+        let item: syn::ItemEnum = syn::parse_str("enum E { A(u8), B }").unwrap();
+        assert!(default_variant(&item).is_err());
+    }
+}
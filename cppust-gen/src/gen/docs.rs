@@ -0,0 +1,296 @@
+use std::io::Write;
+
+use syn::{ext::IdentExt, Attribute, ItemEnum, Lit, Meta};
+
+use super::{attrs, config::Config, error::Result};
+
+/// Rust doc comments (`///` and `#[doc = "..."]`) of an item, translated to Doxygen
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Docs(Vec<String>);
+
+impl Docs {
+    /// Collects the doc comments from `attrs`, intra-doc links are resolved against the enum `item`
+    pub fn of(attrs: &[Attribute], item: &ItemEnum, config: &Config) -> Self {
+        Self::parse(attrs, Some(item), config)
+    }
+
+    /// Collects the doc comments from `attrs` of an item outside of any enum, like a constant
+    pub fn of_item(attrs: &[Attribute], config: &Config) -> Self {
+        Self::parse(attrs, None, config)
+    }
+
+    fn parse(attrs: &[Attribute], item: Option<&ItemEnum>, config: &Config) -> Self {
+        let mut lines = Vec::new();
+        for attr in attrs.iter().filter(|a| a.path.is_ident("doc")) {
+            if let Ok(Meta::NameValue(meta)) = attr.parse_meta() {
+                if let Lit::Str(doc) = meta.lit {
+                    // `///` comments keep the space after the slashes
+                    for line in doc.value().split('\n') {
+                        lines.push(line.strip_prefix(' ').unwrap_or(line).trim_end().to_owned());
+                    }
+                }
+            }
+        }
+
+        let mut docs = Vec::new();
+        let mut in_code = false;
+        for line in lines {
+            if line.trim_start().starts_with("```") {
+                // Rust code blocks are tagged rust, ignore, no_run, ... which Doxygen does not know
+                in_code = !in_code;
+                docs.push("```".to_owned());
+            } else if in_code {
+                // hidden lines of doc tests
+                if line.trim_start() != "#" && !line.trim_start().starts_with("# ") {
+                    docs.push(line);
+                }
+            } else if let Some(heading) = line.strip_prefix("# ") {
+                docs.push(format!("@par {}", heading));
+            } else {
+                docs.push(translate_links(&line, item, config));
+            }
+        }
+
+        while docs.first().is_some_and(|l| l.is_empty()) {
+            docs.remove(0);
+        }
+        while docs.last().is_some_and(|l| l.is_empty()) {
+            docs.pop();
+        }
+
+        Self(docs)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The first paragraph, the summary line of rustdoc
+    pub fn brief(&self) -> &[String] {
+        let end = self
+            .0
+            .iter()
+            .position(|l| l.is_empty() || l.starts_with("```") || l.starts_with("@par"))
+            .unwrap_or(self.0.len());
+        &self.0[..end]
+    }
+
+    /// Writes all the docs, the first paragraph is the `@brief`
+    pub fn write(&self, f: &mut dyn Write, indent: &str) -> Result<()> {
+        for (i, line) in self.0.iter().enumerate() {
+            match (i, line.is_empty()) {
+                (0, _) => writeln!(f, "{}/// @brief {}", indent, line)?,
+                (_, true) => writeln!(f, "{}///", indent)?,
+                (_, false) => writeln!(f, "{}/// {}", indent, line)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the first paragraph only, as a one line comment of an enumerator
+    pub fn write_brief(&self, f: &mut dyn Write, indent: &str) -> Result<()> {
+        for line in self.brief() {
+            writeln!(f, "{}/// {}", indent, line)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the docs of a member generated for a documented variant, e.g. `is_xxx`, with
+    /// `summary` as `@brief` and the first paragraph of the variant docs as `@details`
+    pub fn write_member(&self, f: &mut dyn Write, indent: &str, summary: &str) -> Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "{}/// @brief {}", indent, summary)?;
+        for (i, line) in self.brief().iter().enumerate() {
+            if i == 0 {
+                writeln!(f, "{}/// @details {}", indent, line)?;
+            } else {
+                writeln!(f, "{}/// {}", indent, line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Translates the intra-doc links of a Markdown line, like ``[`Self::Bytes`]`` or
+/// `[bytes](DecoderStatus::Bytes)`, to `@ref` the generated C++ names. Code spans, URLs and
+/// links to anything but types and variants are left as they are.
+fn translate_links(line: &str, item: Option<&ItemEnum>, config: &Config) -> String {
+    let mut output = String::new();
+    let mut rest = line;
+
+    while let Some(pos) = rest.find(['[', '`']) {
+        output.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if rest.starts_with('`') {
+            // copy the code span verbatim
+            let end = rest[1..].find('`').map_or(rest.len(), |end| end + 2);
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        let label_end = match rest.find(']') {
+            Some(end) => end,
+            None => break,
+        };
+        let label = &rest[1..label_end];
+        let after = &rest[label_end + 1..];
+        let (target, consumed) = if let Some(inner) = after.strip_prefix('(') {
+            match inner.find(')') {
+                Some(end) => (&inner[..end], label_end + 1 + end + 2),
+                None => (label, label_end + 1),
+            }
+        } else if let Some(inner) = after.strip_prefix('[') {
+            match inner.find(']') {
+                Some(end) => (&inner[..end], label_end + 1 + end + 2),
+                None => (label, label_end + 1),
+            }
+        } else {
+            (label, label_end + 1)
+        };
+
+        match cpp_path(target, item, config) {
+            Some(path) => {
+                let text = label.replace('`', "");
+                let target = target.replace('`', "");
+                if text == target {
+                    output.push_str(&format!("@ref {}", path));
+                } else {
+                    output.push_str(&format!("@ref {} \"{}\"", path, text));
+                }
+                rest = &rest[consumed..];
+            }
+            None => {
+                output.push('[');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+
+    output
+}
+
+/// C++ name of the Rust path of an intra-doc link, e.g. `Self::Bytes` to `DecoderStatus::Bytes`,
+/// `Self` and bare variants need the enum `item`
+fn cpp_path(target: &str, item: Option<&ItemEnum>, config: &Config) -> Option<String> {
+    let target = target.trim().trim_matches('`');
+    let target = target.strip_suffix("()").unwrap_or(target);
+
+    let segments: Vec<_> = target.split("::").collect();
+    if segments
+        .iter()
+        .any(|s| s.is_empty() || !s.chars().all(|c| c == '_' || c.is_ascii_alphanumeric()))
+    {
+        return None;
+    }
+
+    // `Self` and bare variants refer to the documented enum, if any
+    let enum_ident = match item {
+        Some(item) => item.ident.unraw().to_string(),
+        None if segments.contains(&"Self") => return None,
+        None => String::new(),
+    };
+    let find_variant = |name: &str| item?.variants.iter().find(|v| v.ident.unraw() == name);
+
+    // modules like `crate::status::` have no C++ counterpart, generated types share a namespace
+    let segments: Vec<_> = segments
+        .iter()
+        .map(|s| if *s == "Self" { enum_ident.as_str() } else { s })
+        .skip_while(|s| !s.starts_with(|c: char| c.is_ascii_uppercase()))
+        .collect();
+
+    let member = |class: &str, member: &str| {
+        let name = match find_variant(member) {
+            Some(variant) if class == enum_ident => {
+                config.naming.factory(&attrs::variant_name(variant).ok()?)
+            }
+            _ if member.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                config.naming.factory(&member.into())
+            }
+            _ => config.naming.method(member),
+        };
+        Some(format!("{}::{}", config.class_ref(class), name))
+    };
+
+    match segments.as_slice() {
+        [variant] if find_variant(variant).is_some() => member(&enum_ident, variant),
+        [class] => Some(config.class_ref(class)),
+        [class, name] => member(class, name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::gen::names::Naming;
+
+    fn docs(code: &str, naming: Naming) -> Vec<String> {
+        let item: ItemEnum = syn::parse_str(code).unwrap();
+        let config = Config {
+            naming,
+            ..Config::default()
+        };
+        Docs::of(&item.attrs, &item, &config).0
+    }
+
+    #[test]
+    fn test_docs_of() {
+        let code = r#"
+            /// Status of the decoder, see [`Self::Bytes`]
+            /// and [`DecoderError`].
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use status::DecoderStatus;
+            /// let status = DecoderStatus::Idle;
+            /// ```
+            enum DecoderStatus { Idle, Bytes(Vec<u8>) }
+        "#;
+
+        assert_eq!(
+            docs(code, Naming::default()),
+            vec![
+                "Status of the decoder, see @ref DecoderStatus::Bytes",
+                "and @ref DecoderError.",
+                "",
+                "@par Examples",
+                "",
+                "```",
+                "let status = DecoderStatus::Idle;",
+                "```",
+            ]
+        );
+
+        let item: ItemEnum = syn::parse_str(code).unwrap();
+        let docs = Docs::of(&item.attrs, &item, &Config::default());
+        assert_eq!(docs.brief().len(), 2);
+    }
+
+    #[test]
+    fn test_translate_links() {
+        let code = r#"
+            #[doc = "Use [`Bytes`], [the bytes](crate::status::DecoderStatus::Bytes) or [`len`](Self::len)"]
+            #[doc = "Not [links](https://example.com), `[Bytes]`, [1, 2] or [`Vec<u8>`]"]
+            enum DecoderStatus { Idle, Bytes(Vec<u8>) }
+        "#;
+
+        assert_eq!(
+            docs(code, Naming::profile("google").unwrap()),
+            vec![
+                "Use @ref DecoderStatus::Bytes, @ref DecoderStatus::Bytes \"the bytes\" or @ref DecoderStatus::Len \"len\"",
+                "Not [links](https://example.com), `[Bytes]`, [1, 2] or [`Vec<u8>`]",
+            ]
+        );
+    }
+}
@@ -1,17 +1,52 @@
 use std::io::Write;
 
-use crate::gen::names::IdentName;
+use crate::gen::names::NameKind;
 
+use super::attrs;
+use super::config::{Backend, Config, CppStd};
+use super::derives::{self, Derive, Derives};
+use super::docs::Docs;
 use super::error::{Result, Error};
-use syn::{Fields, ItemEnum};
+use super::user_code;
+use syn::{ext::IdentExt, Fields, ItemEnum};
 
-pub fn tag(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    const INDENT: &'static str = "    ";
+pub fn tag(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    const INDENT: &str = "    ";
 
-    writeln!(f, "{}enum class _Tag {{", INDENT)?;
+    match super::layout::tag_type(config) {
+        Some(tag_type) => writeln!(f, "{}enum class _Tag : {} {{", INDENT, tag_type)?,
+        None => writeln!(f, "{}enum class _Tag {{", INDENT)?,
+    }
+    // values of the tags, numbered like Rust numbers discriminants
+    let mut value: i128 = -1;
+    let mut values = Vec::new();
     for varient in item.variants.iter() {
-        let varient_name: IdentName = (&varient.ident).into();
-        writeln!(f, "{}    {},", INDENT, varient_name.to_enum_variant_name())?;
+        let varient_name = attrs::variant_name(varient)?;
+        Docs::of(&varient.attrs, item, config).write_brief(f, "        ")?;
+        match &varient.discriminant {
+            Some((_, expr)) => {
+                writeln!(
+                    f,
+                    "{}    {} = {},",
+                    INDENT,
+                    config.naming.tag(&varient_name),
+                    super::types::discriminant_to_cpp(expr)?
+                )?;
+                value = super::types::discriminant_value(expr)?;
+            }
+            None => {
+                writeln!(f, "{}    {},", INDENT, config.naming.tag(&varient_name))?;
+                value += 1;
+            }
+        }
+        values.push(value);
+    }
+    // no variant at all, after a payload threw while replacing the value
+    let valueless = super::layout::valueless_value(item, &values, config)?;
+    if item.variants.iter().any(|varient| varient.discriminant.is_some()) {
+        writeln!(f, "{}    _Valueless = {},", INDENT, valueless)?;
+    } else {
+        writeln!(f, "{}    _Valueless,", INDENT)?;
     }
     writeln!(f, "{}}};", INDENT)?;
     writeln!(f)?;
@@ -19,8 +54,8 @@ pub fn tag(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
     Ok(())
 }
 
-pub fn union(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    const INDENT: &'static str = "    ";
+pub fn union(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    const INDENT: &str = "    ";
 
     // write union alignment
     writeln!(
@@ -38,7 +73,7 @@ pub fn union(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
                 f,
                 "{}    , {}",
                 INDENT,
-                super::types::fields_to_cpp_type(&variant.fields)?
+                super::types::fields_to_cpp_type(&variant.fields, config)?
             )?,
         }
     }
@@ -46,49 +81,139 @@ pub fn union(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
     writeln!(f, "{}>();", INDENT)?;
     writeln!(f)?;
 
+    // write move exception specifications, so containers move instead of copying
+    let value_types = item
+        .variants
+        .iter()
+        .filter(|variant| !matches!(variant.fields, Fields::Unit))
+        .map(|variant| super::types::fields_to_cpp_type(&variant.fields, config))
+        .collect::<Result<Vec<_>>>()?
+        .join(", ");
+    writeln!(
+        f,
+        "{}static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<{}>();",
+        INDENT, value_types
+    )?;
+    writeln!(
+        f,
+        "{}static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<{}>();",
+        INDENT, value_types
+    )?;
+    writeln!(f)?;
+
     // write union definition
     writeln!(f, "{}union alignas(_kUnionAlign) _Union {{", INDENT)?;
+    if config.pod {
+        // active for unit variants, as a constexpr constructor must initialize one member
+        writeln!(f, "{}    char _unit;", INDENT)?;
+    }
 
     for variant in item.variants.iter() {
-        let variant_name: IdentName = (&variant.ident).into();
-        match variant.fields {
-            Fields::Unit => continue,
-            _ => {}
-        };
+        let variant_name = attrs::variant_name(variant)?;
+        if let Fields::Unit = variant.fields {
+            continue;
+        }
         writeln!(
             f,
-            "{}    {} {}_val;",
+            "{}    {} {};",
             INDENT,
-            super::types::fields_to_cpp_type(&variant.fields)?,
-            variant_name.to_public_member_name()
+            super::types::fields_to_cpp_type(&variant.fields, config)?,
+            config.naming.name(NameKind::UnionMember, &variant_name)
         )?;
     }
 
-    writeln!(f, "{}    inline _Union() {{}}", INDENT)?;
-    writeln!(f, "{}    inline ~_Union() {{}}", INDENT)?;
+    if config.pod {
+        // trivial members keep the union trivially copyable and destructible
+        writeln!(f, "{}    constexpr _Union(): _unit() {{}}", INDENT)?;
+        for variant in item.variants.iter() {
+            let variant_name = attrs::variant_name(variant)?;
+            if let Fields::Unit = variant.fields {
+                continue;
+            }
+            writeln!(
+                f,
+                "{}    constexpr _Union(std::integral_constant<_Tag, _Tag::{}>, const {}& value): {}(value) {{}}",
+                INDENT,
+                config.naming.tag(&variant_name),
+                super::types::fields_to_cpp_type(&variant.fields, config)?,
+                config.naming.name(NameKind::UnionMember, &variant_name)
+            )?;
+        }
+    } else {
+        writeln!(f, "{}    inline _Union() {{}}", INDENT)?;
+        writeln!(f, "{}    inline ~_Union() {{}}", INDENT)?;
+    }
     writeln!(f, "{}}};", INDENT)?;
     writeln!(f)?;
 
     Ok(())
 }
 
-pub fn write(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+pub fn variant(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    const INDENT: &str = "    ";
 
-    writeln!(
-        f,
-        "// THIS FILE IS GENERATED BY cppust-gen, YOU CAN CHANGE THIS FILE ON YOUR OWN"
-    )?;
+    // unit variants are std::monostate, alternatives are always accessed by index
+    writeln!(f, "{}using _Variant = std::variant<", INDENT)?;
+    for (i, variant) in item.variants.iter().enumerate() {
+        let value_type = match variant.fields {
+            Fields::Unit => "std::monostate".to_owned(),
+            _ => super::types::fields_to_cpp_type(&variant.fields, config)?,
+        };
+        writeln!(
+            f,
+            "{}    {}{}",
+            INDENT,
+            if i == 0 { "" } else { ", " },
+            value_type
+        )?;
+    }
+    writeln!(f, "{}>;", INDENT)?;
     writeln!(f)?;
 
-    writeln!(f, "#pragma once")?;
+    // maps alternative indices to tags, which may have explicit discriminants
+    writeln!(f, "{}static constexpr _Tag _kTags[] = {{", INDENT)?;
+    for variant in item.variants.iter() {
+        let variant_name = attrs::variant_name(variant)?;
+        writeln!(
+            f,
+            "{}    _Tag::{},",
+            INDENT,
+            config.naming.tag(&variant_name)
+        )?;
+    }
+    writeln!(f, "{}}};", INDENT)?;
     writeln!(f)?;
 
-    writeln!(f, "#include <cppust/cppust.hpp>")?;
+    Ok(())
+}
+
+pub fn write(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let namespace = &config.namespace;
+
+    if config.user_regions {
+        writeln!(
+            f,
+            "// THIS FILE IS GENERATED BY cppust-gen, ONLY CHANGE THE USER CODE REGIONS"
+        )?;
+    } else {
+        writeln!(
+            f,
+            "// THIS FILE IS GENERATED BY cppust-gen, YOU CAN CHANGE THIS FILE ON YOUR OWN"
+        )?;
+    }
     writeln!(f)?;
-    writeln!(f, "#include <iostream>")?;
+
+    writeln!(f, "#pragma once")?;
     writeln!(f)?;
 
+    write!(f, "{}", config.library_defines())?;
+    write_includes(f, item, config)?;
+    if config.user_regions {
+        user_code::write_region(f, "", "includes")?;
+        writeln!(f)?;
+    }
+
     if !namespace.is_empty() {
         writeln!(
             f,
@@ -102,12 +227,25 @@ pub fn write(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) -> Res
         writeln!(f)?;
     }
 
-    writeln!(f, "class {} {{", enum_ident_name.to_class_name())?;
-    writeln!(f, "#include \"{}.inc.hpp\"", enum_ident_name.to_file_name())?;
+    Docs::of(&item.attrs, item, config).write(f, "")?;
+    writeln!(f, "class {} {{", config.naming.class(&enum_ident_name))?;
+    if config.user_regions {
+        write_class_body(f, item, config)?;
+    } else {
+        writeln!(f, "#include \"{}.inc.hpp\"", config.naming.file(&enum_ident_name))?;
+    }
     writeln!(f)?;
     writeln!(f, "public:")?;
-    writeln!(f, "    // WRITE YOUR OWN CODE HERE")?;
+    if config.user_regions {
+        user_code::write_region(f, "    ", "members")?;
+    } else {
+        writeln!(f, "    // WRITE YOUR OWN CODE HERE")?;
+    }
     writeln!(f, "}};")?;
+    if config.user_regions {
+        writeln!(f)?;
+        user_code::write_region(f, "", "declarations")?;
+    }
 
     if !namespace.is_empty() {
         writeln!(f)?;
@@ -123,13 +261,160 @@ pub fn write(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) -> Res
     }
 
     writeln!(f)?;
-    writeln!(f, "#include \"{}.fmt.hpp\"", enum_ident_name.to_file_name())?;
+    if config.user_regions {
+        write_fmt_body(f, item, config)?;
+    } else {
+        writeln!(f, "#include \"{}.fmt.hpp\"", config.naming.file(&enum_ident_name))?;
+    }
+    if config.header_only {
+        writeln!(f, "#include \"{}.impl.hpp\"", config.naming.file(&enum_ident_name))?;
+    }
+
+    Ok(())
+}
+
+/// Only includes what the payloads and derives need, so nothing heap-requiring is pulled in by default
+pub fn write_includes(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let derives = Derives::of(&item.attrs)?;
+    let uses_type = |ident: &str| {
+        item.variants
+            .iter()
+            .any(|variant| super::types::fields_use_type(&variant.fields, ident))
+            || super::methods::uses_type(config, ident)
+    };
+
+    writeln!(f, "#include <cppust/types.hpp>")?;
+    writeln!(f, "#include <cppust/utils.hpp>")?;
+    writeln!(f, "#include <cppust/option.hpp>")?;
+    if derives.has(Derive::Debug) {
+        writeln!(f, "#include <cppust/fmt.hpp>")?;
+        if !config.iostream {
+            writeln!(f, "#include <cppust/write.hpp>")?;
+        }
+    }
+    if derives.has(Derive::Hash) {
+        writeln!(f, "#include <cppust/hash.hpp>")?;
+    }
+    if derives.has(Derive::PartialOrd) {
+        writeln!(f, "#include <cppust/cmp.hpp>")?;
+    }
+    if uses_type("Vec") {
+        writeln!(f, "#include <cppust/vec.hpp>")?;
+    }
+    if item
+        .variants
+        .iter()
+        .any(|variant| super::types::fields_use_array(&variant.fields))
+    {
+        writeln!(f, "#include <cppust/array.hpp>")?;
+    }
+    if uses_type("Result") {
+        writeln!(f, "#include <cppust/result.hpp>")?;
+    }
+    writeln!(f)?;
+
+    writeln!(f, "#include <functional>")?;
+    if config.backend == Backend::Union {
+        writeln!(f, "#include <new>")?;
+    }
+    writeln!(f, "#include <utility>")?;
+    if uses_type("String") {
+        writeln!(f, "#include <string>")?;
+    }
+    if item.variants.iter().any(|variant| variant.fields.len() > 1) {
+        writeln!(f, "#include <tuple>")?;
+    }
+    if config.backend == Backend::Variant {
+        writeln!(f, "#include <variant>")?;
+    }
+    if config.exceptions {
+        writeln!(f, "#include <stdexcept>")?;
+    }
+    if config.iostream {
+        writeln!(f, "#include <iostream>")?;
+    }
+    writeln!(f)?;
+
+    let mut alias_headers: Vec<&str> = Vec::new();
+    let mut aliases: Vec<_> = item
+        .variants
+        .iter()
+        .flat_map(|variant| super::types::aliases_used(&variant.fields, config))
+        .collect();
+    let mut method_aliases: Vec<_> = config
+        .aliases
+        .keys()
+        .filter(|ident| super::methods::uses_type(config, ident))
+        .cloned()
+        .collect();
+    method_aliases.sort();
+    aliases.extend(method_aliases);
+    for ident in aliases {
+        let header = config.aliases[&ident].header.as_str();
+        if !alias_headers.contains(&header) {
+            alias_headers.push(header);
+        }
+    }
+    for header in alias_headers.iter() {
+        writeln!(f, "#include \"{}\"", header)?;
+    }
+
+    // payloads of other generated types are stored by value, which must be complete
+    let user_types = super::types::user_types(item, config);
+    for ident in user_types.iter() {
+        let class = &config.classes[ident];
+        writeln!(
+            f,
+            "#include \"{}.hpp\"",
+            class
+                .namespace
+                .iter()
+                .chain(std::iter::once(&config.naming.file(&class.name)))
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("/")
+        )?;
+    }
+    if !alias_headers.is_empty() || !user_types.is_empty() {
+        writeln!(f)?;
+    }
+
+    // classes only in method signatures are declared, as their headers may include this one
+    let declared: Vec<_> = super::methods::user_types(item, config)
+        .into_iter()
+        .filter(|ident| !user_types.contains(ident))
+        .collect();
+    for ident in declared.iter() {
+        write_class_declaration(f, ident, config)?;
+    }
+    if !declared.is_empty() {
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+/// Forward declaration of the class of the user type `ident`, in its namespace
+pub fn write_class_declaration(f: &mut dyn Write, ident: &str, config: &Config) -> Result<()> {
+    let class = &config.classes[ident];
+    writeln!(
+        f,
+        "{}class {};{}",
+        class
+            .namespace
+            .iter()
+            .map(|ns| format!("namespace {} {{ ", ns))
+            .collect::<Vec<String>>()
+            .join(""),
+        config.class_name(ident),
+        " }".repeat(class.namespace.len())
+    )?;
 
     Ok(())
 }
 
-pub fn write_inc(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+pub fn write_inc(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
 
     writeln!(
         f,
@@ -138,135 +423,480 @@ pub fn write_inc(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
     writeln!(
         f,
         "// PLEASE CHANGE {}.hpp INSTEAD",
-        enum_ident_name.to_file_name()
+        config.naming.file(&enum_ident_name)
     )?;
     writeln!(f)?;
 
+    write_class_body(f, item, config)
+}
+
+/// Members of the class of `item`, the content of `<file>.inc.hpp`
+pub fn write_class_body(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let derives = Derives::of(&item.attrs)?;
+
     writeln!(f, "private: // type definition")?;
-    tag(f, item)?;
-    union(f, item)?;
+    if config.cpp_std > CppStd::Cpp11 {
+        writeln!(
+            f,
+            "    static_assert(__cplusplus >= {0}, \"{1} is generated for {0}\");",
+            config.cpp_std.cplusplus(),
+            config.naming.file(&enum_ident_name)
+        )?;
+        writeln!(f)?;
+    }
+    tag(f, item, config)?;
+    match config.backend {
+        Backend::Union => union(f, item, config)?,
+        Backend::Variant => variant(f, item, config)?,
+    }
     writeln!(f)?;
 
+    // the variant backend leaves special members to std::variant, which are noexcept when possible,
+    // and POD classes to the compiler, so they are trivial
+    let (special_member_body, move_ctor_body, move_assign_body) = match config.backend {
+        Backend::Union if !config.pod => (
+            "",
+            " noexcept(_kNothrowMoveConstructible)",
+            " noexcept(_kNothrowMoveAssignable)",
+        ),
+        _ => (" = default", " = default", " = default"),
+    };
+
     writeln!(f, "public: // ctors, dtors, opeartors")?;
-    writeln!(f, "    {0}(const {0}&);", enum_ident_name.to_class_name())?;
-    writeln!(f, "    {0}({0}&&);", enum_ident_name.to_class_name())?;
-    writeln!(f, "    ~{}();", enum_ident_name.to_class_name())?;
+    if derives.has(Derive::Default) && config.pod {
+        let default_variant = derives::default_variant(item)?;
+        let init = match config.backend {
+            Backend::Union => format!(
+                "{}(_Tag::{})",
+                config.naming.class(&enum_ident_name),
+                config.naming.tag(&attrs::variant_name(default_variant)?)
+            ),
+            Backend::Variant => format!(
+                "value_(std::in_place_index<{}>)",
+                item.variants
+                    .iter()
+                    .position(|v| v.ident == default_variant.ident)
+                    .unwrap()
+            ),
+        };
+        writeln!(
+            f,
+            "    constexpr {}(): {} {{}}  // Default",
+            config.naming.class(&enum_ident_name),
+            init
+        )?;
+    } else if derives.has(Derive::Default) {
+        writeln!(f, "    {}();  // Default", config.naming.class(&enum_ident_name))?;
+    }
+    if derives.has(Derive::Clone) {
+        writeln!(
+            f,
+            "    {0}(const {0}&){1};  // Clone",
+            config.naming.class(&enum_ident_name),
+            special_member_body
+        )?;
+    } else {
+        writeln!(f, "    {0}(const {0}&) = delete;", config.naming.class(&enum_ident_name))?;
+    }
+    writeln!(
+        f,
+        "    {0}({0}&&){1};",
+        config.naming.class(&enum_ident_name),
+        move_ctor_body
+    )?;
+    writeln!(
+        f,
+        "    ~{}(){};",
+        config.naming.class(&enum_ident_name),
+        special_member_body
+    )?;
     writeln!(f)?;
+    if derives.has(Derive::Clone) {
+        writeln!(
+            f,
+            "    {0}& operator=(const {0}&){1};  // Clone",
+            config.naming.class(&enum_ident_name),
+            special_member_body
+        )?;
+    } else {
+        writeln!(
+            f,
+            "    {0}& operator=(const {0}&) = delete;",
+            config.naming.class(&enum_ident_name)
+        )?;
+    }
     writeln!(
         f,
-        "    {0}& operator=(const {0}&);",
-        enum_ident_name.to_class_name()
+        "    {0}& operator=({0}&&){1};",
+        config.naming.class(&enum_ident_name),
+        move_assign_body
     )?;
+    writeln!(f)?;
+    match config.backend {
+        Backend::Union if config.pod => {
+            writeln!(
+                f,
+                "    void swap({}& other) noexcept {{ std::swap(*this, other); }}",
+                config.naming.class(&enum_ident_name)
+            )?;
+        }
+        Backend::Union => {
+            writeln!(
+                f,
+                "    void swap({}&) noexcept(_kNothrowMoveConstructible \
+                 && _kNothrowMoveAssignable);",
+                config.naming.class(&enum_ident_name)
+            )?;
+        }
+        Backend::Variant => {
+            writeln!(
+                f,
+                "    void swap({}& other) noexcept(std::is_nothrow_move_constructible<_Variant>::value \
+                 && std::is_nothrow_swappable<_Variant>::value) {{ value_.swap(other.value_); }}",
+                config.naming.class(&enum_ident_name)
+            )?;
+        }
+    }
     writeln!(
         f,
-        "    {0}& operator=({0}&&);",
-        enum_ident_name.to_class_name()
+        "    friend void swap({0}& lhs, {0}& rhs) noexcept(noexcept(lhs.swap(rhs))) \
+         {{ lhs.swap(rhs); }}",
+        config.naming.class(&enum_ident_name)
     )?;
+    writeln!(f)?;
     writeln!(
         f,
-        "    bool operator==(const {0}&) const;",
-        enum_ident_name.to_class_name()
+        "    // whether a payload threw while replacing the value, which then has no variant"
     )?;
     writeln!(
         f,
-        "    bool operator!=(const {0}&) const;",
-        enum_ident_name.to_class_name()
+        "    {}bool {}() const {{ return {}; }}",
+        if config.pod { "constexpr " } else { "" },
+        config.naming.method("valueless_by_exception"),
+        match config.backend {
+            Backend::Union => "tag_ == _Tag::_Valueless",
+            Backend::Variant => "value_.valueless_by_exception()",
+        }
     )?;
+    if derives.has(Derive::PartialEq) {
+        writeln!(f)?;
+        writeln!(
+            f,
+            "    bool operator==(const {0}&) const;  // PartialEq",
+            config.naming.class(&enum_ident_name)
+        )?;
+        writeln!(
+            f,
+            "    bool operator!=(const {0}&) const;  // PartialEq",
+            config.naming.class(&enum_ident_name)
+        )?;
+    }
+    if derives.has(Derive::PartialOrd) {
+        writeln!(
+            f,
+            "    ::cppust::Option<::cppust::Ordering> {1}(const {0}&) const;  // PartialOrd",
+            config.naming.class(&enum_ident_name),
+            config.naming.method("partial_cmp")
+        )?;
+        for op in ["<", "<=", ">", ">="] {
+            writeln!(
+                f,
+                "    bool operator{1}(const {0}&) const;  // PartialOrd",
+                config.naming.class(&enum_ident_name),
+                op
+            )?;
+        }
+        if config.cpp_std < CppStd::Cpp20 {
+            writeln!(f, "#if __cplusplus > 201703L")?;
+        }
+        writeln!(
+            f,
+            "    {1} operator<=>(const {0}&) const;  // PartialOrd",
+            config.naming.class(&enum_ident_name),
+            three_way_ordering(&derives)
+        )?;
+        if config.cpp_std < CppStd::Cpp20 {
+            writeln!(f, "#endif")?;
+        }
+    }
     // writeln!(
     //     f,
     //     "    friend std::ostream& ::operator<<(std::ostream& os, const {}&);  // Display",
-    //     enum_ident_name.to_class_name()
+    //     config.naming.class(&enum_ident_name)
     // )?;
-    writeln!(
-        f,
-        "    template<typename> friend struct ::cppust::debug;  // Debug",
-    )?;
+    if derives.has(Derive::Debug) {
+        writeln!(
+            f,
+            "    template<typename> friend struct ::cppust::debug;  // Debug",
+        )?;
+    }
+    if derives.has(Derive::Hash) {
+        writeln!(
+            f,
+            "    friend struct ::std::hash<{}>;  // Hash",
+            config.naming.class(&enum_ident_name)
+        )?;
+    }
     writeln!(f)?;
 
     writeln!(f, "public: // enum constructors")?;
-    for variant in item.variants.iter() {
-        let variant_name: IdentName = (&variant.ident).into();
+    for (i, variant) in item.variants.iter().enumerate() {
+        let variant_name = attrs::variant_name(variant)?;
+        Docs::of(&variant.attrs, item, config).write(f, "    ")?;
 
+        if config.pod {
+            write_pod_factories(f, item, i, config)?;
+            continue;
+        }
         match variant.fields {
             Fields::Unit => {
                 writeln!(
                     f,
                     "    static {0} {1}();",
-                    enum_ident_name.to_class_name(),
-                    variant_name.to_class_name()
+                    config.naming.class(&enum_ident_name),
+                    config.naming.factory(&variant_name)
                 )?;
             },
             Fields::Unnamed(_) => {
                 write!(
                     f,
                     "    static {0} {1}(",
-                    enum_ident_name.to_class_name(),
-                    variant_name.to_class_name(),
+                    config.naming.class(&enum_ident_name),
+                    config.naming.factory(&variant_name),
                 )?;
                 for (i, field) in variant.fields.iter().enumerate() {
                     if i != 0 {
                         write!(f, ", ")?;
                     }
-                    write!(f, "const {}&", super::types::type_to_cpp_type(&field.ty)?)?;
+                    write!(f, "const {}&", super::types::field_to_cpp_type(field, config)?)?;
                 }
                 writeln!(f, ");")?;
+                writeln!(
+                    f,
+                    "    static {0} {1}({2}&&);",
+                    config.naming.class(&enum_ident_name),
+                    config.naming.factory(&variant_name),
+                    super::types::fields_to_cpp_type(&variant.fields, config)?
+                )?;
+                if variant.fields.len() > 1 {
+                    write!(
+                        f,
+                        "    static {0} {1}(",
+                        config.naming.class(&enum_ident_name),
+                        config.naming.factory(&variant_name),
+                    )?;
+                    for (i, field) in variant.fields.iter().enumerate() {
+                        if i != 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}&&", super::types::field_to_cpp_type(field, config)?)?;
+                    }
+                    writeln!(f, ");")?;
+                }
             },
             _ => return Err(Error::UnknownFieldsType(variant.fields.clone())),
         }
     }
     writeln!(f)?;
 
+    writeln!(f, "public: // in-place constructors")?;
+    for (i, variant) in item.variants.iter().enumerate() {
+        if let Fields::Unit = variant.fields {
+            continue;
+        }
+        write_emplace(f, item, i, config)?;
+    }
+
+    // POD classes define the accessors which can be constexpr here, C++11 makes every constexpr
+    // member function const, so the mutable overloads require C++14
+    let (constexpr, constexpr_mut) = match (config.pod, config.cpp_std) {
+        (false, _) => ("", ""),
+        (true, CppStd::Cpp11) => ("constexpr ", ""),
+        (true, _) => ("constexpr ", "constexpr "),
+    };
+    let body = |expr: String| {
+        if config.pod {
+            format!(" {{ return {}; }}", expr)
+        } else {
+            ";".to_owned()
+        }
+    };
+
     writeln!(f, "public: // value accessors")?;
-    for variant in item.variants.iter() {
-        let variant_name: IdentName = (&variant.ident).into();
+    for (i, variant) in item.variants.iter().enumerate() {
+        let variant_name = attrs::variant_name(variant)?;
+        let docs = Docs::of(&variant.attrs, item, config);
+        let rust_name = variant.ident.unraw();
+        let is_active = format!(
+            "{} == _Tag::{}",
+            config.tag_expr(""),
+            config.naming.tag(&variant_name)
+        );
+        let value = config.value_expr("", i, &variant_name);
+        let checked = |msg: &str| {
+            format!(
+                "{} ? {} : ({}, {})",
+                is_active,
+                value,
+                config.panic_expr(msg),
+                value
+            )
+        };
 
+        docs.write_member(f, "    ", &format!("Whether the value is `{}`", rust_name))?;
         writeln!(
             f,
-            "    bool is_{}() const;",
-            variant_name.to_public_member_name()
+            "    {}bool {}() const{}",
+            constexpr,
+            config.naming.name(NameKind::Is, &variant_name),
+            body(is_active.clone())
         )?;
         writeln!(f)?;
 
         match variant.fields {
             Fields::Unit => continue,
             _ => {
-                let cpp_type_name = super::types::fields_to_cpp_type(&variant.fields)?;
+                let cpp_type_name = super::types::fields_to_cpp_type(&variant.fields, config)?;
+                docs.write_member(
+                    f,
+                    "    ",
+                    &format!("Payload of `{}`, which must be the active variant", rust_name),
+                )?;
+                let unchecked = body(format!("assert({}), {}", is_active, value));
                 writeln!(
                     f,
-                    "    const {1}& {0}_ref_uncheck() const;",
-                    variant_name.to_public_member_name(),
-                    cpp_type_name
+                    "    {2}const {1}& {0}() const{3}",
+                    config.naming.name(NameKind::RefUncheck, &variant_name),
+                    cpp_type_name,
+                    constexpr,
+                    unchecked
                 )?;
                 writeln!(
                     f,
-                    "    {1}& {0}_ref_uncheck();",
-                    variant_name.to_public_member_name(),
-                    cpp_type_name
+                    "    {2}{1}& {0}(){3}",
+                    config.naming.name(NameKind::RefUncheck, &variant_name),
+                    cpp_type_name,
+                    constexpr_mut,
+                    unchecked
                 )?;
                 writeln!(f)?;
+                docs.write_member(
+                    f,
+                    "    ",
+                    &format!("Payload of `{}`, panics unless it is the active variant", rust_name),
+                )?;
+                let checked_ref = body(checked(&format!("\"requires {}\"", rust_name)));
                 writeln!(
                     f,
-                    "    const {1}& {0}_ref() const;",
-                    variant_name.to_public_member_name(),
+                    "    {2}const {1}& {0}() const{3}",
+                    config.naming.name(NameKind::Ref, &variant_name),
+                    cpp_type_name,
+                    constexpr,
+                    checked_ref
+                )?;
+                writeln!(
+                    f,
+                    "    {2}{1}& {0}(){3}",
+                    config.naming.name(NameKind::Ref, &variant_name),
+                    cpp_type_name,
+                    constexpr_mut,
+                    checked_ref
+                )?;
+                writeln!(f)?;
+                docs.write_member(
+                    f,
+                    "    ",
+                    &format!("Payload of `{}`, or `nullptr` unless it is the active variant", rust_name),
+                )?;
+                let ptr = body(format!("{} ? &{} : nullptr", is_active, value));
+                writeln!(
+                    f,
+                    "    {2}const {1}* {0}() const{3}",
+                    config.naming.name(NameKind::Ptr, &variant_name),
+                    cpp_type_name,
+                    constexpr,
+                    ptr
+                )?;
+                writeln!(
+                    f,
+                    "    {2}{1}* {0}(){3}",
+                    config.naming.name(NameKind::Ptr, &variant_name),
+                    cpp_type_name,
+                    constexpr_mut,
+                    ptr
+                )?;
+                writeln!(f)?;
+                docs.write_member(
+                    f,
+                    "    ",
+                    &format!("Payload of `{}`, or `None` unless it is the active variant", rust_name),
+                )?;
+                writeln!(
+                    f,
+                    "    ::cppust::Option<std::reference_wrapper<const {1}>> {0}() const;",
+                    config.naming.name(NameKind::As, &variant_name),
                     cpp_type_name
                 )?;
                 writeln!(
                     f,
-                    "    {1}& {0}_ref();",
-                    variant_name.to_public_member_name(),
+                    "    ::cppust::Option<std::reference_wrapper<{1}>> {0}();",
+                    config.naming.name(NameKind::As, &variant_name),
                     cpp_type_name
                 )?;
                 writeln!(f)?;
+                docs.write_member(
+                    f,
+                    "    ",
+                    &format!("Payload of `{}`, panics with `msg` unless it is the active variant", rust_name),
+                )?;
+                let expected = body(checked("msg"));
+                writeln!(
+                    f,
+                    "    {2}const {1}& {0}(const char* msg) const &{3}",
+                    config.naming.name(NameKind::Expect, &variant_name),
+                    cpp_type_name,
+                    constexpr,
+                    expected
+                )?;
+                writeln!(
+                    f,
+                    "    {2}{1}& {0}(const char* msg) &{3}",
+                    config.naming.name(NameKind::Expect, &variant_name),
+                    cpp_type_name,
+                    constexpr_mut,
+                    expected
+                )?;
+                writeln!(
+                    f,
+                    "    {2}{1} {0}(const char* msg) &&{3}",
+                    config.naming.name(NameKind::Expect, &variant_name),
+                    cpp_type_name,
+                    constexpr_mut,
+                    expected
+                )?;
+                writeln!(f)?;
+                docs.write_member(
+                    f,
+                    "    ",
+                    &format!("Moves out the payload of `{}`, or `None` unless it is the active variant", rust_name),
+                )?;
                 writeln!(
                     f,
-                    "    const {1}* {0}_ptr() const;",
-                    variant_name.to_public_member_name(),
+                    "    ::cppust::Option<{1}> {0}() &&;",
+                    config.naming.name(NameKind::Into, &variant_name),
                     cpp_type_name
                 )?;
+                writeln!(f)?;
+                docs.write_member(
+                    f,
+                    "    ",
+                    &format!("Moves out the payload of `{}`, leaving it moved-from, or `None` unless it is the active variant", rust_name),
+                )?;
                 writeln!(
                     f,
-                    "    {1}* {0}_ptr();",
-                    variant_name.to_public_member_name(),
+                    "    ::cppust::Option<{1}> {0}() &;",
+                    config.naming.name(NameKind::Take, &variant_name),
                     cpp_type_name
                 )?;
                 writeln!(f)?;
@@ -274,32 +904,228 @@ pub fn write_inc(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
         }
     }
 
+    super::methods::write_declarations(f, item, config)?;
+
     writeln!(f, "private:")?;
-    writeln!(f, "    _Union union_;")?;
-    writeln!(f, "    _Tag tag_;")?;
-    writeln!(f)?;
-    writeln!(f, "    {}(_Tag);", enum_ident_name.to_class_name())?;
+    match config.backend {
+        Backend::Union => union_members(f, item, &derives, config),
+        Backend::Variant => variant_members(f, item, config),
+    }
+}
+
+/// Factories of a variant of a POD class, defined `constexpr` so they can fill constant tables
+fn write_pod_factories(
+    f: &mut dyn Write,
+    item: &ItemEnum,
+    index: usize,
+    config: &Config,
+) -> Result<()> {
+    let class = config.naming.class(&attrs::enum_name(item)?);
+    let variant = &item.variants[index];
+    let variant_name = attrs::variant_name(variant)?;
+    let factory = config.naming.factory(&variant_name);
+    let tag = config.naming.tag(&variant_name);
+    let value = |arg: Option<&str>| match (config.backend, arg) {
+        (Backend::Union, None) => format!("{}(_Tag::{})", class, tag),
+        (Backend::Union, Some(arg)) => format!(
+            "{0}(_Tag::{1}, _Union(std::integral_constant<_Tag, _Tag::{1}>(), {2}))",
+            class, tag, arg
+        ),
+        (Backend::Variant, None) => format!("{}(std::in_place_index<{}>)", class, index),
+        (Backend::Variant, Some(arg)) => {
+            format!("{}(std::in_place_index<{}>, {})", class, index, arg)
+        }
+    };
+
+    if let Fields::Unit = variant.fields {
+        writeln!(
+            f,
+            "    static constexpr {} {}() {{ return {}; }}",
+            class,
+            factory,
+            value(None)
+        )?;
+        return Ok(());
+    }
+    let value_type = super::types::fields_to_cpp_type(&variant.fields, config)?;
     writeln!(
         f,
-        "    {}(_Tag, const _Union&);",
-        enum_ident_name.to_class_name()
+        "    static constexpr {} {}(const {}& v0) {{ return {}; }}",
+        class,
+        factory,
+        value_type,
+        value(Some("v0"))
     )?;
+    // a copy is a move for trivially copyable payloads, and std::move is not constexpr in C++11
+    writeln!(
+        f,
+        "    static constexpr {} {}({}&& value) {{ return {}; }}",
+        class,
+        factory,
+        value_type,
+        value(Some("value"))
+    )?;
+
+    Ok(())
+}
+
+/// `emplace_xxx(args...)` replaces the value with a payload constructed from `args` in place
+fn write_emplace(f: &mut dyn Write, item: &ItemEnum, index: usize, config: &Config) -> Result<()> {
+    let variant = &item.variants[index];
+    let variant_name = attrs::variant_name(variant)?;
+    let cpp_type_name = super::types::fields_to_cpp_type(&variant.fields, config)?;
+
+    Docs::of(&variant.attrs, item, config).write_member(
+        f,
+        "    ",
+        &format!("Replaces the value with a `{}` constructed from `args`", variant.ident.unraw()),
+    )?;
+    writeln!(f, "    template <typename... Args>")?;
+    writeln!(
+        f,
+        "    {}& {}(Args&&... args) {{",
+        cpp_type_name,
+        config.naming.name(NameKind::Emplace, &variant_name)
+    )?;
+    match config.backend {
+        Backend::Union => {
+            if !config.pod {
+                writeln!(f, "        deinit_union_();")?;
+            }
+            writeln!(f, "        tag_ = _Tag::_Valueless;")?;
+            writeln!(
+                f,
+                "        new (&union_.{0}) {1}(std::forward<Args>(args)...);",
+                config.naming.name(NameKind::UnionMember, &variant_name),
+                cpp_type_name
+            )?;
+            writeln!(f, "        tag_ = _Tag::{};", config.naming.tag(&variant_name))?;
+            writeln!(
+                f,
+                "        return union_.{};",
+                config.naming.name(NameKind::UnionMember, &variant_name)
+            )?;
+        }
+        Backend::Variant => {
+            writeln!(
+                f,
+                "        return value_.template emplace<{}>(std::forward<Args>(args)...);",
+                index
+            )?;
+        }
+    }
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+
+    Ok(())
+}
+
+fn union_members(
+    f: &mut dyn Write,
+    item: &ItemEnum,
+    derives: &Derives,
+    config: &Config,
+) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+
+    // Rust places the tag first
+    if config.repr.is_some() {
+        writeln!(f, "    _Tag tag_;")?;
+        writeln!(f, "    _Union union_;")?;
+    } else {
+        writeln!(f, "    _Union union_;")?;
+        writeln!(f, "    _Tag tag_;")?;
+    }
+    writeln!(f)?;
+    if config.pod {
+        // the special members are trivial, so nothing else initializes or destroys payloads
+        let inits = |union_val: &str| {
+            if config.repr.is_some() {
+                format!("tag_(tag), union_({})", union_val)
+            } else {
+                format!("union_({}), tag_(tag)", union_val)
+            }
+        };
+        writeln!(
+            f,
+            "    constexpr {}(_Tag tag): {} {{}}",
+            config.naming.class(&enum_ident_name),
+            inits("")
+        )?;
+        writeln!(
+            f,
+            "    constexpr {}(_Tag tag, const _Union& union_val): {} {{}}",
+            config.naming.class(&enum_ident_name),
+            inits("union_val")
+        )?;
+        if config.repr.is_some() {
+            writeln!(f)?;
+            super::layout::write_asserts(f, item, config)?;
+        }
+        return Ok(());
+    }
+    writeln!(f, "    {}(_Tag);", config.naming.class(&enum_ident_name))?;
+    if derives.has(Derive::Clone) {
+        writeln!(
+            f,
+            "    {}(_Tag, const _Union&);",
+            config.naming.class(&enum_ident_name)
+        )?;
+    }
     writeln!(
         f,
         "    {}(_Tag, _Union&&);",
-        enum_ident_name.to_class_name()
+        config.naming.class(&enum_ident_name)
     )?;
     writeln!(f)?;
-    writeln!(f, "    void tagged_init_(_Tag, const _Union&);")?;
+    if derives.has(Derive::Clone) {
+        writeln!(f, "    void tagged_init_(_Tag, const _Union&);")?;
+    }
     writeln!(f, "    void tagged_init_(_Tag, _Union&&);")?;
     writeln!(f, "    void deinit_union_();")?;
+    if config.repr.is_some() {
+        writeln!(f)?;
+        super::layout::write_asserts(f, item, config)?;
+    }
 
     Ok(())
 }
 
-pub fn write_fmt(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
-    let namespace = namespace.iter().map(|ns| format!("{}::", ns)).collect::<Vec<_>>().join("");
+fn variant_members(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+
+    writeln!(f, "    _Variant value_;")?;
+    writeln!(f)?;
+    let constexpr = if config.pod { "constexpr " } else { "" };
+    writeln!(f, "    template <size_t I, typename... Args>")?;
+    writeln!(
+        f,
+        "    {}explicit {}(std::in_place_index_t<I> index, Args&&... args): value_(index, std::forward<Args>(args)...) {{}}",
+        constexpr,
+        config.naming.class(&enum_ident_name)
+    )?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    {}_Tag tag_() const {{ \
+         return value_.valueless_by_exception() ? _Tag::_Valueless : _kTags[value_.index()]; }}",
+        constexpr
+    )?;
+
+    Ok(())
+}
+
+/// Return type of the C++20 `operator<=>`, a total order is only promised by `Ord`
+pub fn three_way_ordering(derives: &Derives) -> &'static str {
+    if derives.has(Derive::Ord) {
+        "std::strong_ordering"
+    } else {
+        "std::partial_ordering"
+    }
+}
+
+pub fn write_fmt(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
 
     writeln!(
         f,
@@ -308,58 +1134,144 @@ pub fn write_fmt(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) ->
     writeln!(
         f,
         "// PLEASE CHANGE {}.hpp INSTEAD",
-        enum_ident_name.to_file_name()
+        config.naming.file(&enum_ident_name)
     )?;
     writeln!(f)?;
 
+    write_fmt_body(f, item, config)
+}
+
+/// `Debug` and `Hash` specializations of `item`, the content of `<file>.fmt.hpp`
+pub fn write_fmt_body(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let derives = Derives::of(&item.attrs)?;
+
     // writeln!(
     //     f,
     //     "std::ostream& operator<<(std::ostream& os, const {}{}& self); // Display",
     //     &namespace,
-    //     enum_ident_name.to_class_name()
+    //     config.naming.class(&enum_ident_name)
     // )?;
     // writeln!(f)?;
+    if derives.has(Derive::Debug) {
+        write_debug(f, item, config)?;
+        writeln!(f)?;
+    }
+
+    if derives.has(Derive::Hash) {
+        write_hash(f, item, config)?;
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn write_debug(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let namespace = config.namespace_prefix();
+
     writeln!(f, "namespace cppust {{")?;
     writeln!(
         f,
         "    template <> struct debug<{}{}> {{ // Debug",
         &namespace,
-        enum_ident_name.to_class_name()
+        config.naming.class(&enum_ident_name)
     )?;
+    // generic over the output, which is a std::ostream or a ::cppust::Writer
+    writeln!(f, "        template <typename Os>")?;
     writeln!(
         f,
-        "        static std::ostream& fmt(const {}{}& self, std::ostream& os) {{",
+        "        static Os& fmt(const {}{}& self, Os& os) {{",
         &namespace,
-        enum_ident_name.to_class_name()
+        config.naming.class(&enum_ident_name)
     )?;
-    writeln!(f, "            switch (self.tag_) {{")?;
-    for variant in item.variants.iter() {
-        let variant_ident_name: IdentName = (&variant.ident).into();
+    writeln!(f, "            switch ({}) {{", config.tag_expr("self."))?;
+    for (i, variant) in item.variants.iter().enumerate() {
+        let variant_ident_name = attrs::variant_name(variant)?;
 
         writeln!(
             f,
             "            case {}{}::_Tag::{}:",
             &namespace,
-            enum_ident_name.to_class_name(),
-            variant_ident_name.to_enum_variant_name()
+            config.naming.class(&enum_ident_name),
+            config.naming.tag(&variant_ident_name)
         )?;
         if let Fields::Unit = &variant.fields {
             writeln!(
                 f,
-                "                return os<<\"{}\";",
-                variant_ident_name.to_enum_variant_name()
+                "                os<<\"{}\";",
+                variant.ident.unraw()
             )?;
         } else {
             writeln!(
                 f,
-                "                return os<<\"{}(\"<<::cppust::as_debug(self.union_.{}_val)<<\")\";",
-                variant_ident_name.to_enum_variant_name(),
-                variant_ident_name.to_public_member_name()
+                "                os<<\"{}(\"<<::cppust::as_debug({})<<\")\";",
+                variant.ident.unraw(),
+                config.value_expr("self.", i, &variant_ident_name)
             )?;
         }
+        writeln!(f, "                break;")?;
+    }
+    writeln!(f, "            default:")?;
+    writeln!(
+        f,
+        "                os<<\"<?>(\"<<int({})<<\")\";",
+        config.tag_expr("self.")
+    )?;
+    writeln!(f, "                break;")?;
+    writeln!(f, "            }}")?;
+    writeln!(f, "            return os;")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }};")?;
+    writeln!(f, "}}")?;
+
+    Ok(())
+}
+
+fn write_hash(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let namespace = config.namespace_prefix();
+
+    writeln!(f, "namespace std {{")?;
+    writeln!(
+        f,
+        "    template <> struct hash<{}{}> {{ // Hash",
+        &namespace,
+        config.naming.class(&enum_ident_name)
+    )?;
+    writeln!(
+        f,
+        "        size_t operator()(const {}{}& self) const {{",
+        &namespace,
+        config.naming.class(&enum_ident_name)
+    )?;
+    writeln!(
+        f,
+        "            size_t seed = std::hash<int>()(int({}));",
+        config.tag_expr("self.")
+    )?;
+    writeln!(f, "            switch ({}) {{", config.tag_expr("self."))?;
+    for (i, variant) in item.variants.iter().enumerate() {
+        let variant_ident_name = attrs::variant_name(variant)?;
+
+        if let Fields::Unit = &variant.fields {
+            continue;
+        }
+
+        writeln!(
+            f,
+            "            case {}{}::_Tag::{}:",
+            &namespace,
+            config.naming.class(&enum_ident_name),
+            config.naming.tag(&variant_ident_name)
+        )?;
+        writeln!(
+            f,
+            "                return ::cppust::hash_combine(seed, ::cppust::hash_of({}));",
+            config.value_expr("self.", i, &variant_ident_name)
+        )?;
     }
     writeln!(f, "            default:")?;
-    writeln!(f, "                return os<<\"<?>(\"<<int(self.tag_)<<\")\";")?;
+    writeln!(f, "                return seed;")?;
     writeln!(f, "            }}")?;
     writeln!(f, "        }}")?;
     writeln!(f, "    }};")?;
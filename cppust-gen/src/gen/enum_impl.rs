@@ -1,36 +1,59 @@
 use std::io::Write;
 
-use crate::gen::names::IdentName;
+use crate::gen::names::NameKind;
 
+use super::attrs;
+use super::config::{Backend, Config, CppStd};
+use super::derives::{self, Derive, Derives};
 use super::error::Result;
-use syn::{Fields, ItemEnum};
+use super::user_code;
+use syn::{ext::IdentExt, Fields, ItemEnum};
 
-pub fn write(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+pub fn write(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let namespace = &config.namespace;
 
-    writeln!(
-        f,
-        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
-    )?;
-    writeln!(
-        f,
-        "// PLEASE CHANGE {}.cpp INSTEAD",
-        enum_ident_name.to_file_name()
-    )?;
+    if config.user_regions {
+        writeln!(
+            f,
+            "// THIS FILE IS GENERATED BY cppust-gen, ONLY CHANGE THE USER CODE REGIONS"
+        )?;
+    } else {
+        writeln!(
+            f,
+            "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+        )?;
+        writeln!(
+            f,
+            "// PLEASE CHANGE {}.{} INSTEAD",
+            config.naming.file(&enum_ident_name),
+            if config.header_only { "hpp" } else { "cpp" }
+        )?;
+    }
     writeln!(f)?;
 
+    if config.header_only {
+        writeln!(f, "#pragma once")?;
+        writeln!(f)?;
+    }
+
     writeln!(
         f,
         "#include \"{}.hpp\"",
         namespace
             .iter()
-            .chain(vec![enum_ident_name.to_file_name()].iter())
-            .map(|x| x.clone())
+            .chain(std::iter::once(&config.naming.file(&enum_ident_name)))
+            .cloned()
             .collect::<Vec<_>>()
             .join("/")
     )?;
     writeln!(f)?;
 
+    if config.user_regions {
+        user_code::write_region(f, "", "includes")?;
+        writeln!(f)?;
+    }
+
     if !namespace.is_empty() {
         writeln!(
             f,
@@ -44,15 +67,12 @@ pub fn write(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) -> Res
         writeln!(f)?;
     }
 
-    write_ctors(f, item)?;
-    writeln!(f)?;
-    write_dtors(f, item)?;
-    writeln!(f)?;
-    write_operators(f, item)?;
-    writeln!(f)?;
-    write_enum_ctors(f, item)?;
-    write_accessors(f, item)?;
-    write_private_methods(f, item)?;
+    write_members(f, item, config)?;
+
+    if config.user_regions {
+        writeln!(f)?;
+        user_code::write_region(f, "", "definitions")?;
+    }
 
     if !namespace.is_empty() {
         writeln!(f)?;
@@ -70,41 +90,86 @@ pub fn write(f: &mut dyn Write, item: &ItemEnum, namespace: &Vec<String>) -> Res
     Ok(())
 }
 
-fn write_ctors(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+/// Out-of-class member definitions of `item`, written inside its namespace
+pub fn write_members(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    match config.backend {
+        // POD classes default or define everything else `constexpr` in the header
+        Backend::Union if config.pod => {}
+        Backend::Union => {
+            write_ctors(f, item, config)?;
+            writeln!(f)?;
+            write_dtors(f, item, config)?;
+            writeln!(f)?;
+            write_assign_operators(f, item, config)?;
+        }
+        Backend::Variant if config.pod => {}
+        Backend::Variant => write_variant_ctors(f, item, config)?,
+    }
+    write_operators(f, item, config)?;
+    write_enum_ctors(f, item, config)?;
+    write_accessors(f, item, config)?;
+    if config.backend == Backend::Union && !config.pod {
+        write_private_methods(f, item, config)?;
+    }
+
+    Ok(())
+}
+
+fn write_ctors(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let derives = Derives::of(&item.attrs)?;
 
     writeln!(f, "// public constructors")?;
+    if derives.has(Derive::Default) {
+        let default_ident_name = attrs::variant_name(derives::default_variant(item)?)?;
+        write!(f, "{}", config.inline())?;
+        writeln!(
+            f,
+            "{0}::{0}(): {0}(_Tag::{1}) {{ }} // default constructor",
+            config.naming.class(&enum_ident_name),
+            config.naming.tag(&default_ident_name)
+        )?;
+    }
+    if derives.has(Derive::Clone) {
+        write!(f, "{}", config.inline())?;
+        writeln!(
+            f,
+            "{0}::{0}(const {0}& rhs): {0}(rhs.tag_, rhs.union_) {{ }} // copy constructor",
+            config.naming.class(&enum_ident_name)
+        )?;
+    }
+    write!(f, "{}", config.inline())?;
     writeln!(
         f,
-        "{0}::{0}(const {0}& rhs): {0}(rhs.tag_, rhs.union_) {{ }} // copy constructor",
-        enum_ident_name.to_class_name()
-    )?;
-    writeln!(
-        f,
-        "{0}::{0}({0}&& rhs): {0}(rhs.tag_, std::move(rhs.union_)) {{ }} // move constructor",
-        enum_ident_name.to_class_name()
+        "{0}::{0}({0}&& rhs) noexcept(_kNothrowMoveConstructible): {0}(rhs.tag_, std::move(rhs.union_)) {{ }} // move constructor",
+        config.naming.class(&enum_ident_name)
     )?;
 
     writeln!(f)?;
     writeln!(f, "// private constructors")?;
+    write!(f, "{}", config.inline())?;
     writeln!(
         f,
         "{0}::{0}(_Tag tag): tag_(tag) {{ }}",
-        enum_ident_name.to_class_name()
-    )?;
-    writeln!(f)?;
-    writeln!(
-        f,
-        "{0}::{0}(_Tag tag, const _Union& union_val): tag_(tag) {{",
-        enum_ident_name.to_class_name()
+        config.naming.class(&enum_ident_name)
     )?;
-    writeln!(f, "    tagged_init_(tag, union_val);")?;
-    writeln!(f, "}}")?;
+    if derives.has(Derive::Clone) {
+        writeln!(f)?;
+        write!(f, "{}", config.inline())?;
+        writeln!(
+            f,
+            "{0}::{0}(_Tag tag, const _Union& union_val): tag_(tag) {{",
+            config.naming.class(&enum_ident_name)
+        )?;
+        writeln!(f, "    tagged_init_(tag, union_val);")?;
+        writeln!(f, "}}")?;
+    }
     writeln!(f)?;
+    write!(f, "{}", config.inline())?;
     writeln!(
         f,
         "{0}::{0}(_Tag tag, _Union&& union_val): tag_(tag) {{",
-        enum_ident_name.to_class_name()
+        config.naming.class(&enum_ident_name)
     )?;
     writeln!(f, "    tagged_init_(tag, std::move(union_val));")?;
     writeln!(f, "}}")?;
@@ -114,31 +179,80 @@ fn write_ctors(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
     Ok(())
 }
 
-fn write_dtors(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+fn write_dtors(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
 
     writeln!(f, "// destructor")?;
-    writeln!(f, "{0}::~{0}() {{", enum_ident_name.to_class_name())?;
+    write!(f, "{}", config.inline())?;
+    writeln!(f, "{0}::~{0}() {{", config.naming.class(&enum_ident_name))?;
     writeln!(f, "    deinit_union_();")?;
     writeln!(f, "}}")?;
     Ok(())
 }
 
-fn write_operators(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+fn write_variant_ctors(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let derives = Derives::of(&item.attrs)?;
 
-    writeln!(f, "// operators")?;
+    // everything else is defaulted in the header
+    if derives.has(Derive::Default) {
+        let default_variant = derives::default_variant(item)?;
+        let index = item
+            .variants
+            .iter()
+            .position(|v| v.ident == default_variant.ident)
+            .unwrap();
+
+        writeln!(f, "// public constructors")?;
+        write!(f, "{}", config.inline())?;
+        writeln!(
+            f,
+            "{0}::{0}(): value_(std::in_place_index<{1}>) {{ }} // default constructor",
+            config.naming.class(&enum_ident_name),
+            index
+        )?;
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn write_assign_operators(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let derives = Derives::of(&item.attrs)?;
+
+    writeln!(f, "// assign operators")?;
+    if derives.has(Derive::Clone) {
+        write!(f, "{}", config.inline())?;
+        writeln!(
+            f,
+            "{0}& {0}::operator=(const {0}& rhs) {{ // assign",
+            config.naming.class(&enum_ident_name)
+        )?;
+        writeln!(f, "    if (this == &rhs) {{ return *this; }}")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "    // copied aside first, so this value is kept when copying a payload throws"
+        )?;
+        writeln!(f, "    {} copy(rhs);", config.naming.class(&enum_ident_name))?;
+        writeln!(f, "    return *this = std::move(copy);")?;
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+    }
+
+    write!(f, "{}", config.inline())?;
     writeln!(
         f,
-        "{0}& {0}::operator=(const {0}& rhs) {{ // assign",
-        enum_ident_name.to_class_name()
+        "{0}& {0}::operator=({0}&& rhs) noexcept(_kNothrowMoveAssignable) {{ // move",
+        config.naming.class(&enum_ident_name)
     )?;
     writeln!(f, "    if (this == &rhs) {{ return *this; }}")?;
     writeln!(f)?;
     writeln!(f, "    if (tag_ == rhs.tag_) {{")?;
     writeln!(f, "        switch (tag_) {{")?;
     for variant in item.variants.iter() {
-        let variant_ident_name: IdentName = (&variant.ident).into();
+        let variant_ident_name = attrs::variant_name(variant)?;
 
         if let Fields::Unit = &variant.fields {
             continue;
@@ -147,12 +261,12 @@ fn write_operators(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
         writeln!(
             f,
             "        case _Tag::{}:",
-            variant_ident_name.to_enum_variant_name()
+            config.naming.tag(&variant_ident_name)
         )?;
         writeln!(
             f,
-            "            union_.{0}_val = rhs.union_.{0}_val;",
-            variant_ident_name.to_public_member_name()
+            "            union_.{0} = std::move(rhs.union_.{0});",
+            config.naming.name(NameKind::UnionMember, &variant_ident_name)
         )?;
         writeln!(f, "            break;")?;
     }
@@ -161,24 +275,65 @@ fn write_operators(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
     writeln!(f, "        }}")?;
     writeln!(f, "    }} else {{")?;
     writeln!(f, "        deinit_union_();")?;
+    writeln!(f, "        // valueless until the payload is moved in, which may throw")?;
+    writeln!(f, "        tag_ = _Tag::_Valueless;")?;
+    writeln!(f, "        tagged_init_(rhs.tag_, std::move(rhs.union_));")?;
     writeln!(f, "        tag_ = rhs.tag_;")?;
-    writeln!(f, "        tagged_init_(rhs.tag_, rhs.union_);")?;
     writeln!(f, "    }}")?;
     writeln!(f, "    return *this;")?;
     writeln!(f, "}}")?;
     writeln!(f)?;
 
+    write!(f, "{}", config.inline())?;
     writeln!(
         f,
-        "{0}& {0}::operator=({0}&& rhs) {{ // move",
-        enum_ident_name.to_class_name()
+        "void {0}::swap({0}& other) noexcept(_kNothrowMoveConstructible \
+         && _kNothrowMoveAssignable) {{",
+        config.naming.class(&enum_ident_name)
     )?;
-    writeln!(f, "    if (this == &rhs) {{ return *this; }}")?;
+    writeln!(f, "    if (this == &other) {{ return; }}")?;
     writeln!(f)?;
-    writeln!(f, "    if (tag_ == rhs.tag_) {{")?;
-    writeln!(f, "        switch (tag_) {{")?;
-    for variant in item.variants.iter() {
-        let variant_ident_name: IdentName = (&variant.ident).into();
+    writeln!(
+        f,
+        "    {} moved(std::move(other));",
+        config.naming.class(&enum_ident_name)
+    )?;
+    writeln!(f, "    other = std::move(*this);")?;
+    writeln!(f, "    *this = std::move(moved);")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    Ok(())
+}
+
+fn write_operators(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let derives = Derives::of(&item.attrs)?;
+
+    if !derives.has(Derive::PartialEq) {
+        return Ok(());
+    }
+
+    writeln!(f, "// operators")?;
+    write!(f, "{}", config.inline())?;
+    writeln!(
+        f,
+        "bool {0}::operator==(const {0}& rhs) const {{ // equal",
+        config.naming.class(&enum_ident_name)
+    )?;
+    // a NaN payload is not equal to itself, unless `Eq` promises there is none
+    if derives.has(Derive::Eq) {
+        writeln!(f, "    if (this == &rhs) {{ return true; }}")?;
+    }
+    writeln!(
+        f,
+        "    if ({} != {}) {{ return false; }}",
+        config.tag_expr(""),
+        config.tag_expr("rhs.")
+    )?;
+    writeln!(f, "    switch ({}) {{", config.tag_expr(""))?;
+    for (i, variant) in item.variants.iter().enumerate() {
+        let variant_ident_name = attrs::variant_name(variant)?;
 
         if let Fields::Unit = &variant.fields {
             continue;
@@ -186,37 +341,69 @@ fn write_operators(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
 
         writeln!(
             f,
-            "        case _Tag::{}:",
-            variant_ident_name.to_enum_variant_name()
+            "    case _Tag::{}:",
+            config.naming.tag(&variant_ident_name)
         )?;
         writeln!(
             f,
-            "            union_.{0}_val = std::move(rhs.union_.{0}_val);",
-            variant_ident_name.to_public_member_name()
+            "        return {} == {};",
+            config.value_expr("", i, &variant_ident_name),
+            config.value_expr("rhs.", i, &variant_ident_name)
         )?;
-        writeln!(f, "            break;")?;
     }
-    writeln!(f, "        default:")?;
-    writeln!(f, "            break;")?;
-    writeln!(f, "        }}")?;
-    writeln!(f, "    }} else {{")?;
-    writeln!(f, "        deinit_union_();")?;
-    writeln!(f, "        tag_ = rhs.tag_;")?;
-    writeln!(f, "        tagged_init_(rhs.tag_, std::move(rhs.union_));")?;
+    writeln!(f, "    default:")?;
+    writeln!(f, "        break;")?;
     writeln!(f, "    }}")?;
-    writeln!(f, "    return *this;")?;
+    writeln!(f, "    return true;")?;
     writeln!(f, "}}")?;
     writeln!(f)?;
+    write!(f, "{}", config.inline())?;
     writeln!(
         f,
-        "bool {0}::operator==(const {0}& rhs) const {{ // equal",
-        enum_ident_name.to_class_name()
+        "bool {0}::operator!=(const {0}& rhs) const {{ // not equal",
+        config.naming.class(&enum_ident_name)
     )?;
-    writeln!(f, "    if (this == &rhs) {{ return true; }}")?;
-    writeln!(f, "    if (tag_ != rhs.tag_) {{ return false; }}")?;
-    writeln!(f, "    switch (tag_) {{")?;
-    for variant in item.variants.iter() {
-        let variant_ident_name: IdentName = (&variant.ident).into();
+    writeln!(f, "    return !(*this == rhs);")?;
+    writeln!(f, "}}")?;
+
+    if derives.has(Derive::PartialOrd) {
+        writeln!(f)?;
+        write_ordering_operators(f, item, &derives, config)?;
+    }
+    writeln!(f)?;
+
+    Ok(())
+}
+
+fn write_ordering_operators(
+    f: &mut dyn Write,
+    item: &ItemEnum,
+    derives: &Derives,
+    config: &Config,
+) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+
+    // every operator is derived from partial_cmp, like the default methods of Rust's PartialOrd
+    write!(f, "{}", config.inline())?;
+    writeln!(
+        f,
+        "::cppust::Option<::cppust::Ordering> {0}::{1}(const {0}& rhs) const {{ // partial compare",
+        config.naming.class(&enum_ident_name),
+        config.naming.method("partial_cmp")
+    )?;
+    let tag = config.tag_expr("");
+    let rhs_tag = config.tag_expr("rhs.");
+    writeln!(f, "    if ({} != {}) {{", tag, rhs_tag)?;
+    writeln!(
+        f,
+        "        return ::cppust::Option<::cppust::Ordering>::Some({} < {} ? ::cppust::Ordering::Less : ::cppust::Ordering::Greater);",
+        tag,
+        rhs_tag
+    )?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "    switch ({}) {{", tag)?;
+    for (i, variant) in item.variants.iter().enumerate() {
+        let variant_ident_name = attrs::variant_name(variant)?;
 
         if let Fields::Unit = &variant.fields {
             continue;
@@ -225,87 +412,146 @@ fn write_operators(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
         writeln!(
             f,
             "    case _Tag::{}:",
-            variant_ident_name.to_enum_variant_name()
+            config.naming.tag(&variant_ident_name)
         )?;
         writeln!(
             f,
-            "        return union_.{0}_val == rhs.union_.{0}_val;",
-            variant_ident_name.to_public_member_name()
+            "        return ::cppust::partial_cmp({}, {});",
+            config.value_expr("", i, &variant_ident_name),
+            config.value_expr("rhs.", i, &variant_ident_name)
         )?;
     }
     writeln!(f, "    default:")?;
     writeln!(f, "        break;")?;
     writeln!(f, "    }}")?;
-    writeln!(f, "    return true;")?;
+    writeln!(
+        f,
+        "    return ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);"
+    )?;
     writeln!(f, "}}")?;
+
+    for (op, name, accepts) in [
+        ("<", "less than", vec!["Less"]),
+        ("<=", "less or equal", vec!["Less", "Equal"]),
+        (">", "greater than", vec!["Greater"]),
+        (">=", "greater or equal", vec!["Greater", "Equal"]),
+    ] {
+        writeln!(f)?;
+        write!(f, "{}", config.inline())?;
+        writeln!(
+            f,
+            "bool {0}::operator{1}(const {0}& rhs) const {{ // {2}",
+            config.naming.class(&enum_ident_name),
+            op,
+            name
+        )?;
+        writeln!(f, "    auto ord = {}(rhs);", config.naming.method("partial_cmp"))?;
+        writeln!(
+            f,
+            "    return {};",
+            accepts
+                .iter()
+                .map(|o| format!(
+                    "ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::{})",
+                    o
+                ))
+                .collect::<Vec<_>>()
+                .join(" || ")
+        )?;
+        writeln!(f, "}}")?;
+    }
+
+    let ordering = super::enum_hdr::three_way_ordering(derives);
     writeln!(f)?;
+    if config.cpp_std < CppStd::Cpp20 {
+        writeln!(f, "#if __cplusplus > 201703L")?;
+    }
+    write!(f, "{}", config.inline())?;
     writeln!(
         f,
-        "bool {0}::operator!=(const {0}& rhs) const {{ // not equal",
-        enum_ident_name.to_class_name()
+        "{1} {0}::operator<=>(const {0}& rhs) const {{ // three-way compare",
+        config.naming.class(&enum_ident_name),
+        ordering
+    )?;
+    writeln!(
+        f,
+        "    return ::cppust::to_{}({}(rhs));",
+        ordering.trim_start_matches("std::"),
+        config.naming.method("partial_cmp")
     )?;
-    writeln!(f, "    return !(*this == rhs);")?;
     writeln!(f, "}}")?;
+    if config.cpp_std < CppStd::Cpp20 {
+        writeln!(f, "#endif")?;
+    }
 
     Ok(())
 }
 
-fn write_enum_ctors(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+fn write_enum_ctors(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+
+    // constexpr in the header
+    if config.pod {
+        return Ok(());
+    }
 
     writeln!(f, "// enum constructors")?;
-    for variant in item.variants.iter() {
-        let variant_ident_name: IdentName = (&variant.ident).into();
+    for (index, variant) in item.variants.iter().enumerate() {
+        let variant_ident_name = attrs::variant_name(variant)?;
 
-        if let Fields::Unit = &variant.fields {
+        if config.backend == Backend::Variant {
+            write_variant_enum_ctor(f, item, index, config)?;
+        } else if let Fields::Unit = &variant.fields {
+            write!(f, "{}", config.inline())?;
             writeln!(
                 f,
                 "{0} {0}::{1}() {{",
-                enum_ident_name.to_class_name(),
-                variant_ident_name.to_class_name()
+                config.naming.class(&enum_ident_name),
+                config.naming.factory(&variant_ident_name)
             )?;
             writeln!(
                 f,
                 "    return {0}(_Tag::{1});",
-                enum_ident_name.to_class_name(),
-                variant_ident_name.to_class_name()
+                config.naming.class(&enum_ident_name),
+                config.naming.tag(&variant_ident_name)
             )?;
             writeln!(f, "}}")?;
         } else {
+            write!(f, "{}", config.inline())?;
             write!(
                 f,
                 "{0} {0}::{1}(",
-                enum_ident_name.to_class_name(),
-                variant_ident_name.to_class_name(),
+                config.naming.class(&enum_ident_name),
+                config.naming.factory(&variant_ident_name),
             )?;
             let mut cnt = 0;
             for (i, field) in variant.fields.iter().enumerate() {
                 if i != 0 {
                     write!(f, ", ")?;
                 }
-                write!(f, "const {}& v{}", super::types::type_to_cpp_type(&field.ty)?, i)?;
+                write!(f, "const {}& v{}", super::types::field_to_cpp_type(field, config)?, i)?;
                 cnt = i + 1;
             }
             writeln!(f, ") {{")?;
+            // valueless until the payload is constructed, which may throw
             writeln!(
                 f,
-                "    {0} output(_Tag::{1});",
-                enum_ident_name.to_class_name(),
-                variant_ident_name.to_class_name()
+                "    {} output(_Tag::_Valueless);",
+                config.naming.class(&enum_ident_name)
             )?;
             if cnt == 1 {
                 writeln!(
                     f,
-                    "    new (&output.union_.{0}_val) {1}(v0);",
-                    variant_ident_name.to_public_member_name(),
-                    super::types::fields_to_cpp_type(&variant.fields)?
+                    "    new (&output.union_.{0}) {1}(v0);",
+                    config.naming.name(NameKind::UnionMember, &variant_ident_name),
+                    super::types::fields_to_cpp_type(&variant.fields, config)?
                 )?;
             } else {
                 write!(
                     f,
-                    "    new (&output.union_.{0}_val) {1}(",
-                    variant_ident_name.to_public_member_name(),
-                    super::types::fields_to_cpp_type(&variant.fields)?
+                    "    new (&output.union_.{0}) {1}(",
+                    config.naming.name(NameKind::UnionMember, &variant_ident_name),
+                    super::types::fields_to_cpp_type(&variant.fields, config)?
                 )?;
                 for i in 0..cnt {
                     if i != 0 {
@@ -315,114 +561,422 @@ fn write_enum_ctors(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
                 }
                 writeln!(f, ");")?;
             }
+            writeln!(
+                f,
+                "    output.tag_ = _Tag::{};",
+                config.naming.tag(&variant_ident_name)
+            )?;
             writeln!(f, "    return output;")?;
             writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            let value_type = super::types::fields_to_cpp_type(&variant.fields, config)?;
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "{0} {0}::{1}({2}&& value) {{",
+                config.naming.class(&enum_ident_name),
+                config.naming.factory(&variant_ident_name),
+                value_type
+            )?;
+            writeln!(
+                f,
+                "    {} output(_Tag::_Valueless);",
+                config.naming.class(&enum_ident_name)
+            )?;
+            writeln!(
+                f,
+                "    new (&output.union_.{0}) {1}(std::move(value));",
+                config.naming.name(NameKind::UnionMember, &variant_ident_name),
+                value_type
+            )?;
+            writeln!(
+                f,
+                "    output.tag_ = _Tag::{};",
+                config.naming.tag(&variant_ident_name)
+            )?;
+            writeln!(f, "    return output;")?;
+            writeln!(f, "}}")?;
+
+            // moves each field into the tuple, rather than requiring one to be built first
+            if cnt > 1 {
+                writeln!(f)?;
+                write!(f, "{}", config.inline())?;
+                writeln!(
+                    f,
+                    "{0} {0}::{1}({2}) {{",
+                    config.naming.class(&enum_ident_name),
+                    config.naming.factory(&variant_ident_name),
+                    rvalue_params(&variant.fields, config)?
+                )?;
+                writeln!(
+                    f,
+                    "    {} output(_Tag::_Valueless);",
+                    config.naming.class(&enum_ident_name)
+                )?;
+                writeln!(
+                    f,
+                    "    new (&output.union_.{0}) {1}({2});",
+                    config.naming.name(NameKind::UnionMember, &variant_ident_name),
+                    value_type,
+                    moved_args(cnt)
+                )?;
+                writeln!(
+                    f,
+                    "    output.tag_ = _Tag::{};",
+                    config.naming.tag(&variant_ident_name)
+                )?;
+                writeln!(f, "    return output;")?;
+                writeln!(f, "}}")?;
+            }
         }
         writeln!(f)?;
     }
     Ok(())
 }
 
-fn write_accessors(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
-
-    writeln!(f, "// accessors")?;
+/// `T0&& v0, T1&& v1, ...` of the per-field rvalue factories
+fn rvalue_params(fields: &Fields, config: &Config) -> Result<String> {
+    let params: Result<Vec<_>> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            Ok(format!("{}&& v{}", super::types::field_to_cpp_type(field, config)?, i))
+        })
+        .collect();
+    Ok(params?.join(", "))
+}
 
-    for variant in item.variants.iter() {
-        let variant_ident_name: IdentName = (&variant.ident).into();
+/// `std::move(v0), std::move(v1), ...` forwarding the parameters of [rvalue_params]
+fn moved_args(count: usize) -> String {
+    (0..count)
+        .map(|i| format!("std::move(v{})", i))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
 
-        // is
-        writeln!(f, "bool {}::is_{}() const {{", enum_ident_name.to_class_name(), variant_ident_name.to_public_member_name())?;
-        writeln!(f, "    return tag_ == _Tag::{};", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "}}")?;
-        writeln!(f)?;
+fn write_variant_enum_ctor(
+    f: &mut dyn Write,
+    item: &ItemEnum,
+    index: usize,
+    config: &Config,
+) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let variant = &item.variants[index];
+    let variant_ident_name = attrs::variant_name(variant)?;
 
-        if let Fields::Unit = &variant.fields {
-            continue;
+    write!(f, "{}", config.inline())?;
+    write!(
+        f,
+        "{0} {0}::{1}(",
+        config.naming.class(&enum_ident_name),
+        config.naming.factory(&variant_ident_name),
+    )?;
+    for (i, field) in variant.fields.iter().enumerate() {
+        if i != 0 {
+            write!(f, ", ")?;
         }
+        write!(f, "const {}& v{}", super::types::field_to_cpp_type(field, config)?, i)?;
+    }
+    writeln!(f, ") {{")?;
+    write!(
+        f,
+        "    return {}(std::in_place_index<{}>",
+        config.naming.class(&enum_ident_name),
+        index
+    )?;
+    for i in 0..variant.fields.len() {
+        write!(f, ", v{}", i)?;
+    }
+    writeln!(f, ");")?;
+    writeln!(f, "}}")?;
 
-        let value_type = super::types::fields_to_cpp_type(&variant.fields)?;
+    if let Fields::Unit = variant.fields {
+        return Ok(());
+    }
 
-        // ref_uncheck
-        writeln!(
-            f,
-            "const {}& {}::{}_ref_uncheck() const {{",
-            &value_type,
-            enum_ident_name.to_class_name(),
-            variant_ident_name.to_public_member_name(),
-        )?;
-        writeln!(f, "    assert(tag_ == _Tag::{});", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "    return union_.{}_val;", variant_ident_name.to_public_member_name())?;
-        writeln!(f, "}}")?;
-        writeln!(f)?;
+    writeln!(f)?;
+    write!(f, "{}", config.inline())?;
+    writeln!(
+        f,
+        "{0} {0}::{1}({2}&& value) {{",
+        config.naming.class(&enum_ident_name),
+        config.naming.factory(&variant_ident_name),
+        super::types::fields_to_cpp_type(&variant.fields, config)?
+    )?;
+    writeln!(
+        f,
+        "    return {}(std::in_place_index<{}>, std::move(value));",
+        config.naming.class(&enum_ident_name),
+        index
+    )?;
+    writeln!(f, "}}")?;
 
+    if variant.fields.len() > 1 {
+        writeln!(f)?;
+        write!(f, "{}", config.inline())?;
         writeln!(
             f,
-            "{}& {}::{}_ref_uncheck() {{",
-            &value_type,
-            enum_ident_name.to_class_name(),
-            variant_ident_name.to_public_member_name(),
+            "{0} {0}::{1}({2}) {{",
+            config.naming.class(&enum_ident_name),
+            config.naming.factory(&variant_ident_name),
+            rvalue_params(&variant.fields, config)?
         )?;
-        writeln!(f, "    assert(tag_ == _Tag::{});", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "    return union_.{}_val;", variant_ident_name.to_public_member_name())?;
-        writeln!(f, "}}")?;
-        writeln!(f)?;
-
-        // ref
         writeln!(
             f,
-            "const {}& {}::{}_ref() const {{",
-            &value_type,
-            enum_ident_name.to_class_name(),
-            variant_ident_name.to_public_member_name(),
+            "    return {}(std::in_place_index<{}>, {});",
+            config.naming.class(&enum_ident_name),
+            index,
+            moved_args(variant.fields.len())
         )?;
-        writeln!(f, "    if (tag_ != _Tag::{}) {{", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "        throw std::runtime_error(\"requires {}\");", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "    }}")?;
-        writeln!(f, "    return union_.{}_val;", variant_ident_name.to_public_member_name())?;
         writeln!(f, "}}")?;
-        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn write_accessors(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+
+    writeln!(f, "// accessors")?;
 
+    let tag = config.tag_expr("");
+
+    for (i, variant) in item.variants.iter().enumerate() {
+        let variant_ident_name = attrs::variant_name(variant)?;
+        let value = config.value_expr("", i, &variant_ident_name);
+
+        // the accessors which can be constexpr are defined in the header of POD classes
+        if !config.pod {
+            // is
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "bool {}::{}() const {{",
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Is, &variant_ident_name)
+            )?;
+            writeln!(f, "    return {} == _Tag::{};", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        if let Fields::Unit = &variant.fields {
+            continue;
+        }
+
+        let value_type = super::types::fields_to_cpp_type(&variant.fields, config)?;
+
+        if !config.pod {
+            // ref_uncheck
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "const {}& {}::{}() const {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::RefUncheck, &variant_ident_name),
+            )?;
+            writeln!(
+                f,
+                "    assert({} == _Tag::{});",
+                tag,
+                config.naming.tag(&variant_ident_name)
+            )?;
+            writeln!(f, "    return {};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "{}& {}::{}() {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::RefUncheck, &variant_ident_name),
+            )?;
+            writeln!(
+                f,
+                "    assert({} == _Tag::{});",
+                tag,
+                config.naming.tag(&variant_ident_name)
+            )?;
+            writeln!(f, "    return {};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            // ref
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "const {}& {}::{}() const {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Ref, &variant_ident_name),
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(
+                f,
+                "        {}",
+                config.panic(&format!("\"requires {}\"", variant.ident.unraw()))
+            )?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return {};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "{}& {}::{}() {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Ref, &variant_ident_name),
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(
+                f,
+                "        {}",
+                config.panic(&format!("\"requires {}\"", variant.ident.unraw()))
+            )?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return {};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            // ptr
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "const {}* {}::{}() const {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Ptr, &variant_ident_name),
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(f, "        return nullptr;")?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return &{};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "{}* {}::{}() {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Ptr, &variant_ident_name),
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(f, "        return nullptr;")?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return &{};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        // as
+        for (constness, make_ref) in [("const ", "std::cref"), ("", "std::ref")] {
+            let option_type = format!(
+                "::cppust::Option<std::reference_wrapper<{}{}>>",
+                constness, &value_type
+            );
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "{} {}::{}() {}{{",
+                &option_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::As, &variant_ident_name),
+                constness
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(f, "        return {}::None();", &option_type)?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return {}::Some({}({}));", &option_type, make_ref, value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        if !config.pod {
+            // expect
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "const {}& {}::{}(const char* msg) const & {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Expect, &variant_ident_name),
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(f, "        {}", config.panic("msg"))?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return {};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "{}& {}::{}(const char* msg) & {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Expect, &variant_ident_name),
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(f, "        {}", config.panic("msg"))?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return {};", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            write!(f, "{}", config.inline())?;
+            writeln!(
+                f,
+                "{} {}::{}(const char* msg) && {{",
+                &value_type,
+                config.naming.class(&enum_ident_name),
+                config.naming.name(NameKind::Expect, &variant_ident_name),
+            )?;
+            writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+            writeln!(f, "        {}", config.panic("msg"))?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "    return std::move({});", value)?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        // into
+        write!(f, "{}", config.inline())?;
         writeln!(
             f,
-            "{}& {}::{}_ref() {{",
+            "::cppust::Option<{}> {}::{}() && {{",
             &value_type,
-            enum_ident_name.to_class_name(),
-            variant_ident_name.to_public_member_name(),
+            config.naming.class(&enum_ident_name),
+            config.naming.name(NameKind::Into, &variant_ident_name),
         )?;
-        writeln!(f, "    if (tag_ != _Tag::{}) {{", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "        throw std::runtime_error(\"requires {}\");", variant_ident_name.to_enum_variant_name())?;
+        writeln!(f, "    if ({} != _Tag::{}) {{", tag, config.naming.tag(&variant_ident_name))?;
+        writeln!(f, "        return ::cppust::Option<{}>::None();", &value_type)?;
         writeln!(f, "    }}")?;
-        writeln!(f, "    return union_.{}_val;", variant_ident_name.to_public_member_name())?;
+        writeln!(f, "    return ::cppust::Option<{}>::Some(std::move({}));", &value_type, value)?;
         writeln!(f, "}}")?;
         writeln!(f)?;
 
-        // ptr
+        // take
+        write!(f, "{}", config.inline())?;
         writeln!(
             f,
-            "const {}* {}::{}_ptr() const {{",
+            "::cppust::Option<{}> {}::{}() & {{",
             &value_type,
-            enum_ident_name.to_class_name(),
-            variant_ident_name.to_public_member_name(),
+            config.naming.class(&enum_ident_name),
+            config.naming.name(NameKind::Take, &variant_ident_name),
         )?;
-        writeln!(f, "    if (tag_ != _Tag::{}) {{", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "        return nullptr;")?;
-        writeln!(f, "    }}")?;
-        writeln!(f, "    return &union_.{}_val;", variant_ident_name.to_public_member_name())?;
-        writeln!(f, "}}")?;
-        writeln!(f)?;
-
         writeln!(
             f,
-            "{}* {}::{}_ptr() {{",
-            &value_type,
-            enum_ident_name.to_class_name(),
-            variant_ident_name.to_public_member_name(),
+            "    return std::move(*this).{}();",
+            config.naming.name(NameKind::Into, &variant_ident_name)
         )?;
-        writeln!(f, "    if (tag_ != _Tag::{}) {{", variant_ident_name.to_enum_variant_name())?;
-        writeln!(f, "        return nullptr;")?;
-        writeln!(f, "    }}")?;
-        writeln!(f, "    return &union_.{}_val;", variant_ident_name.to_public_member_name())?;
         writeln!(f, "}}")?;
         writeln!(f)?;
     }
@@ -430,53 +984,66 @@ fn write_accessors(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
     Ok(())
 }
 
-fn write_private_methods(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
-    let enum_ident_name: IdentName = (&item.ident).into();
+fn write_private_methods(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let enum_ident_name = attrs::enum_name(item)?;
+    let derives = Derives::of(&item.attrs)?;
 
     writeln!(f, "// private methods")?;
 
     // tagged_init_
-    writeln!(f, "void {}::tagged_init_(_Tag tag, const _Union& union_val) {{", enum_ident_name.to_class_name())?;
-    writeln!(f, "    switch (tag) {{")?;
-    for variant in item.variants.iter() {
-        if let Fields::Unit = &variant.fields {
-            continue;
-        }
-
-        let value_type = super::types::fields_to_cpp_type(&variant.fields)?;
-        let variant_ident_name: IdentName = (&variant.ident).into();
-
-        writeln!(f, "    case _Tag::{}:", variant_ident_name.to_enum_variant_name())?;
+    if derives.has(Derive::Clone) {
+        write!(f, "{}", config.inline())?;
         writeln!(
             f,
-            "        new (&union_.{0}_val) {1}(union_val.{0}_val);",
-            variant_ident_name.to_public_member_name(),
-            &value_type
+            "void {}::tagged_init_(_Tag tag, const _Union& union_val) {{",
+            config.naming.class(&enum_ident_name)
         )?;
+        writeln!(f, "    switch (tag) {{")?;
+        for variant in item.variants.iter() {
+            if let Fields::Unit = &variant.fields {
+                continue;
+            }
+
+            let value_type = super::types::fields_to_cpp_type(&variant.fields, config)?;
+            let variant_ident_name = attrs::variant_name(variant)?;
+
+            writeln!(f, "    case _Tag::{}:", config.naming.tag(&variant_ident_name))?;
+            writeln!(
+                f,
+                "        new (&union_.{0}) {1}(union_val.{0});",
+                config.naming.name(NameKind::UnionMember, &variant_ident_name),
+                &value_type
+            )?;
+            writeln!(f, "        break;")?;
+        }
+        writeln!(f, "    default:")?;
         writeln!(f, "        break;")?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+        writeln!(f)?;
     }
-    writeln!(f, "    default:")?;
-    writeln!(f, "        break;")?;
-    writeln!(f, "    }}")?;
-    writeln!(f, "}}")?;
-    writeln!(f)?;
 
     // tagged_init_ (move)
-    writeln!(f, "void {}::tagged_init_(_Tag tag, _Union&& union_val) {{", enum_ident_name.to_class_name())?;
+    write!(f, "{}", config.inline())?;
+    writeln!(
+        f,
+        "void {}::tagged_init_(_Tag tag, _Union&& union_val) {{",
+        config.naming.class(&enum_ident_name)
+    )?;
     writeln!(f, "    switch (tag) {{")?;
     for variant in item.variants.iter() {
         if let Fields::Unit = &variant.fields {
             continue;
         }
 
-        let value_type = super::types::fields_to_cpp_type(&variant.fields)?;
-        let variant_ident_name: IdentName = (&variant.ident).into();
+        let value_type = super::types::fields_to_cpp_type(&variant.fields, config)?;
+        let variant_ident_name = attrs::variant_name(variant)?;
 
-        writeln!(f, "    case _Tag::{}:", variant_ident_name.to_enum_variant_name())?;
+        writeln!(f, "    case _Tag::{}:", config.naming.tag(&variant_ident_name))?;
         writeln!(
             f,
-            "        new (&union_.{0}_val) {1}(std::move(union_val.{0}_val));",
-            variant_ident_name.to_public_member_name(),
+            "        new (&union_.{0}) {1}(std::move(union_val.{0}));",
+            config.naming.name(NameKind::UnionMember, &variant_ident_name),
             &value_type
         )?;
         writeln!(f, "        break;")?;
@@ -488,22 +1055,22 @@ fn write_private_methods(f: &mut dyn Write, item: &ItemEnum) -> Result<()> {
     writeln!(f)?;
 
     // deinit_union_
-    writeln!(f, "void {}::deinit_union_() {{", enum_ident_name.to_class_name())?;
+    write!(f, "{}", config.inline())?;
+    writeln!(f, "void {}::deinit_union_() {{", config.naming.class(&enum_ident_name))?;
     writeln!(f, "    switch (tag_) {{")?;
     for variant in item.variants.iter() {
         if let Fields::Unit = &variant.fields {
             continue;
         }
 
-        let variant_ident_name: IdentName = (&variant.ident).into();
+        let variant_ident_name = attrs::variant_name(variant)?;
 
-        writeln!(f, "    case _Tag::{}:", variant_ident_name.to_enum_variant_name())?;
+        writeln!(f, "    case _Tag::{}:", config.naming.tag(&variant_ident_name))?;
         writeln!(
             f,
-            "        union_.{0}_val.{1}();",
-            variant_ident_name.to_public_member_name(),
-            super::types::fields_to_cpp_dtor(&variant.fields)?,
-        )?;
+            "        union_.{0}.{1}();",
+            config.naming.name(NameKind::UnionMember, &variant_ident_name),
+            super::types::fields_to_cpp_dtor(&variant.fields, config)?)?;
         writeln!(f, "        break;")?;
     }
     writeln!(f, "    default:")?;
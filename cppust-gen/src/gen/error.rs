@@ -1,8 +1,9 @@
 use std::fmt::Debug;
 
-use syn::{Fields, PathSegment, Type};
+use syn::{Expr, Fields, PathSegment, Type};
 
 #[derive(thiserror::Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum Error {
     #[error("config error: [{0}] {1}")]
     ConfigError(String, String),
@@ -16,6 +17,42 @@ pub enum Error {
     #[error("invalid type path segment {0:?}")]
     InvalidTypePathSegment(PathSegment),
 
+    #[error("unsupported expression {0:?}")]
+    UnsupportedExpr(Expr),
+
+    #[error("derive error: [{0}] {1}")]
+    DeriveError(String, String),
+
+    #[error("attribute error: [{0}] {1}")]
+    AttrError(String, String),
+
+    #[error("naming error: [{0}] {1}")]
+    NamingError(String, String),
+
+    #[error("const error: [{0}] {1}")]
+    ConstError(String, String),
+
+    #[error("type alias error: [{0}] {1}")]
+    TypeAliasError(String, String),
+
+    #[error("method error: [{0}] {1}")]
+    MethodError(String, String),
+
+    #[error("ffi error: [{0}] {1}")]
+    FfiError(String, String),
+
+    #[error("layout error: [{0}] {1}")]
+    LayoutError(String, String),
+
+    #[error("wire error: [{0}] {1}")]
+    WireError(String, String),
+
+    #[error("test error: [{0}] {1}")]
+    TestError(String, String),
+
+    #[error("user code error: [{0}] {1}")]
+    UserCodeError(String, String),
+
     #[error(transparent)]
     IoError(#[from] std::io::Error),
 
@@ -0,0 +1,601 @@
+use std::io::Write;
+
+use syn::{ext::IdentExt, Field, Fields, ItemEnum, Type, Variant};
+
+use super::{
+    attrs::{self, FieldAttrs},
+    config::Config,
+    error::{Error, Result},
+    names::{IdentName, NameKind},
+    types,
+};
+
+/// How a payload crosses the FFI boundary
+#[derive(Clone, Debug, PartialEq)]
+enum FfiType {
+    /// Numbers and `bool`, which have the same layout on both sides, with their Rust and C++
+    /// types
+    Plain(String, String),
+    /// `char`, a `u32` in Rust and a `char32_t` in C++
+    Char,
+    /// `String`, handed over as `cppust::ffi::String`
+    Str,
+    /// Another enum of the input, by Rust ident
+    Enum(String),
+}
+
+impl FfiType {
+    fn of(field: &Field, item: &ItemEnum, config: &Config) -> Result<Self> {
+        if FieldAttrs::of(field)?.ty.is_some() {
+            return Err(Error::FfiError(
+                item.ident.to_string(),
+                "fields with #[cppust(type)] have no C layout".to_owned(),
+            ));
+        }
+
+        Self::of_type(&field.ty, config).ok_or_else(|| {
+            Error::FfiError(
+                item.ident.to_string(),
+                format!(
+                    "{} has no C layout, use numbers, bool, char, String or enums of the input",
+                    types::type_to_cpp_type(&field.ty, config)
+                        .unwrap_or_else(|_| "the payload type".to_owned())
+                ),
+            )
+        })
+    }
+
+    fn of_type(ty: &Type, config: &Config) -> Option<Self> {
+        let ident = match ty {
+            Type::Path(path) if path.qself.is_none() => path.path.get_ident()?.to_string(),
+            _ => return None,
+        };
+
+        match ident.as_str() {
+            "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "f32" | "f64"
+            | "usize" | "isize" | "bool" => {
+                let cpp_type = types::type_to_cpp_type(ty, config).ok()?;
+                Some(Self::Plain(ident, cpp_type))
+            }
+            "char" => Some(Self::Char),
+            "String" => Some(Self::Str),
+            _ if config.classes.contains_key(&ident) => Some(Self::Enum(ident)),
+            // aliases have the layout of the type they stand for
+            _ => match config.aliases.get(&ident) {
+                Some(alias) if alias.params.is_empty() => Self::of_type(&alias.ty, config),
+                _ => None,
+            },
+        }
+    }
+
+    fn rust_type(&self) -> String {
+        match self {
+            FfiType::Plain(rust_type, _) => rust_type.clone(),
+            FfiType::Char => "u32".to_owned(),
+            FfiType::Str => "FfiString".to_owned(),
+            FfiType::Enum(ident) => mirror_name(ident),
+        }
+    }
+
+    fn cpp_type(&self) -> String {
+        match self {
+            FfiType::Plain(_, cpp_type) => cpp_type.clone(),
+            FfiType::Char => "char32_t".to_owned(),
+            FfiType::Str => "::cppust::ffi::String".to_owned(),
+            FfiType::Enum(ident) => mirror_name(ident),
+        }
+    }
+
+    /// C++ expression of the C layout of the C++ payload `value`
+    fn cpp_to_ffi(&self, value: &str, module: &str) -> String {
+        match self {
+            FfiType::Plain(..) | FfiType::Char => value.to_owned(),
+            FfiType::Str => format!(
+                "{}(reinterpret_cast<const ::cppust::u8*>({1}.data()), {1}.size())",
+                string_new(module),
+                value
+            ),
+            FfiType::Enum(_) => format!("to_ffi({})", value),
+        }
+    }
+
+    /// C++ expression of the C++ payload of the C layout `value`
+    fn cpp_from_ffi(&self, value: &str) -> String {
+        match self {
+            FfiType::Plain(..) | FfiType::Char => value.to_owned(),
+            FfiType::Str => format!("::cppust::ffi::to_string({})", value),
+            FfiType::Enum(_) => format!("from_ffi({})", value),
+        }
+    }
+}
+
+/// Name of the `#[repr(C)]` mirror of the enum `ident`, on both sides
+fn mirror_name(ident: &str) -> String {
+    format!("{}Ffi", ident)
+}
+
+fn string_new(module: &str) -> String {
+    format!("{}_ffi_string_new", symbol(module))
+}
+
+fn drop_fn(item: &ItemEnum) -> String {
+    format!("{}_ffi_drop", IdentName::from(&item.ident).to_snake())
+}
+
+/// `module` as part of a C symbol
+pub fn symbol(module: &str) -> String {
+    module
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn payloads(item: &ItemEnum, config: &Config) -> Result<Vec<Vec<FfiType>>> {
+    if item.variants.is_empty() {
+        return Err(Error::FfiError(
+            item.ident.to_string(),
+            "enums without variants have no C layout".to_owned(),
+        ));
+    }
+
+    item.variants
+        .iter()
+        .map(|variant| match &variant.fields {
+            Fields::Named(_) => Err(Error::UnknownFieldsType(variant.fields.clone())),
+            fields => fields
+                .iter()
+                .map(|field| FfiType::of(field, item, config))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Writes `<module>_ffi.rs`, the `#[repr(C)]` mirrors of `items` with their conversions, which
+/// is a child module of the module declaring the enums
+pub fn write_rust(f: &mut dyn Write, items: &[&(&ItemEnum, Config)], module: &str) -> Result<()> {
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f, "//")?;
+    writeln!(
+        f,
+        "// `#[repr(C)]` mirrors of the enums of `{}`, include it as a child module of theirs.",
+        module
+    )?;
+    writeln!(f)?;
+    writeln!(f, "use super::*;")?;
+    writeln!(f)?;
+
+    writeln!(
+        f,
+        "/// A `String` handed over the FFI boundary, `cppust::ffi::String` in C++"
+    )?;
+    writeln!(f, "#[repr(C)]")?;
+    writeln!(f, "#[derive(Clone, Copy, Debug)]")?;
+    writeln!(f, "pub struct FfiString {{")?;
+    writeln!(f, "    pub ptr: *mut u8,")?;
+    writeln!(f, "    pub len: usize,")?;
+    writeln!(f, "    pub cap: usize,")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    writeln!(f, "impl From<String> for FfiString {{")?;
+    writeln!(f, "    fn from(value: String) -> Self {{")?;
+    writeln!(
+        f,
+        "        let mut value = std::mem::ManuallyDrop::new(value);"
+    )?;
+    writeln!(f, "        FfiString {{")?;
+    writeln!(f, "            ptr: value.as_mut_ptr(),")?;
+    writeln!(f, "            len: value.len(),")?;
+    writeln!(f, "            cap: value.capacity(),")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    writeln!(f, "impl From<FfiString> for String {{")?;
+    writeln!(f, "    fn from(value: FfiString) -> Self {{")?;
+    writeln!(
+        f,
+        "        // SAFETY: the buffer comes from `From<String>` or `{}`",
+        string_new(module)
+    )?;
+    writeln!(
+        f,
+        "        unsafe {{ String::from_raw_parts(value.ptr, value.len, value.cap) }}"
+    )?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "/// Copies `len` bytes at `ptr` into a Rust `String`, for the C++ side"
+    )?;
+    writeln!(f, "#[no_mangle]")?;
+    writeln!(
+        f,
+        "pub extern \"C\" fn {}(ptr: *const u8, len: usize) -> FfiString {{",
+        string_new(module)
+    )?;
+    writeln!(
+        f,
+        "    // SAFETY: the C++ side passes the bytes of a `std::string`"
+    )?;
+    writeln!(
+        f,
+        "    let bytes = unsafe {{ std::slice::from_raw_parts(ptr, len) }};"
+    )?;
+    writeln!(f, "    String::from_utf8_lossy(bytes).into_owned().into()")?;
+    writeln!(f, "}}")?;
+
+    for (item, config) in items.iter().copied() {
+        writeln!(f)?;
+        write_rust_mirror(f, item, config)?;
+    }
+
+    Ok(())
+}
+
+fn write_rust_mirror(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let ident = item.ident.to_string();
+    let mirror = mirror_name(&item.ident.unraw().to_string());
+    let payloads = payloads(item, config)?;
+
+    writeln!(
+        f,
+        "/// C layout of [`{}`], the tag is the index of the variant",
+        ident
+    )?;
+    writeln!(f, "#[repr(C, u32)]")?;
+    writeln!(f, "#[derive(Clone, Copy, Debug)]")?;
+    writeln!(f, "pub enum {} {{", mirror)?;
+    for (variant, payload) in item.variants.iter().zip(payloads.iter()) {
+        if payload.is_empty() {
+            writeln!(f, "    {},", variant.ident)?;
+        } else {
+            writeln!(
+                f,
+                "    {}({}),",
+                variant.ident,
+                payload
+                    .iter()
+                    .map(FfiType::rust_type)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+    }
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    for (from, to, convert) in [
+        (ident.as_str(), mirror.as_str(), true),
+        (mirror.as_str(), ident.as_str(), false),
+    ] {
+        writeln!(f, "impl From<{}> for {} {{", from, to)?;
+        writeln!(f, "    fn from(value: {}) -> Self {{", from)?;
+        writeln!(f, "        match value {{")?;
+        for (variant, payload) in item.variants.iter().zip(payloads.iter()) {
+            if payload.is_empty() {
+                writeln!(
+                    f,
+                    "            {0}::{2} => {1}::{2},",
+                    from, to, variant.ident
+                )?;
+                continue;
+            }
+
+            let bindings: Vec<_> = (0..payload.len()).map(|i| format!("_{}", i)).collect();
+            let values: Vec<_> = bindings
+                .iter()
+                .zip(payload.iter())
+                .map(|(binding, ty)| match ty {
+                    FfiType::Char if !convert => format!(
+                        "char::from_u32({}).unwrap_or(char::REPLACEMENT_CHARACTER)",
+                        binding
+                    ),
+                    _ => format!("{}.into()", binding),
+                })
+                .collect();
+            writeln!(
+                f,
+                "            {0}::{2}({3}) => {1}::{2}({4}),",
+                from,
+                to,
+                variant.ident,
+                bindings.join(", "),
+                values.join(", ")
+            )?;
+        }
+        writeln!(f, "        }}")?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+    }
+
+    writeln!(
+        f,
+        "/// Releases the Rust allocations of `value`, for the C++ side"
+    )?;
+    writeln!(f, "#[no_mangle]")?;
+    writeln!(
+        f,
+        "pub extern \"C\" fn {}(value: {}) {{",
+        drop_fn(item),
+        mirror
+    )?;
+    writeln!(f, "    drop({}::from(value));", ident)?;
+    writeln!(f, "}}")?;
+
+    Ok(())
+}
+
+/// Writes `<module>_ffi.hpp`, the C layouts of `items` with the conversions from and to their
+/// classes, `includes` declare the classes
+pub fn write_header(
+    f: &mut dyn Write,
+    items: &[&(&ItemEnum, Config)],
+    module: &str,
+    includes: &[String],
+    config: &Config,
+) -> Result<()> {
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+
+    writeln!(f, "#pragma once")?;
+    writeln!(f)?;
+
+    write!(f, "{}", config.library_defines())?;
+    writeln!(f, "#include <cppust/ffi.hpp>")?;
+    writeln!(f, "#include <cppust/panic.hpp>")?;
+    writeln!(f, "#include <cppust/types.hpp>")?;
+    writeln!(f)?;
+    for include in includes.iter() {
+        writeln!(f, "#include \"{}\"", include)?;
+    }
+    writeln!(f)?;
+
+    let namespace: Vec<_> = config
+        .namespace
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once("ffi"))
+        .collect();
+    writeln!(
+        f,
+        "{}",
+        namespace
+            .iter()
+            .map(|ns| format!("namespace {} {{", ns))
+            .collect::<Vec<String>>()
+            .join(" ")
+    )?;
+    writeln!(f)?;
+
+    writeln!(
+        f,
+        "/// @brief Copies `len` bytes at `ptr` into a Rust `String`"
+    )?;
+    writeln!(
+        f,
+        "extern \"C\" ::cppust::ffi::String {}(const ::cppust::u8* ptr, ::cppust::usize len);",
+        string_new(module)
+    )?;
+    writeln!(f)?;
+
+    for (item, config) in items.iter().copied() {
+        write_cpp_layout(f, item, config)?;
+    }
+    for (item, config) in items.iter().copied() {
+        write_cpp_conversions(f, item, module, config)?;
+    }
+
+    writeln!(
+        f,
+        "{}",
+        namespace.iter().map(|_| "}").collect::<Vec<_>>().join(" ")
+    )?;
+
+    Ok(())
+}
+
+fn write_cpp_layout(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let mirror = mirror_name(&item.ident.unraw().to_string());
+    let payloads = payloads(item, config)?;
+
+    writeln!(
+        f,
+        "/// @brief C layout of `{}`, `{}` in Rust",
+        item.ident.unraw(),
+        mirror
+    )?;
+    writeln!(f, "struct {} {{", mirror)?;
+    writeln!(f, "    ::cppust::u32 tag;")?;
+    // unit variants are empty in Rust, and would take a byte in C++
+    if payloads.iter().any(|payload| !payload.is_empty()) {
+        writeln!(f, "    union {{")?;
+        for (variant, payload) in item.variants.iter().zip(payloads.iter()) {
+            if payload.is_empty() {
+                continue;
+            }
+
+            writeln!(f, "        struct {{")?;
+            for (i, ty) in payload.iter().enumerate() {
+                writeln!(f, "            {} _{};", ty.cpp_type(), i)?;
+            }
+            writeln!(f, "        }} {};", member(variant, config)?)?;
+        }
+        writeln!(f, "    }} payload;")?;
+    }
+    writeln!(f, "}};")?;
+    writeln!(f)?;
+
+    writeln!(f, "/// @brief Releases the Rust allocations of `value`")?;
+    writeln!(f, "extern \"C\" void {}({} value);", drop_fn(item), mirror)?;
+    writeln!(f)?;
+
+    Ok(())
+}
+
+fn write_cpp_conversions(
+    f: &mut dyn Write,
+    item: &ItemEnum,
+    module: &str,
+    config: &Config,
+) -> Result<()> {
+    let class = config.class_ref(&item.ident.to_string());
+    let mirror = mirror_name(&item.ident.unraw().to_string());
+    let payloads = payloads(item, config)?;
+
+    writeln!(
+        f,
+        "/// @brief C layout of `value`, its strings are copied into Rust allocations"
+    )?;
+    writeln!(f, "inline {} to_ffi(const {}& value) {{", mirror, class)?;
+    writeln!(f, "    {} ffi = {{}};", mirror)?;
+    for (index, (variant, payload)) in item.variants.iter().zip(payloads.iter()).enumerate() {
+        let variant_name = attrs::variant_name(variant)?;
+        writeln!(
+            f,
+            "    {}if (value.{}()) {{",
+            if index == 0 { "" } else { "} else " },
+            config.naming.name(NameKind::Is, &variant_name)
+        )?;
+        writeln!(f, "        ffi.tag = {};", index)?;
+
+        let accessor = format!(
+            "value.{}()",
+            config.naming.name(NameKind::RefUncheck, &variant_name)
+        );
+        for (i, ty) in payload.iter().enumerate() {
+            let value = if payload.len() == 1 {
+                accessor.clone()
+            } else {
+                format!("std::get<{}>({})", i, accessor)
+            };
+            writeln!(
+                f,
+                "        ffi.payload.{}._{} = {};",
+                member(variant, config)?,
+                i,
+                ty.cpp_to_ffi(&value, module)
+            )?;
+        }
+    }
+    writeln!(f, "    }} else {{")?;
+    writeln!(
+        f,
+        "        CPPUST_PANIC(\"a valueless {} has no C layout\");",
+        item.ident
+    )?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "    return ffi;")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(
+        f,
+        "/// @brief Class of the C layout `value`, which keeps its Rust allocations"
+    )?;
+    writeln!(f, "inline {} from_ffi(const {}& value) {{", class, mirror)?;
+    writeln!(f, "    switch (value.tag) {{")?;
+    for (index, (variant, payload)) in item.variants.iter().zip(payloads.iter()).enumerate() {
+        let variant_name = attrs::variant_name(variant)?;
+        writeln!(f, "    case {}:", index)?;
+        writeln!(
+            f,
+            "        return {}::{}({});",
+            class,
+            config.naming.factory(&variant_name),
+            payload
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| Ok(ty.cpp_from_ffi(&format!(
+                    "value.payload.{}._{}",
+                    member(variant, config)?,
+                    i
+                ))))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        )?;
+    }
+    writeln!(f, "    default:")?;
+    writeln!(
+        f,
+        "        {}",
+        config.panic(&format!("\"invalid tag of {}\"", mirror))
+    )?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(
+        f,
+        "/// @brief Class of the C layout `value` from Rust, releasing its Rust allocations"
+    )?;
+    writeln!(f, "inline {} take_ffi({} value) {{", class, mirror)?;
+    writeln!(f, "    {} result = from_ffi(value);", class)?;
+    writeln!(f, "    {}(value);", drop_fn(item))?;
+    writeln!(f, "    return result;")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    Ok(())
+}
+
+/// Member of the payload union holding `variant`
+fn member(variant: &Variant, config: &Config) -> Result<String> {
+    Ok(config
+        .naming
+        .parameter(&attrs::variant_name(variant)?.to_snake()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::gen::config::{UserAlias, UserClass};
+
+    fn ffi_type(ty: &str) -> Option<FfiType> {
+        let alias: syn::ItemType = syn::parse_str("type Id = u16;").unwrap();
+        let mut classes = HashMap::new();
+        classes.insert(
+            "Frame".to_owned(),
+            UserClass {
+                namespace: Vec::new(),
+                name: "Frame".into(),
+                layout: false,
+                pod: false,
+            },
+        );
+        let config = Config {
+            classes,
+            aliases: std::iter::once((
+                "Id".to_owned(),
+                UserAlias::of(&alias, &[], "a_types.hpp").unwrap(),
+            ))
+            .collect(),
+            ..Config::default()
+        };
+        FfiType::of_type(&syn::parse_str(ty).unwrap(), &config)
+    }
+
+    #[test]
+    fn test_ffi_types() {
+        assert_eq!(
+            ffi_type("u16"),
+            Some(FfiType::Plain("u16".to_owned(), "::cppust::u16".to_owned()))
+        );
+        assert_eq!(ffi_type("Id"), ffi_type("u16"));
+        assert_eq!(ffi_type("char"), Some(FfiType::Char));
+        assert_eq!(ffi_type("String"), Some(FfiType::Str));
+        assert_eq!(ffi_type("Frame"), Some(FfiType::Enum("Frame".to_owned())));
+        for ty in ["Vec<u8>", "Option<u8>", "(u8, u8)", "&str", "Unknown"] {
+            assert_eq!(ffi_type(ty), None, "{}", ty);
+        }
+    }
+}
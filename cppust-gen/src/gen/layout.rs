@@ -0,0 +1,300 @@
+use std::io::Write;
+
+use syn::{Fields, ItemEnum, Meta, NestedMeta, Type};
+
+use super::{
+    attrs,
+    config::{Backend, Config, Repr},
+    error::{Error, Result},
+    types,
+};
+
+/// Integer types a tag may have, as in `#[repr(C, u8)]`
+const TAG_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "usize", "isize",
+];
+
+/// Payload types with the same layout in Rust and C++, besides enums marked `#[cppust(layout)]`
+pub const POD_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64", "usize", "isize", "bool",
+    "char",
+];
+
+impl Repr {
+    /// The `#[repr(C)]` or `#[repr(C, Int)]` of `item`, other representations have no
+    /// counterpart in the generated class
+    pub fn of(item: &ItemEnum) -> Result<Self> {
+        let error = |msg: &str| Error::LayoutError(item.ident.to_string(), msg.to_owned());
+
+        let mut c = false;
+        let mut tag = None;
+        for attr in item.attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                _ => return Err(error("expected #[repr(...)]")),
+            };
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C") => c = true,
+                    NestedMeta::Meta(Meta::Path(path))
+                        if TAG_TYPES.iter().any(|ty| path.is_ident(ty)) =>
+                    {
+                        tag = path.get_ident().map(|ident| ident.to_string())
+                    }
+                    _ => return Err(error("only #[repr(C)] and #[repr(C, Int)] are supported")),
+                }
+            }
+        }
+
+        if !c {
+            return Err(error(
+                "#[cppust(layout)] requires #[repr(C)] or #[repr(C, Int)] on the enum",
+            ));
+        }
+        if item
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.fields, Fields::Unit))
+        {
+            return Err(error(
+                "enums without payloads are not supported, their layout is the bare tag",
+            ));
+        }
+
+        Ok(Self { tag })
+    }
+}
+
+/// Checks that the payloads of `item` have the same layout in Rust and C++
+pub fn validate(item: &ItemEnum, config: &Config) -> Result<()> {
+    let error = |msg: String| Error::LayoutError(item.ident.to_string(), msg);
+
+    if config.backend != Backend::Union {
+        return Err(error(
+            "#[cppust(layout)] requires the union backend".to_owned(),
+        ));
+    }
+
+    for variant in item.variants.iter() {
+        if variant.fields.len() > 1 {
+            return Err(error(format!(
+                "{} has several fields, a std::tuple has no Rust layout",
+                variant.ident
+            )));
+        }
+        for field in variant.fields.iter() {
+            if attrs::FieldAttrs::of(field)?.ty.is_some() {
+                return Err(error(format!(
+                    "{} has a #[cppust(type)] field, which has no Rust layout",
+                    variant.ident
+                )));
+            }
+            if !is_pod(&field.ty, config) {
+                return Err(error(format!(
+                    "{} has no Rust layout, use numbers, bool, char or enums marked \
+                     #[cppust(layout)]",
+                    types::type_to_cpp_type(&field.ty, config)
+                        .unwrap_or_else(|_| "the payload type".to_owned())
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_pod(ty: &Type, config: &Config) -> bool {
+    let ident = match ty {
+        Type::Path(path) if path.qself.is_none() => match path.path.get_ident() {
+            Some(ident) => ident.to_string(),
+            None => return false,
+        },
+        Type::Paren(paren) => return is_pod(&paren.elem, config),
+        _ => return false,
+    };
+
+    if POD_TYPES.contains(&ident.as_str()) {
+        return true;
+    }
+    if let Some(class) = config.classes.get(&ident) {
+        return class.layout;
+    }
+    match config.aliases.get(&ident) {
+        Some(alias) if alias.params.is_empty() => is_pod(&alias.ty, config),
+        _ => false,
+    }
+}
+
+/// C++ underlying type of the tag, `None` keeps the `int` of plain `#[repr(C)]`
+pub fn tag_type(config: &Config) -> Option<String> {
+    config
+        .repr
+        .as_ref()?
+        .tag
+        .as_ref()
+        .map(|tag| format!("::cppust::{}", tag))
+}
+
+/// Value of `_Tag::_Valueless`, the state after a payload threw while replacing the value, which
+/// must fit the tag without being any of the discriminant `values`: the one after the largest, or
+/// the first one left when that is the largest value of the tag
+pub fn valueless_value(item: &ItemEnum, values: &[i128], config: &Config) -> Result<i128> {
+    // plain `#[repr(C)]` and classes without a layout have an `int` tag
+    let tag = config
+        .repr
+        .as_ref()
+        .and_then(|repr| repr.tag.as_deref())
+        .unwrap_or("i32");
+    let (min, max): (i128, i128) = match tag {
+        "u8" => (0, u8::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "u32" => (0, u32::MAX.into()),
+        "u64" | "usize" => (0, u64::MAX.into()),
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i64" | "isize" => (i64::MIN.into(), i64::MAX.into()),
+        _ => (i32::MIN.into(), i32::MAX.into()),
+    };
+
+    let last = values.iter().copied().max().unwrap_or(-1);
+    if last < max {
+        return Ok(last + 1);
+    }
+    (min..=max)
+        .find(|value| !values.contains(value))
+        .ok_or_else(|| {
+            Error::LayoutError(
+                item.ident.to_string(),
+                format!(
+                    "every {} value is a variant, none is left for the valueless state",
+                    tag
+                ),
+            )
+        })
+}
+
+/// Writes a private member function asserting that the class has the layout of the Rust enum,
+/// as specified by RFC 2195: the tag, then a union of the payloads
+pub fn write_asserts(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let class = config.naming.class(&attrs::enum_name(item)?);
+    let payload_types = item
+        .variants
+        .iter()
+        .filter(|variant| !matches!(variant.fields, Fields::Unit))
+        .map(|variant| types::fields_to_cpp_type(&variant.fields, config))
+        .collect::<Result<Vec<_>>>()?;
+
+    writeln!(
+        f,
+        "    // the layout of the Rust enum, checked when compiling"
+    )?;
+    writeln!(f, "    static void check_layout_() {{")?;
+    writeln!(
+        f,
+        "        using _Layout = ::cppust::utils::repr_c_layout<_Tag, {}>;",
+        payload_types.join(", ")
+    )?;
+    writeln!(
+        f,
+        "        static_assert(std::is_standard_layout<{0}>::value, \
+         \"{0} must be standard-layout\");",
+        class
+    )?;
+    for (expr, expected, what) in [
+        ("sizeof({})", "_Layout::size", "size"),
+        ("alignof({})", "_Layout::align", "alignment"),
+        ("offsetof({}, tag_)", "0", "offset of the tag"),
+        (
+            "offsetof({}, union_)",
+            "_Layout::payload_offset",
+            "offset of the payload",
+        ),
+    ] {
+        writeln!(
+            f,
+            "        static_assert({} == {}, \"{} of {} differs from Rust\");",
+            expr.replace("{}", &class),
+            expected,
+            what,
+            class
+        )?;
+    }
+    writeln!(f, "    }}")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn repr(code: &str) -> Result<Repr> {
+        Repr::of(&syn::parse_str(code).unwrap())
+    }
+
+    #[test]
+    fn test_repr() {
+        assert_eq!(
+            repr("#[repr(C, u8)] enum A { B(u8), C }").unwrap(),
+            Repr {
+                tag: Some("u8".to_owned())
+            }
+        );
+        assert_eq!(
+            repr("#[derive(Debug)] #[repr(C)] enum A { B(u8) }").unwrap(),
+            Repr { tag: None }
+        );
+        for code in [
+            "enum A { B(u8) }",
+            "#[repr(u8)] enum A { B(u8) }",
+            "#[repr(C, packed)] enum A { B(u8) }",
+            "#[repr(C, u8)] enum A { B, C }",
+        ] {
+            assert!(repr(code).is_err(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_valueless_value() {
+        let valueless = |code: &str, values: &[i128]| {
+            let item: ItemEnum = syn::parse_str(code).unwrap();
+            let config = Config {
+                repr: Some(Repr::of(&item).unwrap()),
+                ..Config::default()
+            };
+            valueless_value(&item, values, &config)
+        };
+
+        let code = "#[repr(C, u8)] enum A { B(u8) }";
+        assert_eq!(valueless(code, &[0, 3]).unwrap(), 4);
+        assert_eq!(valueless(code, &[0, 255]).unwrap(), 1);
+        assert!(valueless(code, &(0..=255).collect::<Vec<_>>()).is_err());
+        assert_eq!(
+            valueless("#[repr(C, i8)] enum A { B(u8) }", &[127]).unwrap(),
+            -128
+        );
+        assert_eq!(
+            valueless("#[repr(C)] enum A { B(u8) }", &[255]).unwrap(),
+            256
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let validate = |code: &str| {
+            let item: ItemEnum = syn::parse_str(code).unwrap();
+            validate(&item, &Config::default())
+        };
+
+        assert!(validate("enum A { B(u8), C(char), D(bool), E(f64), F }").is_ok());
+        for code in [
+            "enum A { B(u8, u8) }",
+            "enum A { B(String) }",
+            "enum A { B(Option<u8>) }",
+            "enum A { B(Other) }",
+            "enum A { B(#[cppust(type = \"int\")] u8) }",
+        ] {
+            assert!(validate(code).is_err(), "{}", code);
+        }
+    }
+}
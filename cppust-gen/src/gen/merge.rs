@@ -0,0 +1,153 @@
+/// Result of a three-way merge, `text` holds conflict markers when `conflicts` is not zero
+#[derive(Clone, Debug, PartialEq)]
+pub struct Merge {
+    pub text: String,
+    pub conflicts: usize,
+}
+
+/// Merges the changes from `base` to `ours`, the user edits, and from `base` to `theirs`, the
+/// newly generated file, line by line like `diff3 -m`. Changes to different lines are both
+/// kept, overlapping changes are left between `<<<<<<<`, `=======` and `>>>>>>>` markers.
+pub fn merge(base: &str, ours: &str, theirs: &str, labels: (&str, &str)) -> Merge {
+    let base: Vec<_> = base.lines().collect();
+    let ours: Vec<_> = ours.lines().collect();
+    let theirs: Vec<_> = theirs.lines().collect();
+
+    let base_ours = matching_lines(&base, &ours);
+    let base_theirs = matching_lines(&base, &theirs);
+    let stable = |b: usize, o: usize, t: usize| {
+        b < base.len() && base_ours[b] == Some(o) && base_theirs[b] == Some(t)
+    };
+
+    let mut text = String::new();
+    let mut push = |lines: &[&str]| {
+        for line in lines {
+            text.push_str(line);
+            text.push('\n');
+        }
+    };
+    let mut conflicts = 0;
+    let (mut b, mut o, mut t) = (0, 0, 0);
+
+    loop {
+        // lines unchanged on both sides are copied
+        let start = b;
+        while stable(b, o, t) {
+            b += 1;
+            o += 1;
+            t += 1;
+        }
+        push(&base[start..b]);
+        if b == base.len() && o == ours.len() && t == theirs.len() {
+            break;
+        }
+
+        // the changed chunk ends at the next base line kept by both sides
+        let (end_b, end_o, end_t) = (b..base.len())
+            .find_map(|i| match (base_ours[i], base_theirs[i]) {
+                (Some(j), Some(k)) if j >= o && k >= t => Some((i, j, k)),
+                _ => None,
+            })
+            .unwrap_or((base.len(), ours.len(), theirs.len()));
+
+        let base_chunk = &base[b..end_b];
+        let ours_chunk = &ours[o..end_o];
+        let theirs_chunk = &theirs[t..end_t];
+
+        if ours_chunk == base_chunk || ours_chunk == theirs_chunk {
+            push(theirs_chunk);
+        } else if theirs_chunk == base_chunk {
+            push(ours_chunk);
+        } else {
+            conflicts += 1;
+            push(&[&format!("<<<<<<< {}", labels.0)]);
+            push(ours_chunk);
+            push(&["======="]);
+            push(theirs_chunk);
+            push(&[&format!(">>>>>>> {}", labels.1)]);
+        }
+
+        b = end_b;
+        o = end_o;
+        t = end_t;
+    }
+
+    Merge { text, conflicts }
+}
+
+/// For each line of `a`, the index of the line of `b` it is kept as in a longest common
+/// subsequence of both
+fn matching_lines(a: &[&str], b: &[&str]) -> Vec<Option<usize>> {
+    // lengths[i][j] is the length of the longest common subsequence of a[i..] and b[j..]
+    let mut lengths = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if a[i] == b[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut matches = vec![None; a.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            matches[i] = Some(j);
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LABELS: (&str, &str) = ("status.hpp", "cppust-gen");
+
+    #[test]
+    fn test_merge() {
+        let base =
+            "#pragma once\n\n#include <a>\n\nclass A {\n    // WRITE YOUR OWN CODE HERE\n};\n";
+        let ours = "#pragma once\n// mine\n\n#include <a>\n\nclass A {\n    int f();\n};\n";
+        let theirs = "#pragma once\n\n#include <b>\n\nnamespace n {\nclass A {\n    // WRITE YOUR OWN CODE HERE\n};\n}\n";
+
+        assert_eq!(
+            merge(base, ours, theirs, LABELS),
+            Merge {
+                text: "#pragma once\n// mine\n\n#include <b>\n\nnamespace n {\nclass A {\n    int f();\n};\n}\n"
+                    .to_owned(),
+                conflicts: 0,
+            }
+        );
+
+        // unchanged sides take the other one
+        assert_eq!(merge(base, base, theirs, LABELS).text, theirs);
+        assert_eq!(merge(base, ours, base, LABELS).text, ours);
+        assert_eq!(merge(base, theirs, theirs, LABELS).text, theirs);
+    }
+
+    #[test]
+    fn test_merge_conflicts() {
+        let merged = merge("a\nb\nc\n", "a\nx\nc\n", "a\ny\nc\n", LABELS);
+        assert_eq!(
+            merged.text,
+            "a\n<<<<<<< status.hpp\nx\n=======\ny\n>>>>>>> cppust-gen\nc\n"
+        );
+        assert_eq!(merged.conflicts, 1);
+
+        let merged = merge("a\n", "a\nx\n", "a\ny\n", LABELS);
+        assert_eq!(
+            merged.text,
+            "a\n<<<<<<< status.hpp\nx\n=======\ny\n>>>>>>> cppust-gen\n"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::io::Write;
+
+use syn::{
+    ext::IdentExt, FnArg, ImplItemMethod, ItemEnum, Pat, ReturnType, Type, TypePath, Visibility,
+};
+
+use super::{
+    attrs::MethodAttrs,
+    config::Config,
+    docs::Docs,
+    error::{Error, Result},
+    types,
+};
+
+/// Writes the declarations of the methods of `item`, public ones first, which the user defines
+pub fn write_declarations(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let mut public = Vec::new();
+    let mut private = Vec::new();
+
+    for method in config.methods.iter() {
+        let attrs = MethodAttrs::of(method)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let declaration = declaration(method, &attrs, item, config).map_err(|err| {
+            Error::MethodError(format!("{}::{}", item.ident, method.sig.ident), err)
+        })?;
+        match method.vis {
+            Visibility::Inherited => private.push((method, declaration)),
+            _ => public.push((method, declaration)),
+        }
+    }
+
+    for (access, methods) in [("public", public), ("private", private)] {
+        if methods.is_empty() {
+            continue;
+        }
+
+        writeln!(f, "{}: // methods", access)?;
+        for (method, declaration) in methods {
+            Docs::of(&method.attrs, item, config).write(f, "    ")?;
+            writeln!(f, "    {};", declaration)?;
+        }
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+/// Types of the arguments and results of the declared methods
+pub fn signature_types(config: &Config) -> Vec<&Type> {
+    config
+        .methods
+        .iter()
+        .filter(|method| !MethodAttrs::of(method).is_ok_and(|attrs| attrs.skip))
+        .flat_map(|method| {
+            let output = match &method.sig.output {
+                ReturnType::Type(_, ty) => Some(&**ty),
+                ReturnType::Default => None,
+            };
+            method
+                .sig
+                .inputs
+                .iter()
+                .filter_map(|input| match input {
+                    FnArg::Typed(typed) => Some(&*typed.ty),
+                    FnArg::Receiver(_) => None,
+                })
+                .chain(output)
+        })
+        .collect()
+}
+
+/// Whether the method signatures refer to the Rust type `ident`, `str` is declared as `String`
+pub fn uses_type(config: &Config, ident: &str) -> bool {
+    signature_types(config).iter().any(|ty| {
+        types::type_uses_type(ty, ident) || (ident == "String" && types::type_uses_type(ty, "str"))
+    })
+}
+
+/// Rust idents of the other enums of the input that the method signatures of `item` refer to,
+/// sorted
+pub fn user_types(item: &ItemEnum, config: &Config) -> Vec<String> {
+    let mut idents: Vec<_> = config
+        .classes
+        .keys()
+        .filter(|ident| item.ident != ident.as_str() && uses_type(config, ident))
+        .cloned()
+        .collect();
+    idents.sort();
+    idents
+}
+
+/// `&self` methods are `const`, `self` methods consume an rvalue and methods without receiver
+/// are `static`
+fn declaration(
+    method: &ImplItemMethod,
+    attrs: &MethodAttrs,
+    item: &ItemEnum,
+    config: &Config,
+) -> std::result::Result<String, String> {
+    let sig = &method.sig;
+    if !sig.generics.params.is_empty() {
+        return Err("generic methods are not supported".to_owned());
+    }
+    if sig.asyncness.is_some() {
+        return Err("async methods are not supported".to_owned());
+    }
+
+    let mut qualifier = None;
+    let mut args = Vec::new();
+    for input in sig.inputs.iter() {
+        match input {
+            FnArg::Receiver(receiver) => {
+                qualifier = Some(match (&receiver.reference, &receiver.mutability) {
+                    (Some(_), None) => " const",
+                    (Some(_), Some(_)) => "",
+                    (None, _) => " &&",
+                })
+            }
+            FnArg::Typed(typed) => {
+                let ty = cpp_type(&typed.ty, item, config)?;
+                match &*typed.pat {
+                    Pat::Ident(pat) if pat.ident == "self" => {
+                        return Err("typed self receivers are not supported".to_owned())
+                    }
+                    Pat::Ident(pat) => args.push(format!(
+                        "{} {}",
+                        ty,
+                        config.naming.parameter(&pat.ident.unraw().to_string())
+                    )),
+                    Pat::Wild(_) => args.push(ty),
+                    _ => return Err("arguments must be identifiers or _".to_owned()),
+                }
+            }
+        }
+    }
+
+    let output = match &sig.output {
+        ReturnType::Default => "void".to_owned(),
+        ReturnType::Type(_, ty) => cpp_type(ty, item, config)?,
+    };
+
+    Ok(format!(
+        "{}{} {}({}){}{}",
+        if qualifier.is_none() { "static " } else { "" },
+        output,
+        config.naming.method(&sig.ident.unraw().to_string()),
+        args.join(", "),
+        qualifier.unwrap_or(""),
+        if attrs.delete { " = delete" } else { "" }
+    ))
+}
+
+/// C++ type of an argument or result, shared references are `const T&` and `Self` is the class
+fn cpp_type(ty: &Type, item: &ItemEnum, config: &Config) -> std::result::Result<String, String> {
+    match ty {
+        Type::Reference(reference) => {
+            let referent = cpp_type(&reference.elem, item, config)?;
+            Ok(match reference.mutability {
+                Some(_) => format!("{}&", referent),
+                None => format!("const {}&", referent),
+            })
+        }
+        Type::Paren(paren) => cpp_type(&paren.elem, item, config),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Ok("void".to_owned()),
+        Type::Path(path) if path.path.is_ident("str") => Ok("std::string".to_owned()),
+        _ => {
+            let mut ty = ty.clone();
+            let class = Type::Path(TypePath {
+                qself: None,
+                path: item.ident.clone().into(),
+            });
+            types::substitute(&mut ty, &["Self".to_owned()], &[&class]);
+            types::type_to_cpp_type(&ty, config).map_err(|err| err.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use syn::{ImplItem, Item, ItemImpl};
+
+    use super::*;
+
+    fn declare(code: &str) -> Result<Vec<String>> {
+        let file = syn::parse_file(code).unwrap();
+        let item = file
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::Enum(item) => Some(item),
+                _ => None,
+            })
+            .unwrap();
+        let methods = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Impl(ItemImpl { items, .. }) => Some(items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                ImplItem::Method(method) => Some(method.clone()),
+                _ => None,
+            })
+            .collect();
+        let config = Config {
+            methods,
+            ..Config::default()
+        };
+
+        let mut output = Vec::new();
+        write_declarations(&mut output, item, &config)?;
+        Ok(String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    #[test]
+    fn test_methods() {
+        assert_eq!(
+            declare(
+                r#"
+                enum Status { Idle }
+                impl Status {
+                    fn check(&self, _: u8) {}
+                    pub fn len(&self) -> usize { 0 }
+                    pub(crate) fn clear(&mut self, all: bool, new: &mut Vec<u8>) {}
+                    pub fn into_name(self) -> String { String::new() }
+                    pub fn parse(text: &str) -> Option<Self> { None }
+                    #[cppust(delete)]
+                    pub fn copy(&self) -> Self { todo!() }
+                    #[cppust(skip)]
+                    pub fn iter(&self) -> impl Iterator<Item = u8> { todo!() }
+                }
+                "#
+            )
+            .unwrap(),
+            vec![
+                "public: // methods",
+                "    ::cppust::usize len() const;",
+                "    void clear(bool all, std::vector<::cppust::u8>& new_);",
+                "    std::string into_name() &&;",
+                "    static ::cppust::Option<Status> parse(const std::string& text);",
+                "    Status copy() const = delete;",
+                "private: // methods",
+                "    void check(::cppust::u8) const;",
+            ]
+        );
+        assert!(declare("enum Status { Idle }").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_methods() {
+        for method in [
+            "fn map<T>(&self) -> T { todo!() }",
+            "async fn wait(&self) {}",
+            "fn boxed(self: Box<Self>) {}",
+            "fn pair(&self, (a, b): (u8, u8)) {}",
+            "fn iter(&self) -> impl Iterator<Item = u8> { todo!() }",
+            "#[cppust(delete = \"yes\")] fn copy(&self) {}",
+        ] {
+            let code = format!("enum Status {{ Idle }} impl Status {{ {} }}", method);
+            assert!(declare(&code).is_err(), "{}", method);
+        }
+    }
+}
@@ -1,23 +1,59 @@
-use std::{fs::OpenOptions, path::Path};
+use std::{collections::HashMap, fs::OpenOptions, path::Path};
 
-use syn::{File, Item};
+use syn::{File, ImplItem, ImplItemMethod, Item, ItemEnum, ItemType, Type};
 
 mod error;
 
+pub mod aliases;
+pub mod amalgam;
+pub mod attrs;
+pub mod config;
+pub mod consts;
+pub mod cpp_tests;
+pub mod derives;
+pub mod docs;
 pub mod enum_hdr;
 pub mod enum_impl;
+pub mod ffi;
+pub mod layout;
+pub mod merge;
+pub mod methods;
 pub mod names;
+pub mod pod;
+pub mod samples;
 pub mod types;
+pub mod user_code;
+pub mod wire;
 
 use error::{Error, Result};
 
-use self::names::IdentName;
+use self::attrs::EnumAttrs;
+use self::config::{Backend, Config, CppStd, Repr, UserAlias, UserClass};
+use self::consts::Constant;
+use self::names::Naming;
+
+/// Hidden directory keeping the last generated `<file>.hpp` of each type, the common ancestor when
+/// merging the user changes with a new template, under the source directory unless configured
+pub const BASELINE_DIR: &str = ".cppust-gen";
 
 pub struct Generator {
     file: File,
-    namespace: Vec<String>,
+    config: Config,
     header_dir: String,
     source_dir: String,
+    /// Directory to keep the merge baselines in, out of the header directory so that they are not
+    /// installed with the headers
+    baseline_dir: String,
+    /// Name of the single header and source file holding all types, if any
+    amalgamation: Option<String>,
+    /// Name of the input module, the stem of `<module>_consts.hpp` and `<module>_types.hpp`
+    module: String,
+    /// Directory to save the `#[repr(C)]` Rust mirrors into, if any
+    ffi_dir: Option<String>,
+    /// Directory to save the Rust counterparts with their wire encoding into, if any
+    rust_dir: Option<String>,
+    /// Directory to save the C++ tests of the generated classes into, if any
+    tests_dir: Option<String>,
 }
 
 impl Generator {
@@ -26,42 +62,234 @@ impl Generator {
     }
 
     pub fn generate(&self) -> Result<()> {
-        self.generate_enum_headers()?;
-        self.generate_enum_sources()?;
+        let (enum_items, classes) = self.enum_items()?;
+        self.config.naming.check_collisions(
+            &enum_items
+                .iter()
+                .map(|(item, config)| (*item, config.namespace.as_slice()))
+                .collect::<Vec<_>>(),
+        )?;
+
+        let constants = self.constants()?;
+        let type_items = self.type_items();
+
+        // aliases may refer to the generated classes
+        let config = Config {
+            classes,
+            ..self.config.clone()
+        };
+
+        match &self.amalgamation {
+            Some(name) => {
+                self.generate_amalgamation(&enum_items, &constants, &type_items, &config, name)?
+            }
+            None => {
+                if !constants.is_empty() {
+                    self.generate_constants(&constants)?;
+                }
+                if !type_items.is_empty() {
+                    self.generate_aliases(&type_items, &config)?;
+                }
+                self.generate_enum_headers(&enum_items)?;
+                self.generate_enum_sources(&enum_items)?;
+            }
+        }
+
+        if let Some(ffi_dir) = &self.ffi_dir {
+            self.generate_ffi(&enum_items, &config, ffi_dir)?;
+        }
+
+        if let Some(rust_dir) = &self.rust_dir {
+            self.generate_wire(&enum_items, &config, rust_dir)?;
+        }
+
+        if let Some(tests_dir) = &self.tests_dir {
+            self.generate_tests(&enum_items, tests_dir)?;
+        }
 
         Ok(())
     }
 
-    fn generate_enum_headers(&self) -> Result<()> {
-        let header_path = Path::new(&self.header_dir);
-        let namespace_path = header_path.join(self.namespace.join("/"));
+    /// The `const` and `static` items of the input
+    fn constants(&self) -> Result<Vec<Constant<'_>>> {
+        let mut constants = Vec::new();
+        for item in self.file.items.iter() {
+            if let Some(constant) = Constant::of(item)? {
+                constants.push(constant);
+            }
+        }
 
-        std::fs::create_dir_all(&namespace_path)?;
+        Ok(constants)
+    }
+
+    /// The `type` aliases of the input
+    fn type_items(&self) -> Vec<&ItemType> {
+        self.file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Type(type_item) => Some(type_item),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The methods of the inherent `impl` blocks of the enum `ident`, trait impls are left to
+    /// derives
+    fn methods(&self, ident: &str) -> Vec<ImplItemMethod> {
+        self.file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Impl(impl_item) if impl_item.trait_.is_none() => Some(impl_item),
+                _ => None,
+            })
+            .filter(|impl_item| match &*impl_item.self_ty {
+                Type::Path(path) => path.qself.is_none() && path.path.is_ident(ident),
+                _ => false,
+            })
+            .flat_map(|impl_item| impl_item.items.iter())
+            .filter_map(|item| match item {
+                ImplItem::Method(method) => Some(method.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The enums to generate, each with the config adjusted by its `#[cppust(...)]` attributes,
+    /// and the classes generated for all of them
+    #[allow(clippy::type_complexity)]
+    fn enum_items(&self) -> Result<(Vec<(&ItemEnum, Config)>, HashMap<String, UserClass>)> {
+        let mut enum_items = Vec::new();
+        let mut classes = HashMap::new();
 
         for item in self.file.items.iter() {
             if let Item::Enum(enum_item) = item {
-                let enum_ident: IdentName = (&enum_item.ident).into();
+                attrs::validate(enum_item)?;
+                let enum_attrs = EnumAttrs::of(enum_item)?;
+                if enum_attrs.skip {
+                    continue;
+                }
 
-                let type_header_path =
-                    namespace_path.join(format!("{}.hpp", enum_ident.to_file_name()));
-                let type_inc_path =
-                    namespace_path.join(format!("{}.inc.hpp", enum_ident.to_file_name()));
-                let type_fmt_path =
-                    namespace_path.join(format!("{}.fmt.hpp", enum_ident.to_file_name()));
+                let mut config = self.config.clone();
+                if let Some(namespace) = enum_attrs.namespace {
+                    config.namespace = namespace;
+                }
+                config.header_only |= enum_attrs.header_only;
+                if enum_attrs.layout {
+                    config.repr = Some(Repr::of(enum_item)?);
+                }
+
+                classes.insert(
+                    enum_item.ident.to_string(),
+                    UserClass {
+                        namespace: config.namespace.clone(),
+                        name: attrs::enum_name(enum_item)?,
+                        layout: enum_attrs.layout,
+                        pod: false,
+                    },
+                );
+                enum_items.push((enum_item, config));
+            }
+        }
+
+        // POD enums may store each other, so classes are marked until no other one turns out POD
+        loop {
+            let pods: Vec<String> = enum_items
+                .iter()
+                .filter(|(enum_item, _)| !classes[&enum_item.ident.to_string()].pod)
+                .filter(|(enum_item, config)| {
+                    let config = Config {
+                        classes: classes.clone(),
+                        ..config.clone()
+                    };
+                    pod::is_pod(enum_item, &config)
+                })
+                .map(|(enum_item, _)| enum_item.ident.to_string())
+                .collect();
+            if pods.is_empty() {
+                break;
+            }
+            for ident in pods {
+                classes.get_mut(&ident).unwrap().pod = true;
+            }
+        }
+
+        for (enum_item, config) in enum_items.iter_mut() {
+            config.classes = classes.clone();
+            config.methods = self.methods(&enum_item.ident.to_string());
+            config.pod = classes[&enum_item.ident.to_string()].pod;
+            if config.repr.is_some() {
+                layout::validate(enum_item, config)?;
+            }
+        }
 
-                // generate type header
-                if type_header_path.exists() {
+        Ok((enum_items, classes))
+    }
+
+    fn generate_enum_headers(&self, enum_items: &[(&ItemEnum, Config)]) -> Result<()> {
+        let header_path = Path::new(&self.header_dir);
+
+        for (enum_item, config) in enum_items {
+            let namespace_path = header_path.join(config.namespace.join("/"));
+            std::fs::create_dir_all(&namespace_path)?;
+
+            let enum_ident = attrs::enum_name(enum_item)?;
+            let file_name = config.naming.file(&enum_ident);
+
+            let type_header_path = namespace_path.join(format!("{}.hpp", file_name));
+            let type_inc_path = namespace_path.join(format!("{}.inc.hpp", file_name));
+            let type_fmt_path = namespace_path.join(format!("{}.fmt.hpp", file_name));
+
+            if config.user_regions {
+                // generate the whole type header, keeping the user code regions
+                println!("Generating {}...", type_header_path.to_str().unwrap());
+                let mut header = Vec::new();
+                enum_hdr::write(&mut header, enum_item, config)?;
+                write_keeping_user_code(&type_header_path, &header)?;
+            } else {
+                // generate type header, merging in the changes of the user
+                let baseline_path = Path::new(&self.baseline_dir)
+                    .join(config.namespace.join("/"))
+                    .join(format!("{}.hpp", file_name));
+                let mut template = Vec::new();
+                enum_hdr::write(&mut template, enum_item, config)?;
+                let template = String::from_utf8_lossy(&template).into_owned();
+
+                if !type_header_path.exists() {
+                    println!("Generating {}...", type_header_path.to_str().unwrap());
+                    std::fs::write(&type_header_path, &template)?;
+                } else if !baseline_path.exists() {
+                    // headers generated before baselines were kept are taken as edited by the user
                     println!("Skipping {}...", type_header_path.to_str().unwrap());
                 } else {
-                    println!("Generating {}...", type_header_path.to_str().unwrap());
-                    let mut type_header_file = OpenOptions::new()
-                        .read(true)
-                        .write(true)
-                        .create(true)
-                        .open(type_header_path)?;
-                    enum_hdr::write(&mut type_header_file, enum_item, &self.namespace)?;
+                    let baseline = std::fs::read_to_string(&baseline_path)?;
+                    let current = std::fs::read_to_string(&type_header_path)?;
+                    let merged = merge::merge(
+                        &baseline,
+                        &current,
+                        &template,
+                        (&format!("{}.hpp", file_name), "cppust-gen"),
+                    );
+
+                    if merged.text == current {
+                        println!("Skipping {}...", type_header_path.to_str().unwrap());
+                    } else {
+                        println!("Merging {}...", type_header_path.to_str().unwrap());
+                        std::fs::write(&type_header_path, &merged.text)?;
+                    }
+                    if merged.conflicts > 0 {
+                        eprintln!(
+                            "warning: {} conflict(s) in {}, resolve the <<<<<<< markers",
+                            merged.conflicts,
+                            type_header_path.to_str().unwrap()
+                        );
+                    }
                 }
 
+                std::fs::create_dir_all(baseline_path.parent().unwrap())?;
+                std::fs::write(&baseline_path, &template)?;
+
                 // generate inc header
                 println!("Generating {}...", type_inc_path.to_str().unwrap());
                 {
@@ -73,8 +301,9 @@ impl Generator {
                         .read(true)
                         .write(true)
                         .create(true)
+                        .truncate(true)
                         .open(type_inc_path)?;
-                    enum_hdr::write_inc(&mut type_inc_file, enum_item)?;
+                    enum_hdr::write_inc(&mut type_inc_file, enum_item, config)?;
                 }
 
                 // generate fmt headers
@@ -88,8 +317,33 @@ impl Generator {
                         .read(true)
                         .write(true)
                         .create(true)
+                        .truncate(true)
                         .open(type_fmt_path)?;
-                    enum_hdr::write_fmt(&mut type_fmt_file, enum_item, &self.namespace)?;
+                    enum_hdr::write_fmt(&mut type_fmt_file, enum_item, config)?;
+                }
+            }
+
+            // generate inline member definitions
+            if config.header_only {
+                let type_impl_path = namespace_path.join(format!("{}.impl.hpp", file_name));
+                println!("Generating {}...", type_impl_path.to_str().unwrap());
+
+                if config.user_regions {
+                    let mut type_impl = Vec::new();
+                    enum_impl::write(&mut type_impl, enum_item, config)?;
+                    write_keeping_user_code(&type_impl_path, &type_impl)?;
+                } else {
+                    if type_impl_path.exists() {
+                        std::fs::remove_file(&type_impl_path)?;
+                    }
+
+                    let mut type_impl_file = OpenOptions::new()
+                        .read(true)
+                        .write(true)
+                        .create(true)
+                        .truncate(true)
+                        .open(type_impl_path)?;
+                    enum_impl::write(&mut type_impl_file, enum_item, config)?;
                 }
             }
         }
@@ -97,33 +351,283 @@ impl Generator {
         Ok(())
     }
 
-    fn generate_enum_sources(&self) -> Result<()> {
-        let source_path = Path::new(&self.source_dir);
-        let namespace_path = source_path.join(self.namespace[1..].join("/"));
+    fn generate_constants(&self, constants: &[Constant]) -> Result<()> {
+        let namespace_path = Path::new(&self.header_dir).join(self.config.namespace.join("/"));
+        std::fs::create_dir_all(&namespace_path)?;
+
+        let file_name = self
+            .config
+            .naming
+            .file(&format!("{}_consts", self.module).as_str().into());
+        let consts_path = namespace_path.join(format!("{}.hpp", file_name));
+
+        println!("Generating {}...", consts_path.to_str().unwrap());
+        let mut consts_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(consts_path)?;
+        consts::write(&mut consts_file, constants, &self.config)
+    }
 
+    fn generate_aliases(&self, type_items: &[&ItemType], config: &Config) -> Result<()> {
+        let namespace_path = Path::new(&self.header_dir).join(self.config.namespace.join("/"));
         std::fs::create_dir_all(&namespace_path)?;
 
-        for item in self.file.items.iter() {
-            if let Item::Enum(enum_item) = item {
-                let enum_ident: IdentName = (&enum_item.ident).into();
+        let file_name = self
+            .config
+            .naming
+            .file(&format!("{}_types", self.module).as_str().into());
+        let types_path = namespace_path.join(format!("{}.hpp", file_name));
+
+        println!("Generating {}...", types_path.to_str().unwrap());
+        let mut types_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(types_path)?;
+        aliases::write(&mut types_file, type_items, config)
+    }
 
-                let type_gen_path =
-                    namespace_path.join(format!("{}.gen.cpp", enum_ident.to_file_name()));
+    fn generate_ffi(
+        &self,
+        enum_items: &[(&ItemEnum, Config)],
+        config: &Config,
+        ffi_dir: &str,
+    ) -> Result<()> {
+        // mirrors store each other by value
+        let enum_items = amalgam::order(enum_items)?;
+
+        std::fs::create_dir_all(ffi_dir)?;
+        let rust_path = Path::new(ffi_dir).join(format!("{}_ffi.rs", self.module));
+        println!("Generating {}...", rust_path.to_str().unwrap());
+        {
+            let mut rust_file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(rust_path)?;
+            ffi::write_rust(&mut rust_file, &enum_items, &self.module)?;
+        }
 
-                // generate impl src
-                println!("Generating {}...", type_gen_path.to_str().unwrap());
-                {
-                    if type_gen_path.exists() {
-                        std::fs::remove_file(&type_gen_path)?;
-                    }
+        let includes = self.class_headers(&enum_items, config)?;
 
-                    let mut type_gen_file = OpenOptions::new()
-                        .read(true)
-                        .write(true)
-                        .create(true)
-                        .open(type_gen_path)?;
-                    enum_impl::write(&mut type_gen_file, enum_item, &self.namespace)?;
+        let namespace_path = Path::new(&self.header_dir).join(config.namespace.join("/"));
+        std::fs::create_dir_all(&namespace_path)?;
+
+        let file_name = config
+            .naming
+            .file(&format!("{}_ffi", self.module).as_str().into());
+        let header_path = namespace_path.join(format!("{}.hpp", file_name));
+        println!("Generating {}...", header_path.to_str().unwrap());
+        let mut header_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(header_path)?;
+        ffi::write_header(&mut header_file, &enum_items, &self.module, &includes, config)
+    }
+
+    fn generate_wire(
+        &self,
+        enum_items: &[(&ItemEnum, Config)],
+        config: &Config,
+        rust_dir: &str,
+    ) -> Result<()> {
+        let enum_items: Vec<_> = enum_items.iter().collect();
+        let module = ffi::symbol(&self.module);
+
+        std::fs::create_dir_all(rust_dir)?;
+        let rust_path = Path::new(rust_dir).join(format!("{}.rs", module));
+        wire::write_rust(&mut create_file(&rust_path)?, &enum_items, &self.module)?;
+        let samples_path = Path::new(rust_dir).join(format!("{}_samples.rs", module));
+        wire::write_rust_samples(&mut create_file(&samples_path)?, &self.module)?;
+
+        let namespace_path = Path::new(&self.header_dir).join(config.namespace.join("/"));
+        std::fs::create_dir_all(&namespace_path)?;
+        let file_name = config
+            .naming
+            .file(&format!("{}_wire", self.module).as_str().into());
+        let header_path = namespace_path.join(format!("{}.hpp", file_name));
+        let includes = self.class_headers(&enum_items, config)?;
+        wire::write_header(&mut create_file(&header_path)?, &enum_items, &includes)?;
+
+        let test_path = Path::new(rust_dir).join(format!("{}_wire_test.cpp", module));
+        let header = config
+            .namespace
+            .iter()
+            .cloned()
+            .chain(std::iter::once(format!("{}.hpp", file_name)))
+            .collect::<Vec<_>>()
+            .join("/");
+        wire::write_cpp_test(&mut create_file(&test_path)?, &enum_items, &header)
+    }
+
+    fn generate_tests(&self, enum_items: &[(&ItemEnum, Config)], tests_dir: &str) -> Result<()> {
+        let all_items: Vec<_> = enum_items.iter().collect();
+
+        for enum_item in enum_items.iter() {
+            let (item, config) = enum_item;
+            let namespace_path = Path::new(tests_dir).join(config.namespace.join("/"));
+            std::fs::create_dir_all(&namespace_path)?;
+            let test_path = namespace_path.join(format!(
+                "{}.test.cpp",
+                config.naming.file(&attrs::enum_name(item)?)
+            ));
+            let header = self.class_headers(&[enum_item], config)?.remove(0);
+            cpp_tests::write_test(
+                &mut create_file(&test_path)?,
+                item,
+                config,
+                &all_items,
+                &header,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Paths of the headers defining the classes of `enum_items`, relative to the header
+    /// directory
+    fn class_headers(
+        &self,
+        enum_items: &[&(&ItemEnum, Config)],
+        config: &Config,
+    ) -> Result<Vec<String>> {
+        let header = |namespace: &[String], file_name: String| {
+            namespace
+                .iter()
+                .cloned()
+                .chain(std::iter::once(format!("{}.hpp", file_name)))
+                .collect::<Vec<_>>()
+                .join("/")
+        };
+
+        match &self.amalgamation {
+            Some(name) => Ok(vec![header(&config.namespace, name.clone())]),
+            None => enum_items
+                .iter()
+                .map(|(item, config)| {
+                    Ok(header(
+                        &config.namespace,
+                        config.naming.file(&attrs::enum_name(item)?),
+                    ))
+                })
+                .collect(),
+        }
+    }
+
+    fn generate_amalgamation(
+        &self,
+        enum_items: &[(&ItemEnum, Config)],
+        constants: &[Constant],
+        type_items: &[&ItemType],
+        config: &Config,
+        name: &str,
+    ) -> Result<()> {
+        // all classes are defined in one namespace block
+        if let Some((item, config)) = enum_items
+            .iter()
+            .find(|(_, config)| config.namespace != self.config.namespace)
+        {
+            return Err(Error::ConfigError(
+                "amalgamate".to_owned(),
+                format!(
+                    "{} is generated into namespace {}, but all types of {} must share one",
+                    item.ident,
+                    config.namespace.join("::"),
+                    name
+                ),
+            ));
+        }
+
+        let enum_items = amalgam::order(enum_items)?;
+
+        let namespace_path = Path::new(&self.header_dir).join(self.config.namespace.join("/"));
+        std::fs::create_dir_all(&namespace_path)?;
+
+        let header_path = namespace_path.join(format!("{}.hpp", name));
+        println!("Generating {}...", header_path.to_str().unwrap());
+        {
+            let mut header_file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(header_path)?;
+            amalgam::write_header(
+                &mut header_file,
+                &enum_items,
+                constants,
+                type_items,
+                config,
+            )?;
+        }
+
+        if self.config.header_only {
+            return Ok(());
+        }
+
+        // the first namespace is the project, which is usually the source directory
+        let namespace_path = Path::new(&self.source_dir)
+            .join(self.config.namespace.get(1..).unwrap_or(&[]).join("/"));
+        std::fs::create_dir_all(&namespace_path)?;
+
+        let source_path = namespace_path.join(format!("{}.cpp", name));
+        println!("Generating {}...", source_path.to_str().unwrap());
+        {
+            let mut source_file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(source_path)?;
+            amalgam::write_source(&mut source_file, name, &enum_items, &self.config)?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_enum_sources(&self, enum_items: &[(&ItemEnum, Config)]) -> Result<()> {
+        let source_path = Path::new(&self.source_dir);
+
+        for (enum_item, config) in enum_items {
+            if config.header_only {
+                continue;
+            }
+
+            // the first namespace is the project, which is usually the source directory
+            let namespace_path =
+                source_path.join(config.namespace.get(1..).unwrap_or(&[]).join("/"));
+            std::fs::create_dir_all(&namespace_path)?;
+
+            let enum_ident = attrs::enum_name(enum_item)?;
+            let file_name = config.naming.file(&enum_ident);
+
+            let type_gen_path = namespace_path.join(format!("{}.gen.cpp", file_name));
+
+            // generate impl src
+            println!("Generating {}...", type_gen_path.to_str().unwrap());
+            if config.user_regions {
+                let mut type_gen = Vec::new();
+                enum_impl::write(&mut type_gen, enum_item, config)?;
+                write_keeping_user_code(&type_gen_path, &type_gen)?;
+            } else {
+                if type_gen_path.exists() {
+                    std::fs::remove_file(&type_gen_path)?;
                 }
+
+                let mut type_gen_file = OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(type_gen_path)?;
+                enum_impl::write(&mut type_gen_file, enum_item, config)?;
             }
         }
 
@@ -131,11 +635,52 @@ impl Generator {
     }
 }
 
+/// Writes `generated` to `path`, with the user code regions of the file at `path` if any
+fn write_keeping_user_code(path: &Path, generated: &[u8]) -> Result<()> {
+    let existing = if path.exists() {
+        std::fs::read_to_string(path)?
+    } else {
+        String::new()
+    };
+    let output = user_code::restore(
+        &String::from_utf8_lossy(generated),
+        &existing,
+        path.to_str().unwrap(),
+    )?;
+    std::fs::write(path, output)?;
+
+    Ok(())
+}
+
+/// Truncates or creates the generated file at `path`
+fn create_file(path: &Path) -> Result<std::fs::File> {
+    println!("Generating {}...", path.to_str().unwrap());
+    Ok(OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?)
+}
+
 pub struct Builder<'a> {
     source_code: &'a str,
     namespace: Option<String>,
     header_dir: Option<String>,
     source_dir: Option<String>,
+    baseline_dir: Option<String>,
+    cpp_std: CppStd,
+    backend: Backend,
+    exceptions: bool,
+    iostream: bool,
+    header_only: bool,
+    naming: Naming,
+    amalgamation: Option<String>,
+    user_regions: bool,
+    module: Option<String>,
+    ffi_dir: Option<String>,
+    rust_dir: Option<String>,
+    tests_dir: Option<String>,
 }
 
 impl<'a> Builder<'a> {
@@ -145,6 +690,19 @@ impl<'a> Builder<'a> {
             namespace: None,
             header_dir: None,
             source_dir: None,
+            baseline_dir: None,
+            cpp_std: CppStd::Cpp11,
+            backend: Backend::Union,
+            exceptions: true,
+            iostream: true,
+            header_only: false,
+            naming: Naming::default(),
+            amalgamation: None,
+            user_regions: false,
+            module: None,
+            ffi_dir: None,
+            rust_dir: None,
+            tests_dir: None,
         }
     }
 
@@ -156,28 +714,111 @@ impl<'a> Builder<'a> {
             ));
         }
 
-        if self.source_dir.is_none() {
+        if self.source_dir.is_none() && !self.header_only {
             return Err(Error::ConfigError(
                 "source_dir".to_owned(),
                 "specify directory to save source files".to_owned(),
             ));
         }
 
+        if self.backend == Backend::Variant && self.cpp_std < CppStd::Cpp17 {
+            return Err(Error::ConfigError(
+                "backend".to_owned(),
+                "the variant backend requires C++17 or above".to_owned(),
+            ));
+        }
+
+        if let Some(name) = &self.amalgamation {
+            let valid = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c == '_' || c == '-' || c.is_ascii_alphanumeric());
+            if !valid {
+                return Err(Error::ConfigError(
+                    "amalgamate".to_owned(),
+                    format!("{} is not a valid file name, give it without extension", name),
+                ));
+            }
+        }
+
+        if self.user_regions && self.amalgamation.is_some() {
+            return Err(Error::ConfigError(
+                "user_regions".to_owned(),
+                "amalgamated output has no user code regions".to_owned(),
+            ));
+        }
+
         let namespace = self
             .namespace
             .map(|s| s.split("::").map(|p| p.to_owned()).collect::<Vec<String>>())
             .unwrap_or_default();
 
+        // without an input file name, constants are named after the innermost namespace
+        let module = self
+            .module
+            .or_else(|| namespace.last().cloned())
+            .unwrap_or_else(|| "cppust".to_owned());
+
         let header_dir = self.header_dir.unwrap();
-        let source_dir = self.source_dir.unwrap();
+        // header-only output has no source files
+        let source_dir = self.source_dir.unwrap_or_default();
+        // next to the sources, or in the working directory for header-only output
+        let baseline_dir = self.baseline_dir.unwrap_or_else(|| {
+            Path::new(&source_dir)
+                .join(BASELINE_DIR)
+                .to_string_lossy()
+                .into_owned()
+        });
 
         let file = syn::parse_file(self.source_code)?;
 
+        let types_header = format!(
+            "{}.hpp",
+            namespace
+                .iter()
+                .cloned()
+                .chain(std::iter::once(
+                    self.naming.file(&format!("{}_types", module).as_str().into())
+                ))
+                .collect::<Vec<_>>()
+                .join("/")
+        );
+        let mut aliases = HashMap::new();
+        for item in file.items.iter() {
+            if let Item::Type(type_item) = item {
+                aliases.insert(
+                    type_item.ident.to_string(),
+                    UserAlias::of(type_item, &namespace, &types_header)?,
+                );
+            }
+        }
+
         Ok(Generator {
             file,
-            namespace,
+            config: Config {
+                namespace,
+                cpp_std: self.cpp_std,
+                backend: self.backend,
+                exceptions: self.exceptions,
+                iostream: self.iostream,
+                naming: self.naming,
+                header_only: self.header_only,
+                user_regions: self.user_regions,
+                classes: HashMap::new(),
+                aliases,
+                type_params: Vec::new(),
+                methods: Vec::new(),
+                repr: None,
+                pod: false,
+            },
             header_dir,
             source_dir,
+            baseline_dir,
+            amalgamation: self.amalgamation,
+            module,
+            ffi_dir: self.ffi_dir,
+            rust_dir: self.rust_dir,
+            tests_dir: self.tests_dir,
         })
     }
 
@@ -188,6 +829,92 @@ impl<'a> Builder<'a> {
         }
     }
 
+    pub fn with_cpp_std(self, cpp_std: CppStd) -> Self {
+        Self { cpp_std, ..self }
+    }
+
+    pub fn with_backend(self, backend: Backend) -> Self {
+        Self { backend, ..self }
+    }
+
+    pub fn with_naming(self, naming: Naming) -> Self {
+        Self { naming, ..self }
+    }
+
+    pub fn without_exceptions(self) -> Self {
+        Self {
+            exceptions: false,
+            ..self
+        }
+    }
+
+    pub fn without_iostream(self) -> Self {
+        Self {
+            iostream: false,
+            ..self
+        }
+    }
+
+    pub fn with_header_only(self) -> Self {
+        Self {
+            header_only: true,
+            ..self
+        }
+    }
+
+    /// Names the input module, usually after the input file, for `<module>_consts.hpp`
+    pub fn with_module(self, module: &str) -> Self {
+        Self {
+            module: Some(module.to_owned()),
+            ..self
+        }
+    }
+
+    /// Generates whole headers with user code regions instead of `<file>.inc.hpp` included
+    /// into a user header
+    pub fn with_user_regions(self) -> Self {
+        Self {
+            user_regions: true,
+            ..self
+        }
+    }
+
+    /// Generates all types into a single `<name>.hpp`, and `<name>.cpp` unless header-only
+    pub fn with_amalgamation(self, name: &str) -> Self {
+        Self {
+            amalgamation: Some(name.to_owned()),
+            ..self
+        }
+    }
+
+    /// Generates `#[repr(C)]` Rust mirrors of the enums into `<path>/<module>_ffi.rs`, and the
+    /// C++ conversions into `<module>_ffi.hpp`
+    pub fn with_ffi(self, path: &str) -> Self {
+        Self {
+            ffi_dir: Some(path.to_owned()),
+            ..self
+        }
+    }
+
+    /// Generates Rust counterparts of the enums with their wire encoding into `<path>/<module>.rs`,
+    /// a binary writing samples of each variant and a C++ test reading them back, with the C++
+    /// encoding in `<module>_wire.hpp`
+    pub fn with_rust(self, path: &str) -> Self {
+        Self {
+            rust_dir: Some(path.to_owned()),
+            ..self
+        }
+    }
+
+    /// Generates a C++ program per class into `<path>/<file>.test.cpp`, checking the accessors,
+    /// copies, moves and assignments between every pair of variants
+    pub fn with_tests(self, path: &str) -> Self {
+        Self {
+            tests_dir: Some(path.to_owned()),
+            ..self
+        }
+    }
+
     pub fn save_headers_at(self, path: &str) -> Self {
         Self {
             header_dir: Some(path.to_owned()),
@@ -201,4 +928,13 @@ impl<'a> Builder<'a> {
             ..self
         }
     }
+
+    /// Keeps the merge baselines of the generated headers in `path` instead of
+    /// `<source_dir>/.cppust-gen`
+    pub fn with_baseline_dir(self, path: &str) -> Self {
+        Self {
+            baseline_dir: Some(path.to_owned()),
+            ..self
+        }
+    }
 }
@@ -1,4 +1,12 @@
-use syn::Ident;
+use std::collections::HashMap;
+
+use syn::{Fields, Ident, ItemEnum};
+
+use super::{
+    attrs,
+    derives::{Derive, Derives},
+    error::{Error, Result},
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct IdentName(Vec<IdentPart>);
@@ -16,76 +24,481 @@ impl From<&Ident> for IdentName {
 }
 
 impl IdentName {
-    pub fn to_class_name(&self) -> String {
-        self.to_pascal_case()
+    pub fn to_snake(&self) -> String {
+        self.0
+            .iter()
+            .map(|s| s.to_all_lower_case())
+            .collect::<Vec<String>>()
+            .join("_")
     }
 
-    pub fn to_file_name(&self) -> String {
-        self.to_snake()
+    pub fn to_screaming_snake(&self) -> String {
+        self.0
+            .iter()
+            .map(|s| s.to_all_upper_case())
+            .collect::<Vec<String>>()
+            .join("_")
     }
 
-    // pub fn to_template_arg(&self) -> String {
-    //     self.to_pascal_case()
-    // }
+    pub fn to_camel_case(&self) -> String {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(idx, s)| {
+                if idx == 0 {
+                    s.to_all_lower_case()
+                } else {
+                    s.to_capital_first()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("")
+    }
 
-    // pub fn to_enum_name(&self) -> String {
-    //     self.to_pascal_case()
-    // }
+    pub fn to_pascal_case(&self) -> String {
+        self.0
+            .iter()
+            .map(|s| s.to_capital_first())
+            .collect::<Vec<String>>()
+            .join("")
+    }
+}
 
-    pub fn to_enum_variant_name(&self) -> String {
-        self.to_pascal_case()
+/// Every kind of generated identifier whose style can be configured
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NameKind {
+    /// Generated classes, and the user types they refer to
+    Class,
+    /// Stem of the generated files
+    File,
+    /// Static factory of a variant, e.g. `Bytes(...)`
+    Factory,
+    /// Enumerators of `_Tag`
+    Tag,
+    /// Non-accessor methods, e.g. `partial_cmp`
+    Method,
+    /// Members of the `_Union` of the union backend
+    UnionMember,
+    Is,
+    RefUncheck,
+    Ref,
+    Ptr,
+    As,
+    Expect,
+    Into,
+    Take,
+    Emplace,
+    /// Constants from `const` and `static` items
+    Constant,
+}
+
+impl NameKind {
+    const ALL: [NameKind; 16] = [
+        NameKind::Class,
+        NameKind::File,
+        NameKind::Factory,
+        NameKind::Tag,
+        NameKind::Method,
+        NameKind::UnionMember,
+        NameKind::Is,
+        NameKind::RefUncheck,
+        NameKind::Ref,
+        NameKind::Ptr,
+        NameKind::As,
+        NameKind::Expect,
+        NameKind::Into,
+        NameKind::Take,
+        NameKind::Emplace,
+        NameKind::Constant,
+    ];
+
+    /// Key of this kind in naming config files
+    pub fn key(&self) -> &'static str {
+        match self {
+            NameKind::Class => "class",
+            NameKind::File => "file",
+            NameKind::Factory => "factory",
+            NameKind::Tag => "tag",
+            NameKind::Method => "method",
+            NameKind::UnionMember => "union_member",
+            NameKind::Is => "is",
+            NameKind::RefUncheck => "ref_uncheck",
+            NameKind::Ref => "ref",
+            NameKind::Ptr => "ptr",
+            NameKind::As => "as",
+            NameKind::Expect => "expect",
+            NameKind::Into => "into",
+            NameKind::Take => "take",
+            NameKind::Emplace => "emplace",
+            NameKind::Constant => "constant",
+        }
     }
+}
 
-    // pub fn to_const_name(&self) -> String {
-    //     format!("k{}", self.to_pascal_case())
-    // }
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Case {
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl Case {
+    const ALL: [(Case, &'static str); 4] = [
+        (Case::Pascal, "{Pascal}"),
+        (Case::Camel, "{camel}"),
+        (Case::Snake, "{snake}"),
+        (Case::ScreamingSnake, "{SNAKE}"),
+    ];
+}
+
+/// A naming pattern like `is_{snake}`, with exactly one case placeholder
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    prefix: String,
+    case: Case,
+    suffix: String,
+}
+
+impl Pattern {
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            Error::ConfigError(
+                "naming".to_owned(),
+                format!("invalid pattern {}: {}", pattern, reason),
+            )
+        };
+
+        let mut found = Case::ALL.iter().filter_map(|(case, placeholder)| {
+            pattern
+                .find(placeholder)
+                .map(|pos| (*case, *placeholder, pos))
+        });
+        let (case, placeholder, pos) = found
+            .next()
+            .ok_or_else(|| invalid("use one of {Pascal}, {camel}, {snake} or {SNAKE}"))?;
+        if found.next().is_some() || pattern[pos + placeholder.len()..].contains(placeholder) {
+            return Err(invalid("only one placeholder is allowed"));
+        }
 
-    pub fn to_public_member_name(&self) -> String {
-        self.to_snake()
+        let prefix = &pattern[..pos];
+        let suffix = &pattern[pos + placeholder.len()..];
+        if !prefix
+            .chars()
+            .chain(suffix.chars())
+            .all(|c| c == '_' || c.is_ascii_alphanumeric())
+        {
+            return Err(invalid(
+                "only letters, digits and _ are allowed around the placeholder",
+            ));
+        }
+        if prefix.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(invalid("identifiers cannot start with a digit"));
+        }
+
+        let pattern = Self {
+            prefix: prefix.to_owned(),
+            case,
+            suffix: suffix.to_owned(),
+        };
+        if is_reserved_identifier(&pattern.apply(&"Sample".into())) {
+            return Err(invalid(
+                "identifiers starting with _ and an uppercase letter, or containing __, are reserved",
+            ));
+        }
+
+        Ok(pattern)
     }
 
-    // pub fn to_protected_member_name(&self) -> String {
-    //     self.to_snake()
-    // }
+    pub fn apply(&self, name: &IdentName) -> String {
+        let name = match self.case {
+            Case::Pascal => name.to_pascal_case(),
+            Case::Camel => name.to_camel_case(),
+            Case::Snake => name.to_snake(),
+            Case::ScreamingSnake => name.to_screaming_snake(),
+        };
+        format!("{}{}{}", self.prefix, name, self.suffix)
+    }
+}
 
-    // pub fn to_private_member_name(&self) -> String {
-    //     format!("{}_", self.to_snake())
-    // }
+/// Naming convention of the generated code, see [`Naming::profile`] for the built-in ones
+#[derive(Clone, Debug, PartialEq)]
+pub struct Naming(HashMap<NameKind, Pattern>);
 
-    // pub fn to_variable_name(&self) -> String {
-    //     self.to_camel_case()
-    // }
+impl Default for Naming {
+    fn default() -> Self {
+        Self::profile("default").unwrap()
+    }
+}
 
-    fn to_snake(&self) -> String {
-        self.0
-            .iter()
-            .map(|s| s.to_all_lower_case())
-            .collect::<Vec<String>>()
-            .join("_")
+impl Naming {
+    pub const PROFILES: [&'static str; 4] = ["default", "google", "std", "llvm"];
+
+    /// Built-in profiles, `default` is the historic cppust-gen style
+    pub fn profile(name: &str) -> Result<Self> {
+        #[rustfmt::skip]
+        let patterns: [&str; 16] = match name {
+            // class, file, factory, tag, method, union_member,
+            // is, ref_uncheck, ref, ptr, as, expect, into, take, emplace, constant
+            "default" => [
+                "{Pascal}", "{snake}", "{Pascal}", "{Pascal}", "{snake}", "{snake}_val",
+                "is_{snake}", "{snake}_ref_uncheck", "{snake}_ref", "{snake}_ptr",
+                "as_{snake}", "expect_{snake}", "into_{snake}", "take_{snake}",
+                "emplace_{snake}", "{SNAKE}",
+            ],
+            "google" => [
+                "{Pascal}", "{snake}", "{Pascal}", "k{Pascal}", "{Pascal}", "{snake}_val",
+                "Is{Pascal}", "{Pascal}RefUnchecked", "{Pascal}Ref", "{Pascal}Ptr",
+                "As{Pascal}", "Expect{Pascal}", "Into{Pascal}", "Take{Pascal}",
+                "Emplace{Pascal}", "k{Pascal}",
+            ],
+            "std" => [
+                "{snake}", "{snake}", "{snake}", "{snake}", "{snake}", "{snake}_val",
+                "is_{snake}", "{snake}_ref_unchecked", "{snake}_ref", "{snake}_ptr",
+                "as_{snake}", "expect_{snake}", "into_{snake}", "take_{snake}",
+                "emplace_{snake}", "{SNAKE}",
+            ],
+            "llvm" => [
+                "{Pascal}", "{Pascal}", "{camel}", "{Pascal}", "{camel}", "{camel}Val",
+                "is{Pascal}", "get{Pascal}Unchecked", "get{Pascal}", "getIf{Pascal}",
+                "as{Pascal}", "expect{Pascal}", "into{Pascal}", "take{Pascal}",
+                "emplace{Pascal}", "{Pascal}",
+            ],
+            _ => {
+                return Err(Error::ConfigError(
+                    "naming".to_owned(),
+                    format!(
+                        "unknown naming profile {}, use one of {}",
+                        name,
+                        Self::PROFILES.join(", ")
+                    ),
+                ))
+            }
+        };
+
+        Ok(Self(
+            NameKind::ALL
+                .iter()
+                .zip(patterns.iter())
+                .map(|(kind, pattern)| (*kind, Pattern::parse(pattern).unwrap()))
+                .collect(),
+        ))
     }
 
-    // fn to_camel_case(&self) -> String {
-    //     self.0
-    //         .iter()
-    //         .enumerate()
-    //         .map(|(idx, s)| {
-    //             if idx == 0 {
-    //                 s.to_all_lower_case()
-    //             } else {
-    //                 s.to_capital_first()
-    //             }
-    //         })
-    //         .collect::<Vec<String>>()
-    //         .join("")
-    // }
-
-    fn to_pascal_case(&self) -> String {
-        self.0
-            .iter()
-            .map(|s| s.to_capital_first())
-            .collect::<Vec<String>>()
-            .join("")
+    /// Parses a config file of `key = pattern` lines, starting from the profile named by an
+    /// optional `base = <profile>` line, `#` starts a comment
+    pub fn from_config(config: &str) -> Result<Self> {
+        let error = |line_no: usize, reason: String| {
+            Error::ConfigError(
+                "naming".to_owned(),
+                format!("line {}: {}", line_no + 1, reason),
+            )
+        };
+
+        let mut lines = Vec::new();
+        for (line_no, line) in config.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(line_no, format!("expected key = pattern, found {}", line)))?;
+            lines.push((line_no, key.trim(), value.trim()));
+        }
+
+        let mut naming = match lines.iter().find(|(_, key, _)| *key == "base") {
+            Some((_, _, profile)) => Self::profile(profile)?,
+            None => Self::default(),
+        };
+
+        for (line_no, key, value) in lines {
+            if key == "base" {
+                continue;
+            }
+            let kind = NameKind::ALL
+                .iter()
+                .find(|kind| kind.key() == key)
+                .ok_or_else(|| error(line_no, format!("unknown key {}", key)))?;
+            naming.0.insert(*kind, Pattern::parse(value)?);
+        }
+
+        Ok(naming)
+    }
+
+    /// Styled identifier of `kind`, C++ keywords get a trailing `_`, e.g. `default_`
+    pub fn name(&self, kind: NameKind, name: &IdentName) -> String {
+        let ident = self.0[&kind].apply(name);
+        if kind != NameKind::File && CPP_KEYWORDS.contains(&ident.as_str()) {
+            format!("{}_", ident)
+        } else {
+            ident
+        }
     }
+
+    pub fn class(&self, name: &IdentName) -> String {
+        self.name(NameKind::Class, name)
+    }
+
+    pub fn file(&self, name: &IdentName) -> String {
+        self.name(NameKind::File, name)
+    }
+
+    pub fn factory(&self, name: &IdentName) -> String {
+        self.name(NameKind::Factory, name)
+    }
+
+    pub fn tag(&self, name: &IdentName) -> String {
+        self.name(NameKind::Tag, name)
+    }
+
+    pub fn method(&self, name: &str) -> String {
+        self.name(NameKind::Method, &name.into())
+    }
+
+    /// Name of a method parameter, kept as in Rust unless it is a C++ keyword
+    pub fn parameter(&self, name: &str) -> String {
+        if CPP_KEYWORDS.contains(&name) {
+            format!("{}_", name)
+        } else {
+            name.to_owned()
+        }
+    }
+
+    /// Styled name of a constant, the words of `SCREAMING_SNAKE_CASE` are not acronyms
+    pub fn constant(&self, name: &str) -> String {
+        if name.chars().any(|c| c.is_ascii_lowercase()) {
+            self.name(NameKind::Constant, &name.into())
+        } else {
+            self.name(NameKind::Constant, &name.to_ascii_lowercase().as_str().into())
+        }
+    }
+
+    /// Fails when two generated identifiers collide in the same C++ scope, e.g. variants
+    /// `HTTPError` and `HttpError` both generating `is_http_error()`
+    /// `items` are paired with the namespace they are generated into.
+    pub fn check_collisions(&self, items: &[(&ItemEnum, &[String])]) -> Result<()> {
+        let mut files = Scope::new("file");
+        let mut classes = Scope::new("class");
+
+        for (item, namespace) in items {
+            let enum_name = attrs::enum_name(item)?;
+            let origin = format!("enum {}", item.ident);
+            let file = namespace
+                .iter()
+                .cloned()
+                .chain(std::iter::once(self.file(&enum_name)))
+                .collect::<Vec<_>>();
+            let class = namespace
+                .iter()
+                .cloned()
+                .chain(std::iter::once(self.class(&enum_name)))
+                .collect::<Vec<_>>();
+            files.insert(&item.ident, file.join("/"), &origin)?;
+            classes.insert(&item.ident, class.join("::"), &origin)?;
+
+            let mut members = Scope::new("member");
+            let mut tags = Scope::new("tag");
+            let mut union_members = Scope::new("union member");
+
+            members.insert(&item.ident, self.class(&enum_name), "the class name")?;
+            for internal in ["tag_", "union_", "value_", "tagged_init_", "deinit_union_"] {
+                members.insert(&item.ident, internal.to_owned(), "the generated code")?;
+            }
+            if Derives::of(&item.attrs)?.has(Derive::PartialOrd) {
+                members.insert(&item.ident, self.method("partial_cmp"), "partial_cmp")?;
+            }
+
+            for variant in item.variants.iter() {
+                let name = attrs::variant_name(variant)?;
+                let origin = format!("variant {}", variant.ident);
+
+                tags.insert(&item.ident, self.tag(&name), &origin)?;
+                members.insert(&item.ident, self.factory(&name), &origin)?;
+                members.insert(&item.ident, self.name(NameKind::Is, &name), &origin)?;
+                if let Fields::Unit = variant.fields {
+                    continue;
+                }
+
+                union_members.insert(
+                    &item.ident,
+                    self.name(NameKind::UnionMember, &name),
+                    &origin,
+                )?;
+                for kind in [
+                    NameKind::RefUncheck,
+                    NameKind::Ref,
+                    NameKind::Ptr,
+                    NameKind::As,
+                    NameKind::Expect,
+                    NameKind::Into,
+                    NameKind::Take,
+                    NameKind::Emplace,
+                ] {
+                    members.insert(&item.ident, self.name(kind, &name), &origin)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Generated identifiers of one C++ scope, with what generated them
+struct Scope {
+    kind: &'static str,
+    idents: HashMap<String, String>,
+}
+
+impl Scope {
+    fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            idents: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, item: &Ident, ident: String, origin: &str) -> Result<()> {
+        match self.idents.get(&ident) {
+            Some(previous) if previous != origin => Err(Error::NamingError(
+                item.to_string(),
+                format!(
+                    "{} and {} both generate the {} {}, rename one or change the naming",
+                    previous, origin, self.kind, ident
+                ),
+            )),
+            _ => {
+                self.idents.insert(ident, origin.to_owned());
+                Ok(())
+            }
+        }
+    }
+}
+
+/// C++ keywords and alternative tokens, plus standard macros that cannot be used as identifiers
+#[rustfmt::skip]
+const CPP_KEYWORDS: &[&str] = &[
+    "alignas", "alignof", "and", "and_eq", "asm", "auto", "bitand", "bitor", "bool", "break",
+    "case", "catch", "char", "char8_t", "char16_t", "char32_t", "class", "compl", "concept",
+    "const", "consteval", "constexpr", "constinit", "const_cast", "continue", "co_await",
+    "co_return", "co_yield", "decltype", "default", "delete", "do", "double", "dynamic_cast",
+    "else", "enum", "explicit", "export", "extern", "false", "float", "for", "friend", "goto",
+    "if", "inline", "int", "long", "mutable", "namespace", "new", "noexcept", "not", "not_eq",
+    "nullptr", "operator", "or", "or_eq", "private", "protected", "public", "register",
+    "reinterpret_cast", "requires", "return", "short", "signed", "sizeof", "static",
+    "static_assert", "static_cast", "struct", "switch", "template", "this", "thread_local",
+    "throw", "true", "try", "typedef", "typeid", "typename", "union", "unsigned", "using",
+    "virtual", "void", "volatile", "wchar_t", "while", "xor", "xor_eq",
+    // macros from the standard library
+    "assert", "errno", "offsetof", "setjmp", "va_arg", "va_end", "va_start", "EOF", "NULL",
+];
+
+/// Identifiers reserved to the C++ implementation, which start with `_` and an uppercase letter,
+/// or contain `__`
+fn is_reserved_identifier(ident: &str) -> bool {
+    let mut chars = ident.chars();
+    (chars.next() == Some('_') && chars.next().is_some_and(|c| c.is_ascii_uppercase()))
+        || ident.contains("__")
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -112,13 +525,13 @@ impl IdentPart {
         }
     }
 
-    // pub fn to_all_upper_case(&self) -> String {
-    //     match self {
-    //         IdentPart::CapitalFirst(s) => s.to_uppercase(),
-    //         IdentPart::AllUpperCase(s) => s.clone(),
-    //         IdentPart::AllLowerCase(s) => s.to_uppercase(),
-    //     }
-    // }
+    pub fn to_all_upper_case(&self) -> String {
+        match self {
+            IdentPart::CapitalFirst(s) => s.to_uppercase(),
+            IdentPart::AllUpperCase(s) => s.clone(),
+            IdentPart::AllLowerCase(s) => s.to_uppercase(),
+        }
+    }
 
     pub fn to_all_lower_case(&self) -> String {
         match self {
@@ -246,6 +659,9 @@ fn parse_ident_part(s: String) -> IdentPart {
 }
 
 fn parse_ident_name(ident: &str) -> Vec<IdentPart> {
+    // raw identifiers like `r#type` are styled without their prefix, leading and
+    // trailing underscores would make reserved or mangled C++ identifiers
+    let ident = ident.strip_prefix("r#").unwrap_or(ident).trim_matches('_');
     let segments = segment_ident_name(ident);
     segments.into_iter().map(parse_ident_part).collect()
 }
@@ -324,4 +740,149 @@ mod test {
             IdentPart::AllLowerCase("usb2".to_owned())
         );
     }
+
+    #[test]
+    fn test_naming_profiles() {
+        let name = IdentName::from("ByteBuffer");
+
+        let google = Naming::profile("google").unwrap();
+        assert_eq!(google.tag(&name), "kByteBuffer");
+        assert_eq!(google.name(NameKind::Is, &name), "IsByteBuffer");
+        assert_eq!(google.method("partial_cmp"), "PartialCmp");
+        assert_eq!(google.constant("MAX_USB_LEN"), "kMaxUsbLen");
+
+        let llvm = Naming::profile("llvm").unwrap();
+        assert_eq!(llvm.factory(&name), "byteBuffer");
+        assert_eq!(llvm.file(&name), "ByteBuffer");
+        assert_eq!(llvm.name(NameKind::Ptr, &name), "getIfByteBuffer");
+        assert_eq!(llvm.constant("maxLen"), "MaxLen");
+
+        let std = Naming::profile("std").unwrap();
+        assert_eq!(std.class(&name), "byte_buffer");
+        assert_eq!(
+            std.name(NameKind::RefUncheck, &name),
+            "byte_buffer_ref_unchecked"
+        );
+
+        assert_eq!(
+            Naming::default().name(NameKind::UnionMember, &name),
+            "byte_buffer_val"
+        );
+        assert!(Naming::profile("java").is_err());
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        let name = IdentName::from("ByteBuffer");
+        assert_eq!(
+            Pattern::parse("m_{camel}").unwrap().apply(&name),
+            "m_byteBuffer"
+        );
+        assert_eq!(
+            Pattern::parse("K_{SNAKE}_2").unwrap().apply(&name),
+            "K_BYTE_BUFFER_2"
+        );
+
+        assert!(Pattern::parse("value").is_err());
+        assert!(Pattern::parse("{snake}_{snake}").is_err());
+        assert!(Pattern::parse("{Pascal}{snake}").is_err());
+        assert!(Pattern::parse("get-{snake}").is_err());
+        assert!(Pattern::parse("2{snake}").is_err());
+    }
+
+    #[test]
+    fn test_naming_from_config() {
+        let name = IdentName::from("ByteBuffer");
+
+        let naming =
+            Naming::from_config("# style\nis = Has{Pascal}\n\nbase = google # ignored order\n")
+                .unwrap();
+        assert_eq!(naming.name(NameKind::Is, &name), "HasByteBuffer");
+        assert_eq!(naming.tag(&name), "kByteBuffer");
+
+        let naming = Naming::from_config("ref = {snake}").unwrap();
+        assert_eq!(naming.name(NameKind::Ref, &name), "byte_buffer");
+        assert_eq!(naming.name(NameKind::Ptr, &name), "byte_buffer_ptr");
+
+        let err = Naming::from_config("tag = k{Pascal}\nunknown = {snake}").unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{}", err);
+        let err = Naming::from_config("\ntag").unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{}", err);
+        assert!(Naming::from_config("base = java").is_err());
+    }
+
+    #[test]
+    fn test_escape_keywords() {
+        let std = Naming::profile("std").unwrap();
+        assert_eq!(std.factory(&"Default".into()), "default_");
+        assert_eq!(std.class(&"Union".into()), "union_");
+        assert_eq!(std.name(NameKind::Is, &"Default".into()), "is_default");
+        assert_eq!(std.file(&"Union".into()), "union");
+        assert_eq!(Naming::default().tag(&"Union".into()), "Union");
+
+        assert_eq!(std.factory(&"r#type".into()), "type");
+        assert_eq!(std.factory(&"r#while".into()), "while_");
+        assert_eq!(std.factory(&"_Hidden".into()), "hidden");
+
+        assert!(Pattern::parse("_{Pascal}").is_err());
+        assert!(Pattern::parse("{snake}__impl").is_err());
+        assert!(Pattern::parse("_{snake}").is_ok());
+    }
+
+    #[test]
+    fn test_check_collisions() {
+        let check = |naming: &Naming, code: &str| {
+            let items: Vec<ItemEnum> = syn::parse_str::<syn::File>(code)
+                .unwrap()
+                .items
+                .into_iter()
+                .filter_map(|item| match item {
+                    syn::Item::Enum(item) => Some(item),
+                    _ => None,
+                })
+                .collect();
+            let namespace = vec!["t".to_owned()];
+            naming.check_collisions(
+                &items.iter().map(|item| (item, namespace.as_slice())).collect::<Vec<_>>(),
+            )
+        };
+        let default = Naming::default();
+        let std = Naming::profile("std").unwrap();
+
+        assert!(check(&default, "enum Status { Ok, HttpError(u8), Delete(u8) }").is_ok());
+
+        let err = check(&default, "enum Status { HTTPError(u8), HttpError(u8) }").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("variant HTTPError and variant HttpError both generate the member"),
+            "{}",
+            err
+        );
+
+        let err = check(&default, "enum Empty { Empty }").unwrap_err();
+        assert!(
+            err.to_string().contains("the class name and variant Empty"),
+            "{}",
+            err
+        );
+
+        // tags are in their own scope, but the factory `is_ok` collides with `Ok`'s `is_ok()`
+        assert!(check(&default, "enum Status { Ok, IsOk }").is_ok());
+        assert!(check(&std, "enum Status { Ok, IsOk }").is_err());
+
+        assert!(check(&default, "enum Status { PartialCmp }").is_ok());
+        assert!(check(&std, "enum Status { PartialCmp }").is_ok());
+        assert!(check(
+            &std,
+            "#[derive(PartialEq, PartialOrd)] enum Status { PartialCmp }"
+        )
+        .is_err());
+
+        let err = check(&default, "enum Status { A } enum status { B }").unwrap_err();
+        assert!(
+            err.to_string().contains("both generate the file t/status"),
+            "{}",
+            err
+        );
+    }
 }
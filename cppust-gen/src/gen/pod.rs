@@ -0,0 +1,84 @@
+use syn::{Fields, ItemEnum, Type};
+
+use super::{attrs::FieldAttrs, config::Config, layout::POD_TYPES, types};
+
+/// Whether every payload of `item` is trivially copyable in C++: numbers, `bool`, `char`, arrays
+/// of them and other POD enums, one per variant as a `std::tuple` is never trivially copyable.
+/// The class then defaults its special members instead of defining them in the source file, and
+/// defines its factories and accessors `constexpr` in the header
+pub fn is_pod(item: &ItemEnum, config: &Config) -> bool {
+    item.variants.iter().all(|variant| match &variant.fields {
+        Fields::Unit => true,
+        Fields::Unnamed(_) if variant.fields.len() == 1 => {
+            variant.fields.iter().all(|field| {
+                matches!(FieldAttrs::of(field).map(|attrs| attrs.ty), Ok(None))
+                    && is_trivial(&field.ty, config)
+            })
+        }
+        _ => false,
+    })
+}
+
+fn is_trivial(ty: &Type, config: &Config) -> bool {
+    let ident = match ty {
+        Type::Path(path) if path.qself.is_none() => match path.path.get_ident() {
+            Some(ident) => ident.to_string(),
+            None => return false,
+        },
+        Type::Array(array) => {
+            return types::array_len(array).is_ok() && is_trivial(&array.elem, config)
+        }
+        Type::Paren(paren) => return is_trivial(&paren.elem, config),
+        _ => return false,
+    };
+
+    if POD_TYPES.contains(&ident.as_str()) {
+        return true;
+    }
+    if let Some(class) = config.classes.get(&ident) {
+        return class.pod;
+    }
+    match config.aliases.get(&ident) {
+        Some(alias) if alias.params.is_empty() => is_trivial(&alias.ty, config),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen::config::UserClass;
+
+    #[test]
+    fn test_is_pod() {
+        let mut config = Config::default();
+        config.classes.insert(
+            "Color".to_owned(),
+            UserClass {
+                namespace: Vec::new(),
+                name: "Color".into(),
+                layout: false,
+                pod: true,
+            },
+        );
+        let is_pod = |code: &str| {
+            let item: ItemEnum = syn::parse_str(code).unwrap();
+            is_pod(&item, &config)
+        };
+
+        assert!(is_pod("enum A { B, C(u8), D([i32; 4]), E([[u16; 2]; 3]), F(Color), G(char) }"));
+        assert!(is_pod("enum A { B, C }"));
+        for code in [
+            "enum A { B(u8, u8) }",
+            "enum A { B(String) }",
+            "enum A { B(Vec<u8>) }",
+            "enum A { B(Option<u8>) }",
+            "enum A { B([String; 2]) }",
+            "enum A { B([u8; LEN]) }",
+            "enum A { B(Other) }",
+            "enum A { B(#[cppust(type = \"int\")] u8) }",
+        ] {
+            assert!(!is_pod(code), "{}", code);
+        }
+    }
+}
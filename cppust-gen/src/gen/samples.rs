@@ -0,0 +1,134 @@
+use syn::{GenericArgument, ItemEnum, PathArguments, Type, Variant};
+
+use super::{attrs::FieldAttrs, config::Config, types};
+
+/// Depth below which vectors and options of samples are empty, so recursive enums end
+const SAMPLE_DEPTH: usize = 3;
+
+/// How sample values are spelled, in C++ by `--emit-tests` and in Rust by the wire round-trip
+pub trait Syntax {
+    /// A number of the primitive `ty` from its `literal`, e.g. `-7`
+    fn number(&self, ty: &Type, literal: &str) -> Option<String>;
+
+    /// The sample character, outside of ASCII so encodings of strings and chars differ
+    fn char(&self) -> String;
+
+    /// The sample string
+    fn string(&self) -> String;
+
+    /// A vector of `item`, holding `sample` or empty when it is `None`
+    fn vec(&self, item: &Type, sample: Option<String>) -> Option<String>;
+
+    /// The option `ty`, holding `sample` or none when it is `None`
+    fn option(&self, ty: &Type, sample: Option<String>) -> Option<String>;
+
+    /// The result `ty` holding `sample`, as its ok value or else its error
+    fn result(&self, ty: &Type, sample: String, is_ok: bool) -> Option<String>;
+
+    /// `variant` of `item` made from the samples of its fields
+    fn variant(&self, variant: &Variant, item: &ItemEnum, samples: Vec<String>) -> Option<String>;
+
+    /// A default-constructed value of the C++ type `cpp_type`, used for every other type
+    fn constructed(&self, cpp_type: &str) -> Option<String>;
+}
+
+/// A fresh value of `ty`, `None` when every value of it is infinite
+pub fn sample(
+    syntax: &dyn Syntax,
+    ty: &Type,
+    config: &Config,
+    items: &[&(&ItemEnum, Config)],
+    depth: usize,
+) -> Option<String> {
+    let constructed = || syntax.constructed(&types::type_to_cpp_type(ty, config).ok()?);
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() && path.path.segments.len() == 1 => {
+            &path.path.segments[0]
+        }
+        Type::Paren(paren) => return sample(syntax, &paren.elem, config, items, depth),
+        _ => return constructed(),
+    };
+    let args: Vec<&Type> = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let nested = |ty: &Type| {
+        Some(depth)
+            .filter(|depth| *depth < SAMPLE_DEPTH)
+            .and_then(|depth| sample(syntax, ty, config, items, depth + 1))
+    };
+
+    let ident = segment.ident.to_string();
+    match (ident.as_str(), args.as_slice()) {
+        ("f32" | "f64", []) => syntax.number(ty, "0.5"),
+        ("i8" | "i16" | "i32" | "i64" | "isize", []) => syntax.number(ty, "-7"),
+        ("u8" | "u16" | "u32" | "u64" | "usize", []) => syntax.number(ty, "7"),
+        ("bool", []) => Some("true".to_owned()),
+        ("char", []) => Some(syntax.char()),
+        ("String", []) => Some(syntax.string()),
+        ("Vec", [item]) => syntax.vec(item, nested(item)),
+        ("Option", [item]) => syntax.option(ty, nested(item)),
+        ("Result", [ok, err]) => match sample(syntax, ok, config, items, depth + 1) {
+            Some(sample) => syntax.result(ty, sample, true),
+            None => syntax.result(ty, sample(syntax, err, config, items, depth + 1)?, false),
+        },
+        (_, []) if config.classes.contains_key(&ident) => {
+            if depth >= SAMPLE_DEPTH {
+                return None;
+            }
+            let (item, _) = items
+                .iter()
+                .copied()
+                .find(|(item, _)| item.ident == ident.as_str())?;
+            item.variants
+                .iter()
+                .find_map(|variant| variant_sample(syntax, variant, item, config, items, depth + 1))
+        }
+        _ => match config.aliases.get(&ident) {
+            // aliases are sampled as the types they stand for
+            Some(alias) => {
+                let ty = types::expand_alias(alias, segment);
+                sample(syntax, &ty, config, items, depth)
+            }
+            None => constructed(),
+        },
+    }
+}
+
+/// The samples of the fields of `variant`, separately
+pub fn field_samples(
+    syntax: &dyn Syntax,
+    variant: &Variant,
+    config: &Config,
+    items: &[&(&ItemEnum, Config)],
+    depth: usize,
+) -> Option<Vec<String>> {
+    variant
+        .fields
+        .iter()
+        .map(|field| match FieldAttrs::of(field).ok()?.ty {
+            Some(ty) => syntax.constructed(&ty),
+            None => sample(syntax, &field.ty, config, items, depth),
+        })
+        .collect()
+}
+
+/// A fresh `variant` of `item`, `None` when every value of it is infinite
+pub fn variant_sample(
+    syntax: &dyn Syntax,
+    variant: &Variant,
+    item: &ItemEnum,
+    config: &Config,
+    items: &[&(&ItemEnum, Config)],
+    depth: usize,
+) -> Option<String> {
+    let samples = field_samples(syntax, variant, config, items, depth)?;
+    syntax.variant(variant, item, samples)
+}
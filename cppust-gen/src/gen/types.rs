@@ -1,86 +1,127 @@
 use std::collections::{HashMap, HashSet};
 
-use syn::{Field, Fields, GenericArgument, Path, PathArguments, PathSegment, Type};
+use syn::{
+    Expr, Field, Fields, GenericArgument, ItemEnum, Lit, Path, PathArguments, PathSegment, Type,
+    TypeArray, UnOp,
+};
 
 use super::{
+    attrs::FieldAttrs,
+    config::{Config, UserAlias},
     error::{Error, Result},
-    names::IdentName,
 };
 
-pub fn fields_to_cpp_type(ty: &Fields) -> Result<String> {
+pub fn fields_to_cpp_type(ty: &Fields, config: &Config) -> Result<String> {
     Ok(match ty {
         Fields::Unit => "void".to_string(),
         Fields::Named(_) => return Err(Error::UnknownFieldsType(ty.clone())),
-        Fields::Unnamed(_) => unnamed_to_cpp_type(ty)?,
+        Fields::Unnamed(_) => unnamed_to_cpp_type(ty, config)?,
     })
 }
 
-pub fn fields_to_cpp_dtor(ty: &Fields) -> Result<String> {
+pub fn fields_to_cpp_dtor(ty: &Fields, config: &Config) -> Result<String> {
     Ok(match ty {
         Fields::Unit => return Err(Error::UnknownFieldsType(ty.clone())),
         Fields::Named(_) => return Err(Error::UnknownFieldsType(ty.clone())),
-        Fields::Unnamed(_) => unnamed_to_cpp_dtor(ty)?,
+        Fields::Unnamed(_) => unnamed_to_cpp_dtor(ty, config)?,
     })
 }
 
-fn unnamed_to_cpp_type(unnamed: &Fields) -> Result<String> {
+fn unnamed_to_cpp_type(unnamed: &Fields, config: &Config) -> Result<String> {
     let fields: Vec<&Field> = unnamed.iter().collect();
 
-    if fields.len() == 0 {
+    if fields.is_empty() {
         Ok("void".to_owned())
     } else if fields.len() == 1 {
-        Ok(field_to_cpp_type(fields.get(0).unwrap())?)
+        Ok(field_to_cpp_type(fields[0], config)?)
     } else {
-        let field_type_names: Result<Vec<_>> = fields.into_iter().map(field_to_cpp_type).collect();
+        let field_type_names: Result<Vec<_>> = fields
+            .into_iter()
+            .map(|field| field_to_cpp_type(field, config))
+            .collect();
         Ok(format!("std::tuple<{}>", field_type_names?.join(", ")))
     }
 }
 
-fn unnamed_to_cpp_dtor(unnamed: &Fields) -> Result<String> {
+fn unnamed_to_cpp_dtor(unnamed: &Fields, config: &Config) -> Result<String> {
     let fields: Vec<&Field> = unnamed.iter().collect();
 
-    if fields.len() == 0 {
+    if fields.is_empty() {
         Err(Error::UnknownFieldsType(unnamed.clone()))
     } else if fields.len() == 1 {
-        Ok(field_to_cpp_dtor(fields.get(0).unwrap())?)
+        Ok(field_to_cpp_dtor(fields[0], config)?)
     } else {
         Ok("~tuple".to_string())
     }
 }
 
-fn field_to_cpp_type(field: &Field) -> Result<String> {
-    type_to_cpp_type(&field.ty)
+/// C++ type of a field, which is overridden by `#[cppust(type = "...")]`
+pub fn field_to_cpp_type(field: &Field, config: &Config) -> Result<String> {
+    match FieldAttrs::of(field)?.ty {
+        Some(ty) => Ok(ty),
+        None => type_to_cpp_type(&field.ty, config),
+    }
 }
 
-fn field_to_cpp_dtor(field: &Field) -> Result<String> {
-    type_to_cpp_dtor(&field.ty)
+fn field_to_cpp_dtor(field: &Field, config: &Config) -> Result<String> {
+    match FieldAttrs::of(field)?.ty {
+        // `std::span<const u8>` is destroyed by `~span()`
+        Some(ty) => {
+            let name = ty.split('<').next().unwrap().trim();
+            Ok(format!("~{}", name.rsplit("::").next().unwrap()))
+        }
+        None => type_to_cpp_dtor(&field.ty, config),
+    }
 }
 
-pub fn type_to_cpp_type(ty: &Type) -> Result<String> {
+pub fn type_to_cpp_type(ty: &Type, config: &Config) -> Result<String> {
     match ty {
-        Type::Path(type_path) => type_path_to_cpp_type(&type_path.path),
-        _ => return Err(Error::UnknownType(ty.clone())),
+        Type::Path(type_path) => type_path_to_cpp_type(&type_path.path, config),
+        Type::Array(array) => Ok(format!(
+            "std::array<{}, {}>",
+            type_to_cpp_type(&array.elem, config)?,
+            array_len(array)?
+        )),
+        _ => Err(Error::UnknownType(ty.clone())),
     }
 }
 
-pub fn type_to_cpp_dtor(ty: &Type) -> Result<String> {
+pub fn type_to_cpp_dtor(ty: &Type, config: &Config) -> Result<String> {
     match ty {
-        Type::Path(type_path) => type_path_to_cpp_dtor(&type_path.path),
-        _ => return Err(Error::UnknownType(ty.clone())),
+        Type::Path(type_path) => type_path_to_cpp_dtor(&type_path.path, config),
+        Type::Array(_) => Ok("~array".to_owned()),
+        _ => Err(Error::UnknownType(ty.clone())),
+    }
+}
+
+/// Length of `[T; N]`, which must be an integer literal
+pub fn array_len(array: &TypeArray) -> Result<u64> {
+    match &array.len {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => int
+                .base10_parse()
+                .map_err(|_| Error::UnknownType(Type::Array(array.clone()))),
+            _ => Err(Error::UnknownType(Type::Array(array.clone()))),
+        },
+        _ => Err(Error::UnknownType(Type::Array(array.clone()))),
     }
 }
 
-fn type_path_to_cpp_type(path: &Path) -> Result<String> {
-    let segments: Result<Vec<_>> = path.segments.iter().map(path_segment_to_cpp_type).collect();
+fn type_path_to_cpp_type(path: &Path, config: &Config) -> Result<String> {
+    let segments: Result<Vec<_>> = path
+        .segments
+        .iter()
+        .map(|segment| path_segment_to_cpp_type(segment, config))
+        .collect();
     Ok(segments?.join("::"))
 }
 
-fn type_path_to_cpp_dtor(path: &Path) -> Result<String> {
+fn type_path_to_cpp_dtor(path: &Path, config: &Config) -> Result<String> {
     let last_segment = path.segments.last().unwrap();
-    Ok(path_segment_to_cpp_dtor(last_segment)?)
+    path_segment_to_cpp_dtor(last_segment, config)
 }
 
-fn path_segment_to_cpp_type(segment: &PathSegment) -> Result<String> {
+fn path_segment_to_cpp_type(segment: &PathSegment, config: &Config) -> Result<String> {
     let template_args = match &segment.arguments {
         PathArguments::None => String::new(),
         PathArguments::AngleBracketed(args) => {
@@ -91,7 +132,7 @@ fn path_segment_to_cpp_type(segment: &PathSegment) -> Result<String> {
                     GenericArgument::Type(t) => Some(t),
                     _ => None,
                 })
-                .map(type_to_cpp_type)
+                .map(|t| type_to_cpp_type(t, config))
                 .collect();
 
             format!("<{}>", args?.join(", "))
@@ -101,28 +142,50 @@ fn path_segment_to_cpp_type(segment: &PathSegment) -> Result<String> {
 
     let ident_name = segment.ident.to_string();
 
-    let type_map = HashMap::from([("Vec", "std::vector"), ("String", "std::string")]);
+    let type_map = HashMap::from([
+        ("Vec", "std::vector"),
+        ("String", "std::string"),
+        ("Option", "::cppust::Option"),
+        ("Result", "::cppust::Result"),
+        ("bool", "bool"),
+        ("char", "char32_t"),
+    ]);
 
     let cppust_prefixing_types = HashSet::from([
         "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64", "usize", "isize",
     ]);
 
-    let ident_name = if let Some(mapped) = type_map.get(ident_name.as_str()) {
+    let ident_name = if config.type_params.contains(&ident_name) {
+        ident_name
+    } else if let Some(mapped) = type_map.get(ident_name.as_str()) {
         mapped.to_string()
-    } else if cppust_prefixing_types.get(ident_name.as_str()).is_some() {
+    } else if cppust_prefixing_types.contains(ident_name.as_str()) {
         format!("::cppust::{}", ident_name)
+    } else if config.aliases.contains_key(&ident_name) {
+        config.alias_ref(&ident_name)
     } else {
-        let ident_name: IdentName = ident_name.as_str().into();
-        ident_name.to_class_name()
+        config.class_ref(&ident_name)
     };
 
     Ok(format!("{}{}", ident_name, template_args))
 }
 
-fn path_segment_to_cpp_dtor(segment: &PathSegment) -> Result<String> {
+fn path_segment_to_cpp_dtor(segment: &PathSegment, config: &Config) -> Result<String> {
     let ident_name = segment.ident.to_string();
 
-    let type_map = HashMap::from([("Vec", "~vector"), ("String", "~string")]);
+    // an alias is destroyed as the type it stands for
+    if let Some(alias) = config.aliases.get(&ident_name) {
+        return type_to_cpp_dtor(&expand_alias(alias, segment), config);
+    }
+
+    let type_map = HashMap::from([
+        ("Vec", "~vector"),
+        ("String", "~basic_string"),
+        ("Option", "~Option"),
+        ("Result", "~Result"),
+        ("bool", "::cppust::bool_::~bool_"),
+        ("char", "::cppust::char_::~char_"),
+    ]);
 
     let cppust_prefixing_types = HashSet::from([
         "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64", "usize", "isize",
@@ -130,12 +193,185 @@ fn path_segment_to_cpp_dtor(segment: &PathSegment) -> Result<String> {
 
     let ident_name = if let Some(mapped) = type_map.get(ident_name.as_str()) {
         mapped.to_string()
-    } else if cppust_prefixing_types.get(ident_name.as_str()).is_some() {
-        format!("~{}", ident_name)
+    } else if cppust_prefixing_types.contains(ident_name.as_str()) {
+        format!("::cppust::{0}::~{0}", ident_name)
     } else {
-        let ident_name: IdentName = ident_name.as_str().into();
-        format!("~{}", ident_name.to_class_name())
+        format!("~{}", config.class_name(&ident_name))
     };
 
     Ok(ident_name)
 }
+
+/// The type `alias` stands for, with the generic arguments of `segment` substituted
+pub fn expand_alias(alias: &UserAlias, segment: &PathSegment) -> Type {
+    let args: Vec<&Type> = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|g| match g {
+                GenericArgument::Type(t) => Some(t),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let mut ty = alias.ty.clone();
+    substitute(&mut ty, &alias.params, &args);
+    ty
+}
+
+/// Replaces the generic parameters `params` in `ty` by `args`, e.g. `T` by `u8`
+pub fn substitute(ty: &mut Type, params: &[String], args: &[&Type]) {
+    let param = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => params
+            .iter()
+            .position(|param| type_path.path.is_ident(param)),
+        _ => None,
+    };
+    if let Some(index) = param {
+        if let Some(arg) = args.get(index) {
+            *ty = (*arg).clone();
+        }
+        return;
+    }
+
+    if let Type::Array(array) = ty {
+        substitute(&mut array.elem, params, args);
+        return;
+    }
+    if let Type::Path(type_path) = ty {
+        for segment in type_path.path.segments.iter_mut() {
+            if let PathArguments::AngleBracketed(generics) = &mut segment.arguments {
+                for generic in generics.args.iter_mut() {
+                    if let GenericArgument::Type(t) = generic {
+                        substitute(t, params, args);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whether any field refers to the Rust type `ident`, e.g. `Vec`, including generic arguments,
+/// fields with a `#[cppust(type = "...")]` are ignored
+pub fn fields_use_type(fields: &Fields, ident: &str) -> bool {
+    fields
+        .iter()
+        .filter(|field| !matches!(FieldAttrs::of(field).map(|attrs| attrs.ty), Ok(Some(_))))
+        .any(|field| type_uses_type(&field.ty, ident))
+}
+
+/// Rust idents of the other enums of the input that payloads of `item` refer to, directly or
+/// through type aliases, sorted
+pub fn user_types(item: &ItemEnum, config: &Config) -> Vec<String> {
+    let refers_to = |ident: &str| {
+        item.variants.iter().any(|v| {
+            fields_use_type(&v.fields, ident)
+                || aliases_used(&v.fields, config)
+                    .iter()
+                    .any(|alias| type_refers_to(&config.aliases[alias].ty, ident, config))
+        })
+    };
+
+    let mut idents: Vec<_> = config
+        .classes
+        .keys()
+        .filter(|ident| item.ident != ident.as_str())
+        .filter(|ident| refers_to(ident))
+        .cloned()
+        .collect();
+    idents.sort();
+    idents
+}
+
+/// Rust idents of the type aliases of the input that `fields` refer to, sorted
+pub fn aliases_used(fields: &Fields, config: &Config) -> Vec<String> {
+    let mut idents: Vec<_> = config
+        .aliases
+        .keys()
+        .filter(|ident| fields_use_type(fields, ident))
+        .cloned()
+        .collect();
+    idents.sort();
+    idents
+}
+
+/// Whether `ty` refers to the Rust type `ident`, directly or through type aliases, which rustc
+/// guarantees not to be cyclic
+pub fn type_refers_to(ty: &Type, ident: &str, config: &Config) -> bool {
+    type_uses_type(ty, ident)
+        || config.aliases.iter().any(|(alias, target)| {
+            alias != ident
+                && type_uses_type(ty, alias)
+                && type_refers_to(&target.ty, ident, config)
+        })
+}
+
+pub fn type_uses_type(ty: &Type, ident: &str) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.iter().any(|segment| {
+            segment.ident == ident
+                || match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => args.args.iter().any(|g| match g {
+                        GenericArgument::Type(t) => type_uses_type(t, ident),
+                        _ => false,
+                    }),
+                    _ => false,
+                }
+        }),
+        Type::Reference(reference) => type_uses_type(&reference.elem, ident),
+        Type::Paren(paren) => type_uses_type(&paren.elem, ident),
+        Type::Array(array) => type_uses_type(&array.elem, ident),
+        _ => false,
+    }
+}
+
+/// Whether any field is or contains a `[T; N]`, fields with a `#[cppust(type = "...")]` are
+/// ignored
+pub fn fields_use_array(fields: &Fields) -> bool {
+    fields
+        .iter()
+        .filter(|field| !matches!(FieldAttrs::of(field).map(|attrs| attrs.ty), Ok(Some(_))))
+        .any(|field| type_uses_array(&field.ty))
+}
+
+pub fn type_uses_array(ty: &Type) -> bool {
+    match ty {
+        Type::Array(_) => true,
+        Type::Path(type_path) => type_path.path.segments.iter().any(|segment| {
+            match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().any(|g| match g {
+                    GenericArgument::Type(t) => type_uses_array(t),
+                    _ => false,
+                }),
+                _ => false,
+            }
+        }),
+        Type::Reference(reference) => type_uses_array(&reference.elem),
+        Type::Paren(paren) => type_uses_array(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Value of a discriminant accepted by `discriminant_to_cpp`
+pub fn discriminant_value(expr: &Expr) -> Result<i128> {
+    discriminant_to_cpp(expr)?
+        .parse()
+        .map_err(|_| Error::UnsupportedExpr(expr.clone()))
+}
+
+pub fn discriminant_to_cpp(expr: &Expr) -> Result<String> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => Ok(int.base10_digits().to_owned()),
+            _ => Err(Error::UnsupportedExpr(expr.clone())),
+        },
+        Expr::Unary(unary) => match unary.op {
+            UnOp::Neg(_) => Ok(format!("-{}", discriminant_to_cpp(&unary.expr)?)),
+            _ => Err(Error::UnsupportedExpr(expr.clone())),
+        },
+        Expr::Paren(paren) => discriminant_to_cpp(&paren.expr),
+        _ => Err(Error::UnsupportedExpr(expr.clone())),
+    }
+}
@@ -0,0 +1,131 @@
+use std::{collections::HashMap, io::Write};
+
+use super::error::{Error, Result};
+
+const BEGIN: &str = "// BEGIN USER CODE ";
+const END: &str = "// END USER CODE";
+
+/// Writes an empty user code region `id`, which keeps its content when the file is regenerated
+pub fn write_region(f: &mut dyn Write, indent: &str, id: &str) -> Result<()> {
+    writeln!(f, "{}{}{}", indent, BEGIN, id)?;
+    writeln!(f, "{}{}", indent, END)?;
+
+    Ok(())
+}
+
+/// Copies the content of the user code regions of the `existing` file into the regions of the
+/// `generated` one. Regions which disappeared from the generated file are an error, rather
+/// than dropping user code.
+pub fn restore(generated: &str, existing: &str, file: &str) -> Result<String> {
+    let mut user_code: HashMap<_, _> = regions(existing, file)?.into_iter().collect();
+    // validates the generated regions too
+    regions(generated, file)?;
+
+    let mut output = String::new();
+    let mut lines = generated.lines();
+    while let Some(line) = lines.next() {
+        output.push_str(line);
+        output.push('\n');
+
+        if let Some(id) = region_id(line) {
+            if let Some(code) = user_code.remove(id) {
+                output.push_str(&code);
+                // skips the generated content, up to the end marker which is written as usual
+                for line in lines.by_ref() {
+                    if line.trim() == END {
+                        output.push_str(line);
+                        output.push('\n');
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut orphans: Vec<_> = user_code.into_keys().collect();
+    if !orphans.is_empty() {
+        orphans.sort();
+        return Err(Error::UserCodeError(
+            file.to_owned(),
+            format!(
+                "regions {} are no longer generated, move their code before regenerating",
+                orphans.join(", ")
+            ),
+        ));
+    }
+
+    Ok(output)
+}
+
+/// `(id, content)` of the user code regions of `text`, the content keeps its line endings
+fn regions(text: &str, file: &str) -> Result<Vec<(String, String)>> {
+    let mut regions: Vec<(String, String)> = Vec::new();
+    let mut current: Option<(String, String)> = None;
+
+    for (number, line) in text.lines().enumerate() {
+        let error = |msg: &str| {
+            Error::UserCodeError(file.to_owned(), format!("line {}: {}", number + 1, msg))
+        };
+
+        if let Some(id) = region_id(line) {
+            if current.is_some() {
+                return Err(error("user code regions cannot be nested"));
+            }
+            if regions.iter().any(|(other, _)| other == id) {
+                return Err(error(&format!("region {} is given more than once", id)));
+            }
+            current = Some((id.to_owned(), String::new()));
+        } else if line.trim() == END {
+            match current.take() {
+                Some(region) => regions.push(region),
+                None => return Err(error("END USER CODE without BEGIN USER CODE")),
+            }
+        } else if let Some((_, code)) = current.as_mut() {
+            code.push_str(line);
+            code.push('\n');
+        }
+    }
+
+    match current {
+        Some((id, _)) => Err(Error::UserCodeError(
+            file.to_owned(),
+            format!("region {} has no END USER CODE", id),
+        )),
+        None => Ok(regions),
+    }
+}
+
+fn region_id(line: &str) -> Option<&str> {
+    line.trim().strip_prefix(BEGIN).map(str::trim)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_restore() {
+        let generated = "#include <a>\n// BEGIN USER CODE includes\n// END USER CODE\nclass A {\n    // BEGIN USER CODE members\n    // END USER CODE\n};\n";
+        let existing = "#include <old>\n// BEGIN USER CODE includes\n#include <vector>\n\n// END USER CODE\nclass A {\n    // BEGIN USER CODE members\n    int f();\n    // END USER CODE\n};\n";
+
+        assert_eq!(
+            restore(generated, existing, "a.hpp").unwrap(),
+            "#include <a>\n// BEGIN USER CODE includes\n#include <vector>\n\n// END USER CODE\nclass A {\n    // BEGIN USER CODE members\n    int f();\n    // END USER CODE\n};\n"
+        );
+        assert_eq!(restore(generated, "", "a.hpp").unwrap(), generated);
+    }
+
+    #[test]
+    fn test_invalid_regions() {
+        let generated = "// BEGIN USER CODE members\n// END USER CODE\n";
+        for existing in [
+            "// BEGIN USER CODE gone\nint f();\n// END USER CODE\n",
+            "// BEGIN USER CODE members\nint f();\n",
+            "int f();\n// END USER CODE\n",
+            "// BEGIN USER CODE members\n// BEGIN USER CODE other\n// END USER CODE\n// END USER CODE\n",
+            "// BEGIN USER CODE members\n// END USER CODE\n// BEGIN USER CODE members\n// END USER CODE\n",
+        ] {
+            assert!(restore(generated, existing, "a.hpp").is_err(), "{}", existing);
+        }
+    }
+}
@@ -0,0 +1,842 @@
+use std::io::Write;
+
+use syn::{Attribute, Fields, GenericArgument, ItemEnum, Lit, Meta, PathArguments, Type, Variant};
+
+use super::{
+    attrs::{self, FieldAttrs},
+    config::Config,
+    error::{Error, Result},
+    names::NameKind,
+    samples::{self, Syntax},
+    types,
+};
+
+/// Numbers encoded little-endian with their Rust width
+const NUMBER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64", "usize", "isize",
+];
+
+/// How a payload is encoded, see `cppust/wire.hpp`
+#[derive(Clone, Debug, PartialEq)]
+enum WireType {
+    Number(String),
+    Bool,
+    Char,
+    Str,
+    Vec(Box<WireType>),
+    Option(Box<WireType>),
+    Result(Box<WireType>, Box<WireType>),
+    /// Another enum of the input, by Rust ident
+    Enum(String),
+}
+
+impl WireType {
+    fn of(ty: &Type, item: &ItemEnum, config: &Config) -> Result<Self> {
+        Self::of_type(ty, config).ok_or_else(|| {
+            Error::WireError(
+                item.ident.to_string(),
+                format!(
+                    "{} has no wire encoding, use numbers, bool, char, String, Vec, Option, \
+                     Result or enums of the input",
+                    types::type_to_cpp_type(ty, config)
+                        .unwrap_or_else(|_| "the payload type".to_owned())
+                ),
+            )
+        })
+    }
+
+    fn of_type(ty: &Type, config: &Config) -> Option<Self> {
+        let segment = match ty {
+            Type::Path(path) if path.qself.is_none() && path.path.segments.len() == 1 => {
+                &path.path.segments[0]
+            }
+            Type::Paren(paren) => return Self::of_type(&paren.elem, config),
+            _ => return None,
+        };
+        let args: Vec<_> = match &segment.arguments {
+            PathArguments::None => Vec::new(),
+            PathArguments::AngleBracketed(args) => args
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    GenericArgument::Type(ty) => Self::of_type(ty, config),
+                    _ => None,
+                })
+                .collect::<Option<_>>()?,
+            PathArguments::Parenthesized(_) => return None,
+        };
+
+        let ident = segment.ident.to_string();
+        let mut args = args.into_iter().map(Box::new);
+        let wire_type = match (ident.as_str(), args.len()) {
+            (number, 0) if NUMBER_TYPES.contains(&number) => Self::Number(ident.clone()),
+            ("bool", 0) => Self::Bool,
+            ("char", 0) => Self::Char,
+            ("String", 0) => Self::Str,
+            ("Vec", 1) => Self::Vec(args.next()?),
+            ("Option", 1) => Self::Option(args.next()?),
+            ("Result", 2) => Self::Result(args.next()?, args.next()?),
+            (_, 0) if config.classes.contains_key(&ident) => Self::Enum(ident),
+            // aliases are encoded as the types they stand for
+            _ => {
+                let alias = config.aliases.get(&ident)?;
+                return Self::of_type(&types::expand_alias(alias, segment), config);
+            }
+        };
+
+        Some(wire_type)
+    }
+
+    fn rust_type(&self) -> String {
+        match self {
+            WireType::Number(ty) => ty.clone(),
+            WireType::Bool => "bool".to_owned(),
+            WireType::Char => "char".to_owned(),
+            WireType::Str => "String".to_owned(),
+            WireType::Vec(ty) => format!("Vec<{}>", ty.rust_type()),
+            WireType::Option(ty) => format!("Option<{}>", ty.rust_type()),
+            WireType::Result(ok, err) => format!("Result<{}, {}>", ok.rust_type(), err.rust_type()),
+            WireType::Enum(ident) => ident.clone(),
+        }
+    }
+
+    /// C++ type, qualified to be referred to from `cppust::wire`
+    fn cpp_type(&self, config: &Config) -> String {
+        match self {
+            WireType::Number(ty) => format!("::cppust::{}", ty),
+            WireType::Bool => "bool".to_owned(),
+            WireType::Char => "char32_t".to_owned(),
+            WireType::Str => "std::string".to_owned(),
+            WireType::Vec(ty) => format!("std::vector<{}>", ty.cpp_type(config)),
+            WireType::Option(ty) => format!("::cppust::Option<{}>", ty.cpp_type(config)),
+            WireType::Result(ok, err) => format!(
+                "::cppust::Result<{}, {}>",
+                ok.cpp_type(config),
+                err.cpp_type(config)
+            ),
+            WireType::Enum(ident) => config.global_class_ref(ident),
+        }
+    }
+}
+
+fn payloads(item: &ItemEnum, config: &Config) -> Result<Vec<Vec<WireType>>> {
+    if item.variants.is_empty() {
+        return Err(Error::WireError(
+            item.ident.to_string(),
+            "enums without variants have no values to encode".to_owned(),
+        ));
+    }
+
+    item.variants
+        .iter()
+        .map(|variant| match &variant.fields {
+            Fields::Named(_) => Err(Error::UnknownFieldsType(variant.fields.clone())),
+            fields => fields
+                .iter()
+                .map(|field| {
+                    if FieldAttrs::of(field)?.ty.is_some() {
+                        return Err(Error::WireError(
+                            item.ident.to_string(),
+                            "fields with #[cppust(type)] have no wire encoding".to_owned(),
+                        ));
+                    }
+                    WireType::of(&field.ty, item, config)
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Sample values spelled in Rust, where every payload type has a wire encoding
+struct RustSyntax;
+
+impl Syntax for RustSyntax {
+    fn number(&self, _: &Type, literal: &str) -> Option<String> {
+        Some(literal.to_owned())
+    }
+
+    fn char(&self) -> String {
+        "'λ'".to_owned()
+    }
+
+    fn string(&self) -> String {
+        "String::from(\"sämple\")".to_owned()
+    }
+
+    fn vec(&self, _: &Type, sample: Option<String>) -> Option<String> {
+        Some(match sample {
+            Some(sample) => format!("vec![{}]", sample),
+            None => "Vec::new()".to_owned(),
+        })
+    }
+
+    fn option(&self, _: &Type, sample: Option<String>) -> Option<String> {
+        Some(match sample {
+            Some(sample) => format!("Some({})", sample),
+            None => "None".to_owned(),
+        })
+    }
+
+    fn result(&self, _: &Type, sample: String, is_ok: bool) -> Option<String> {
+        Some(format!("{}({})", if is_ok { "Ok" } else { "Err" }, sample))
+    }
+
+    fn variant(&self, variant: &Variant, item: &ItemEnum, samples: Vec<String>) -> Option<String> {
+        Some(if samples.is_empty() {
+            format!("{}::{}", item.ident, variant.ident)
+        } else {
+            format!("{}::{}({})", item.ident, variant.ident, samples.join(", "))
+        })
+    }
+
+    fn constructed(&self, _: &str) -> Option<String> {
+        None
+    }
+}
+
+/// Rust expression of a sample value of `variant`
+fn variant_sample(
+    variant: &Variant,
+    item: &ItemEnum,
+    config: &Config,
+    items: &[&(&ItemEnum, Config)],
+) -> Result<Option<String>> {
+    if let Fields::Named(_) = &variant.fields {
+        return Err(Error::UnknownFieldsType(variant.fields.clone()));
+    }
+    for field in variant.fields.iter() {
+        WireType::of(&field.ty, item, config)?;
+    }
+
+    Ok(samples::variant_sample(
+        &RustSyntax,
+        variant,
+        item,
+        config,
+        items,
+        0,
+    ))
+}
+
+/// The doc comments of `attrs`, as Rust source
+fn write_rust_docs(f: &mut dyn Write, attrs: &[Attribute], indent: &str) -> Result<()> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("doc")) {
+        if let Ok(Meta::NameValue(meta)) = attr.parse_meta() {
+            if let Lit::Str(doc) = meta.lit {
+                writeln!(f, "{}///{}", indent, doc.value())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `<module>.rs`, Rust counterparts of `items` with their wire encoding and samples of
+/// each variant
+pub fn write_rust(f: &mut dyn Write, items: &[&(&ItemEnum, Config)], module: &str) -> Result<()> {
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f, "//")?;
+    writeln!(
+        f,
+        "// Rust counterparts of the enums of `{}`, encoded as `cppust/wire.hpp` describes.",
+        module
+    )?;
+    writeln!(f)?;
+
+    f.write_all(
+        br#"/// Encoding shared with the `cppust::wire::Codec` of the C++ classes
+pub trait Wire: Sized {
+    fn encode(&self, output: &mut Vec<u8>);
+
+    /// Decodes a value from the start of `input`, which is advanced past it
+    fn decode(input: &mut &[u8]) -> Option<Self>;
+}
+
+fn take<'a>(input: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+    if input.len() < n {
+        return None;
+    }
+    let (bytes, rest) = input.split_at(n);
+    *input = rest;
+    Some(bytes)
+}
+
+macro_rules! number_wire {
+    ($($ty:ty),*) => {
+        $(
+            impl Wire for $ty {
+                fn encode(&self, output: &mut Vec<u8>) {
+                    output.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(input: &mut &[u8]) -> Option<Self> {
+                    let bytes = take(input, std::mem::size_of::<Self>())?;
+                    Some(Self::from_le_bytes(bytes.try_into().ok()?))
+                }
+            }
+        )*
+    };
+}
+
+number_wire!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, usize, isize);
+
+impl Wire for bool {
+    fn encode(&self, output: &mut Vec<u8>) {
+        (*self as u8).encode(output);
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        match u8::decode(input)? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl Wire for char {
+    fn encode(&self, output: &mut Vec<u8>) {
+        (*self as u32).encode(output);
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        char::from_u32(u32::decode(input)?)
+    }
+}
+
+impl Wire for String {
+    fn encode(&self, output: &mut Vec<u8>) {
+        (self.len() as u64).encode(output);
+        output.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let len = u64::decode(input)?.try_into().ok()?;
+        String::from_utf8(take(input, len)?.to_vec()).ok()
+    }
+}
+
+impl<T: Wire> Wire for Vec<T> {
+    fn encode(&self, output: &mut Vec<u8>) {
+        (self.len() as u64).encode(output);
+        for item in self {
+            item.encode(output);
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let len = u64::decode(input)?;
+        (0..len).map(|_| T::decode(input)).collect()
+    }
+}
+
+impl<T: Wire> Wire for Option<T> {
+    fn encode(&self, output: &mut Vec<u8>) {
+        match self {
+            None => 0u8.encode(output),
+            Some(value) => {
+                1u8.encode(output);
+                value.encode(output);
+            }
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        match u8::decode(input)? {
+            0 => Some(None),
+            1 => Some(Some(T::decode(input)?)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Wire, E: Wire> Wire for Result<T, E> {
+    fn encode(&self, output: &mut Vec<u8>) {
+        match self {
+            Ok(value) => {
+                0u8.encode(output);
+                value.encode(output);
+            }
+            Err(err) => {
+                1u8.encode(output);
+                err.encode(output);
+            }
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        match u8::decode(input)? {
+            0 => Some(Ok(T::decode(input)?)),
+            1 => Some(Err(E::decode(input)?)),
+            _ => None,
+        }
+    }
+}
+"#,
+    )?;
+
+    for (item, config) in items.iter().copied() {
+        writeln!(f)?;
+        write_rust_enum(f, item, config)?;
+    }
+
+    writeln!(f)?;
+    writeln!(
+        f,
+        "/// Encoded samples of each variant by `Enum::Variant`, which are checked to decode back"
+    )?;
+    writeln!(f, "pub fn samples() -> Vec<(&'static str, Vec<u8>)> {{")?;
+    writeln!(f, "    vec![")?;
+    for (item, config) in items.iter().copied() {
+        for variant in item.variants.iter() {
+            let sample = variant_sample(variant, item, config, items)?.ok_or_else(|| {
+                Error::WireError(
+                    item.ident.to_string(),
+                    format!("{} has no finite sample value", variant.ident),
+                )
+            })?;
+            writeln!(
+                f,
+                "        sample(\"{}::{}\", {}),",
+                item.ident, variant.ident, sample
+            )?;
+        }
+    }
+    writeln!(f, "    ]")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    f.write_all(
+        br#"fn sample<T>(name: &'static str, value: T) -> (&'static str, Vec<u8>)
+where
+    T: Wire + PartialEq + std::fmt::Debug,
+{
+    let mut bytes = Vec::new();
+    value.encode(&mut bytes);
+    let mut input = bytes.as_slice();
+    let decoded = T::decode(&mut input);
+    assert_eq!(decoded.as_ref(), Some(&value), "{} does not decode back", name);
+    assert!(input.is_empty(), "{} is not decoded whole", name);
+    (name, bytes)
+}
+"#,
+    )?;
+
+    Ok(())
+}
+
+fn write_rust_enum(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let ident = &item.ident;
+    let payloads = payloads(item, config)?;
+
+    write_rust_docs(f, &item.attrs, "")?;
+    writeln!(f, "#[derive(Clone, Debug, PartialEq)]")?;
+    writeln!(f, "pub enum {} {{", ident)?;
+    for (variant, payload) in item.variants.iter().zip(payloads.iter()) {
+        write_rust_docs(f, &variant.attrs, "    ")?;
+        if payload.is_empty() {
+            writeln!(f, "    {},", variant.ident)?;
+        } else {
+            writeln!(
+                f,
+                "    {}({}),",
+                variant.ident,
+                payload
+                    .iter()
+                    .map(WireType::rust_type)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+    }
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "impl Wire for {} {{", ident)?;
+    writeln!(f, "    fn encode(&self, output: &mut Vec<u8>) {{")?;
+    writeln!(f, "        match self {{")?;
+    for (index, (variant, payload)) in item.variants.iter().zip(payloads.iter()).enumerate() {
+        if payload.is_empty() {
+            writeln!(
+                f,
+                "            {}::{} => {}u32.encode(output),",
+                ident, variant.ident, index
+            )?;
+            continue;
+        }
+        let fields: Vec<_> = (0..payload.len()).map(|i| format!("_{}", i)).collect();
+        writeln!(
+            f,
+            "            {}::{}({}) => {{",
+            ident,
+            variant.ident,
+            fields.join(", ")
+        )?;
+        writeln!(f, "                {}u32.encode(output);", index)?;
+        for field in fields.iter() {
+            writeln!(f, "                {}.encode(output);", field)?;
+        }
+        writeln!(f, "            }}")?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(f, "    fn decode(input: &mut &[u8]) -> Option<Self> {{")?;
+    writeln!(f, "        Some(match u32::decode(input)? {{")?;
+    for (index, (variant, payload)) in item.variants.iter().zip(payloads.iter()).enumerate() {
+        if payload.is_empty() {
+            writeln!(f, "            {} => {}::{},", index, ident, variant.ident)?;
+        } else {
+            writeln!(
+                f,
+                "            {} => {}::{}({}),",
+                index,
+                ident,
+                variant.ident,
+                vec!["Wire::decode(input)?"; payload.len()].join(", ")
+            )?;
+        }
+    }
+    writeln!(f, "            _ => return None,")?;
+    writeln!(f, "        }})")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+
+    Ok(())
+}
+
+/// Writes `<module>_samples.rs`, a binary writing the samples of `<module>.rs` into the file
+/// given as argument, each as an encoded `Vec<u8>`
+pub fn write_rust_samples(f: &mut dyn Write, module: &str) -> Result<()> {
+    let module = super::ffi::symbol(module);
+
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+    writeln!(f, "mod {};", module)?;
+    writeln!(f)?;
+    writeln!(f, "use {}::Wire;", module)?;
+    writeln!(f)?;
+    writeln!(f, "fn main() {{")?;
+    writeln!(
+        f,
+        "    let path = std::env::args().nth(1).expect(\"usage: {}_samples <output>\");",
+        module
+    )?;
+    writeln!(f, "    let mut output = Vec::new();")?;
+    writeln!(f, "    for (_, bytes) in {}::samples() {{", module)?;
+    writeln!(f, "        bytes.encode(&mut output);")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "    std::fs::write(path, output).unwrap();")?;
+    writeln!(f, "}}")?;
+
+    Ok(())
+}
+
+/// Writes `<module>_wire.hpp`, the `cppust::wire::Codec` of the classes of `items`
+pub fn write_header(
+    f: &mut dyn Write,
+    items: &[&(&ItemEnum, Config)],
+    includes: &[String],
+) -> Result<()> {
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+    writeln!(f, "#pragma once")?;
+    writeln!(f)?;
+    if let Some((_, config)) = items.iter().find(|(_, config)| !config.iostream) {
+        write!(f, "{}", config.library_defines())?;
+    }
+    writeln!(f, "#include <cppust/wire.hpp>")?;
+    writeln!(f)?;
+    for include in includes.iter() {
+        writeln!(f, "#include \"{}\"", include)?;
+    }
+    writeln!(f)?;
+    writeln!(f, "#include <utility>")?;
+    writeln!(f)?;
+    writeln!(f, "namespace cppust {{ namespace wire {{")?;
+    writeln!(f)?;
+
+    // declared first, as payloads may refer to classes coming later
+    for (item, config) in items.iter().copied() {
+        let class = config.global_class_ref(&item.ident.to_string());
+        writeln!(f, "template <>")?;
+        writeln!(f, "struct Codec<{}> {{", class)?;
+        writeln!(
+            f,
+            "    static void encode(Encoder& encoder, const {}& value);",
+            class
+        )?;
+        writeln!(f, "    static {} decode(Decoder& decoder);", class)?;
+        writeln!(f, "}};")?;
+        writeln!(f)?;
+    }
+
+    for (item, config) in items.iter().copied() {
+        write_codec(f, item, config)?;
+    }
+
+    writeln!(f, "}} }}")?;
+
+    Ok(())
+}
+
+fn write_codec(f: &mut dyn Write, item: &ItemEnum, config: &Config) -> Result<()> {
+    let class = config.global_class_ref(&item.ident.to_string());
+    let payloads = payloads(item, config)?;
+
+    writeln!(
+        f,
+        "inline void Codec<{0}>::encode(Encoder& encoder, const {0}& value) {{",
+        class
+    )?;
+    for (index, (variant, payload)) in item.variants.iter().zip(payloads.iter()).enumerate() {
+        let variant_name = attrs::variant_name(variant)?;
+        writeln!(
+            f,
+            "    {}if (value.{}()) {{",
+            if index == 0 { "" } else { "} else " },
+            config.naming.name(NameKind::Is, &variant_name)
+        )?;
+        writeln!(f, "        wire::encode<u32>(encoder, {});", index)?;
+
+        let accessor = format!(
+            "value.{}()",
+            config.naming.name(NameKind::RefUncheck, &variant_name)
+        );
+        for i in 0..payload.len() {
+            let value = if payload.len() == 1 {
+                accessor.clone()
+            } else {
+                format!("std::get<{}>({})", i, accessor)
+            };
+            writeln!(f, "        wire::encode(encoder, {});", value)?;
+        }
+    }
+    writeln!(f, "    }} else {{")?;
+    writeln!(
+        f,
+        "        CPPUST_PANIC(\"cannot encode a valueless {}\");",
+        item.ident
+    )?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(
+        f,
+        "inline {0} Codec<{0}>::decode(Decoder& decoder) {{",
+        class
+    )?;
+    writeln!(f, "    switch (wire::decode<u32>(decoder)) {{")?;
+    for (index, (variant, payload)) in item.variants.iter().zip(payloads.iter()).enumerate() {
+        let variant_name = attrs::variant_name(variant)?;
+        let factory = format!("{}::{}", class, config.naming.factory(&variant_name));
+        if payload.is_empty() {
+            writeln!(f, "    case {}:", index)?;
+            writeln!(f, "        return {}();", factory)?;
+            continue;
+        }
+
+        // decoded in order before being passed
+        writeln!(f, "    case {}: {{", index)?;
+        for (i, ty) in payload.iter().enumerate() {
+            writeln!(
+                f,
+                "        {0} _{1} = wire::decode<{0}>(decoder);",
+                ty.cpp_type(config),
+                i
+            )?;
+        }
+        writeln!(
+            f,
+            "        return {}({});",
+            factory,
+            (0..payload.len())
+                .map(|i| format!("std::move(_{})", i))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        writeln!(f, "    }}")?;
+    }
+    writeln!(f, "    default:")?;
+    writeln!(
+        f,
+        "        {}",
+        config.panic(&format!(
+            "\"invalid variant of {} on the wire\"",
+            item.ident
+        ))
+    )?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    Ok(())
+}
+
+/// Writes `<module>_wire_test.cpp`, which decodes each sample written by `<module>_samples`
+/// from the file given as argument, checks its variant, and that it encodes back to the same
+/// bytes
+pub fn write_cpp_test(
+    f: &mut dyn Write,
+    items: &[&(&ItemEnum, Config)],
+    header: &str,
+) -> Result<()> {
+    writeln!(
+        f,
+        "// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE"
+    )?;
+    writeln!(f)?;
+    writeln!(f, "#include \"{}\"", header)?;
+    writeln!(f)?;
+    writeln!(f, "#include <fstream>")?;
+    writeln!(f, "#include <iostream>")?;
+    writeln!(f, "#include <iterator>")?;
+    writeln!(f, "#include <vector>")?;
+    writeln!(f)?;
+
+    f.write_all(
+        br#"namespace {
+
+template <typename T>
+int check(cppust::wire::Decoder& samples, const char* name, bool (T::*is_variant)() const) {
+    std::vector<cppust::u8> bytes = cppust::wire::decode<std::vector<cppust::u8>>(samples);
+    cppust::wire::Decoder decoder(bytes.data(), bytes.size());
+    T value = cppust::wire::decode<T>(decoder);
+    cppust::wire::Encoder encoder;
+    cppust::wire::encode(encoder, value);
+
+    bool ok = decoder.is_empty() && (value.*is_variant)() && encoder.bytes() == bytes;
+    std::cout<<name<<(ok ? " ok" : " FAILED")<<"\n";
+    return ok ? 0 : 1;
+}
+
+}
+
+int main(int argc, char** argv) {
+    if (argc != 2) {
+        std::cerr<<"usage: "<<argv[0]<<" <samples>\n";
+        return 2;
+    }
+    std::ifstream file(argv[1], std::ios::binary);
+    std::vector<cppust::u8> input(
+        (std::istreambuf_iterator<char>(file)), std::istreambuf_iterator<char>());
+    cppust::wire::Decoder samples(input.data(), input.size());
+
+    int failures = 0;
+"#,
+    )?;
+    for (item, config) in items.iter().copied() {
+        let class = config.global_class_ref(&item.ident.to_string());
+        for variant in item.variants.iter() {
+            writeln!(
+                f,
+                "    failures += check<{0}>(samples, \"{1}::{2}\", &{0}::{3});",
+                class,
+                item.ident,
+                variant.ident,
+                config
+                    .naming
+                    .name(NameKind::Is, &attrs::variant_name(variant)?)
+            )?;
+        }
+    }
+    writeln!(f)?;
+    writeln!(f, "    if (!samples.is_empty()) {{")?;
+    writeln!(f, "        std::cout<<\"more samples than variants\\n\";")?;
+    writeln!(f, "        failures += 1;")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "    return failures == 0 ? 0 : 1;")?;
+    writeln!(f, "}}")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::gen::config::{UserAlias, UserClass};
+
+    fn config() -> Config {
+        let alias: syn::ItemType = syn::parse_str("type Res<T> = Result<T, Frame>;").unwrap();
+        let mut classes = HashMap::new();
+        classes.insert(
+            "Frame".to_owned(),
+            UserClass {
+                namespace: vec!["t".to_owned()],
+                name: "Frame".into(),
+                layout: false,
+                pod: false,
+            },
+        );
+        Config {
+            classes,
+            aliases: std::iter::once((
+                "Res".to_owned(),
+                UserAlias::of(&alias, &[], "a_types.hpp").unwrap(),
+            ))
+            .collect(),
+            ..Config::default()
+        }
+    }
+
+    fn wire_type(ty: &str) -> Option<WireType> {
+        WireType::of_type(&syn::parse_str(ty).unwrap(), &config())
+    }
+
+    #[test]
+    fn test_wire_types() {
+        let frame = || Box::new(WireType::Enum("Frame".to_owned()));
+        assert_eq!(
+            wire_type("Vec<Option<u16>>"),
+            Some(WireType::Vec(Box::new(WireType::Option(Box::new(
+                WireType::Number("u16".to_owned())
+            )))))
+        );
+        assert_eq!(
+            wire_type("Res<String>"),
+            Some(WireType::Result(Box::new(WireType::Str), frame()))
+        );
+        assert_eq!(
+            wire_type("Res<char>").unwrap().cpp_type(&config()),
+            "::cppust::Result<char32_t, ::t::Frame>"
+        );
+        for ty in [
+            "&str",
+            "(u8, u8)",
+            "Box<u8>",
+            "Vec<u8, u8>",
+            "Unknown",
+            "std::string::String",
+        ] {
+            assert_eq!(wire_type(ty), None, "{}", ty);
+        }
+    }
+
+    #[test]
+    fn test_samples() {
+        let item: ItemEnum =
+            syn::parse_str("enum Frame { Nested(Vec<Frame>), Pair(i8, Option<char>) }").unwrap();
+        let config = config();
+        let items = [(&item, config.clone())];
+        let items: Vec<_> = items.iter().collect();
+
+        let sample = |index: usize| {
+            variant_sample(&item.variants[index], &item, &config, &items)
+                .unwrap()
+                .unwrap()
+        };
+        assert_eq!(sample(0), "Frame::Nested(vec![Frame::Nested(Vec::new())])");
+        assert_eq!(sample(1), "Frame::Pair(-7, Some('λ'))");
+    }
+}
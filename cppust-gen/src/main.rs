@@ -1,6 +1,8 @@
-use gen::Generator;
+#![allow(clippy::result_large_err)]
+
+use gen::{names::Naming, Generator};
 use getopts::Options;
-use std::env;
+use std::{env, path::Path};
 
 mod gen;
 
@@ -19,10 +21,81 @@ fn main() {
     opts.optopt(
         "O",
         "source-dir",
-        "set the directory to save source files",
+        "set the directory to save source files, not needed with --header-only",
         "",
     );
+    opts.optopt(
+        "",
+        "baseline-dir",
+        "set the directory keeping the last generated headers to merge user changes with, \
+         SOURCE_DIR/.cppust-gen (default) or ./.cppust-gen with --header-only",
+        "DIR",
+    );
     opts.optopt("n", "namespace", "specify targeting namespace", "");
+    opts.optopt(
+        "",
+        "cpp-std",
+        "set the targeting C++ standard: 11 (default), 14, 17 or 20",
+        "",
+    );
+    opts.optopt(
+        "",
+        "backend",
+        "set how values are stored: union (default) or variant (C++17 and above)",
+        "",
+    );
+    opts.optopt(
+        "",
+        "naming",
+        "set the naming profile: default, google, std, llvm, or a config file of key = pattern lines",
+        "",
+    );
+    opts.optflag(
+        "",
+        "no-exceptions",
+        "call CPPUST_PANIC(msg) instead of throwing in checked accessors",
+    );
+    opts.optflag(
+        "",
+        "no-iostream",
+        "do not include <iostream> in generated headers",
+    );
+    opts.optflag(
+        "",
+        "header-only",
+        "define all members inline in headers instead of generating source files",
+    );
+    opts.optflag(
+        "",
+        "user-regions",
+        "generate whole headers, keeping the code between BEGIN/END USER CODE markers",
+    );
+    opts.optopt(
+        "",
+        "amalgamate",
+        "generate all types into a single NAME.hpp, and NAME.cpp unless --header-only",
+        "NAME",
+    );
+    opts.optopt(
+        "",
+        "ffi",
+        "generate #[repr(C)] Rust mirrors into DIR/<input>_ffi.rs, with C++ conversions",
+        "DIR",
+    );
+    opts.optopt(
+        "",
+        "rust",
+        "generate Rust counterparts with their wire encoding into DIR/<input>.rs, with a sample \
+         writer and a C++ round-trip test",
+        "DIR",
+    );
+    opts.optopt(
+        "",
+        "emit-tests",
+        "generate a C++ test per type into DIR/<file>.test.cpp, checking accessors, copies, \
+         moves and assignments between every pair of variants",
+        "DIR",
+    );
     opts.optflag("h", "help", "print this message");
 
     let matches = match opts.parse(&args[1..]) {
@@ -40,22 +113,31 @@ fn main() {
     let input = matches.opt_str("i");
     let header_dir = matches.opt_str("I");
     let source_dir = matches.opt_str("O");
+    let baseline_dir = matches.opt_str("baseline-dir");
     let namespace = matches.opt_str("n");
-
-    if !input.is_some() {
+    let cpp_std = matches.opt_str("cpp-std");
+    let backend = matches.opt_str("backend");
+    let naming = matches.opt_str("naming");
+    let amalgamation = matches.opt_str("amalgamate");
+    let ffi_dir = matches.opt_str("ffi");
+    let rust_dir = matches.opt_str("rust");
+    let tests_dir = matches.opt_str("emit-tests");
+
+    if input.is_none() {
         panic!("no input file");
     }
 
-    if !header_dir.is_some() {
+    if header_dir.is_none() {
         panic!("no header dir");
     }
 
-    if !source_dir.is_some() {
+    let header_only = matches.opt_present("header-only");
+    if source_dir.is_none() && !header_only {
         panic!("no source dir");
     }
 
     let code = {
-        let file = std::fs::read_to_string(input.unwrap());
+        let file = std::fs::read_to_string(input.as_ref().unwrap());
 
         if let Err(err) = &file {
             panic!("failed to open input file: {}", err);
@@ -64,14 +146,87 @@ fn main() {
         file.unwrap()
     };
 
-    let mut builder = Generator::builder_with(&code)
-        .save_headers_at(&header_dir.unwrap())
-        .save_sources_at(&source_dir.unwrap());
+    let mut builder = Generator::builder_with(&code).save_headers_at(&header_dir.unwrap());
+
+    // constants of `nmea.rs` are generated into `nmea_consts.hpp`
+    let module = Path::new(input.as_ref().unwrap()).file_stem();
+    if let Some(module) = module.and_then(|stem| stem.to_str()) {
+        builder = builder.with_module(module);
+    }
+
+    if let Some(source_dir) = source_dir {
+        builder = builder.save_sources_at(&source_dir);
+    }
+
+    if let Some(baseline_dir) = baseline_dir {
+        builder = builder.with_baseline_dir(&baseline_dir);
+    }
+
+    if header_only {
+        builder = builder.with_header_only();
+    }
+
+    if matches.opt_present("user-regions") {
+        builder = builder.with_user_regions();
+    }
+
+    if let Some(amalgamation) = amalgamation {
+        builder = builder.with_amalgamation(&amalgamation);
+    }
+
+    if let Some(ffi_dir) = ffi_dir {
+        builder = builder.with_ffi(&ffi_dir);
+    }
+
+    if let Some(rust_dir) = rust_dir {
+        builder = builder.with_rust(&rust_dir);
+    }
+
+    if let Some(tests_dir) = tests_dir {
+        builder = builder.with_tests(&tests_dir);
+    }
 
     if let Some(namespace) = namespace {
         builder = builder.with_namespace(&namespace);
     }
 
+    if let Some(cpp_std) = cpp_std {
+        match cpp_std.parse() {
+            Ok(cpp_std) => builder = builder.with_cpp_std(cpp_std),
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    if let Some(backend) = backend {
+        match backend.parse() {
+            Ok(backend) => builder = builder.with_backend(backend),
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    if let Some(naming) = naming {
+        let naming = if Naming::PROFILES.contains(&naming.as_str()) {
+            Naming::profile(&naming)
+        } else {
+            match std::fs::read_to_string(&naming) {
+                Ok(config) => Naming::from_config(&config),
+                Err(err) => panic!("failed to open naming config {}: {}", naming, err),
+            }
+        };
+        match naming {
+            Ok(naming) => builder = builder.with_naming(naming),
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    if matches.opt_present("no-exceptions") {
+        builder = builder.without_exceptions();
+    }
+
+    if matches.opt_present("no-iostream") {
+        builder = builder.without_iostream();
+    }
+
     let generator = builder.build();
 
     if let Err(err) = &generator {
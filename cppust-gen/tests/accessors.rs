@@ -0,0 +1,71 @@
+//! Compile test: the Rust-like `as_xxx`, `expect_xxx`, `into_xxx` and `take_xxx` accessors.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug, PartialEq)]
+enum Message {
+    Quit,
+    Write(Vec<u8>),
+    Move(i32, i32),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/message.hpp"
+#include "check.hpp"
+
+#include <stdexcept>
+#include <string>
+
+int main() {
+    auto write = t::Message::Write(std::vector<cppust::u8>{1, 2});
+    const auto& const_write = write;
+
+    // as
+    CHECK(const_write.as_write().is_some());
+    CHECK(const_write.as_move().is_none());
+    write.as_write().unwrap_ref().get().push_back(3);
+    CHECK(const_write.as_write().unwrap_ref().get() == std::vector<cppust::u8>({1, 2, 3}));
+
+    // expect
+    CHECK(const_write.expect_write("is write").size() == 3);
+    write.expect_write("is write").pop_back();
+    try {
+        write.expect_move("is move");
+        CHECK(false);
+    } catch (const std::runtime_error& e) {
+        CHECK(std::string(e.what()) == "is move");
+    }
+
+    // into moves the payload out
+    auto copy = write;
+    const cppust::u8* data = write.write_ref().data();
+    auto bytes = std::move(write).into_write();
+    CHECK(bytes.unwrap_ref().data() == data);
+    CHECK(std::move(copy).into_move().is_none());
+
+    auto moved = t::Message::Move(3, 4).into_move();
+    CHECK(moved.unwrap() == std::make_tuple(3, 4));
+    CHECK(std::get<1>(t::Message::Move(5, 6).expect_move("is move")) == 6);
+    CHECK(t::Message::Quit().into_write().is_none());
+
+    // take moves the payload out of an lvalue, which keeps its variant
+    auto taken = t::Message::Write(std::vector<cppust::u8>{4});
+    data = taken.write_ref().data();
+    CHECK(taken.take_write().unwrap_ref().data() == data);
+    CHECK(taken.is_write());
+    CHECK(taken.take_move().is_none());
+
+    std::cout<<"ok\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_accessors() {
+    for (backend, cfg) in [("union", Cfg::UNION), ("variant", Cfg::VARIANT)] {
+        common::compile_and_run(&format!("accessors-{}", backend), INPUT, MAIN, &cfg, "ok\n");
+    }
+}
@@ -0,0 +1,121 @@
+//! Compile test: `type` aliases become `using` declarations and alias templates, in
+//! `<module>_types.hpp` or in the amalgamated header, and payloads of aliased types are destroyed
+//! as the types they stand for.
+
+mod common;
+
+use std::fs;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+/// Raw bytes of a sentence
+type Payload = Vec<u16>;
+type Res<T> = Result<T, Fault>;
+type Batch = Vec<Reading>;
+type Id = u16;
+
+const FIRST: Id = 7;
+
+#[derive(Clone)]
+enum Command {
+    Send(Payload),
+    Report(Batch),
+    Poll(Res<Id>),
+}
+
+#[derive(Clone, Debug)]
+enum Reading {
+    Temp(i32),
+    Empty,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Fault {
+    Timeout,
+    Code(Id),
+}
+"#;
+
+const MAIN: &str = r#"
+int main() {
+    t::Command poll = t::Command::Poll(t::Res<t::Id>::Ok(t::FIRST));
+    t::Command report = t::Command::Report(t::Batch{t::Reading::Temp(3), t::Reading::Empty()});
+    t::Command send = t::Command::Send(t::Payload{1, 2});
+    poll = report;
+    std::cout<<cppust::as_debug(poll.report_ref())<<" "<<send.send_ref().size()<<" "
+        <<t::Command::Poll(t::Res<t::Id>::Err(t::Fault::Timeout())).poll_ref().is_err()<<"\n";
+    return 0;
+}
+"#;
+
+const EXPECTED: &str = "<Temp(3),Empty> 2 1\n";
+
+#[test]
+fn test_aliases() {
+    let ws = Workspace::new("aliases");
+    let input = ws.write("nmea.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+
+    let aliases = fs::read_to_string(ws.path("include/t/nmea_types.hpp")).unwrap();
+    assert!(aliases.contains(
+        "/// @brief Raw bytes of a sentence\nusing Payload = std::vector<::cppust::u16>;\n"
+    ));
+    assert!(aliases.contains("template <typename T>\nusing Res = ::cppust::Result<T, Fault>;\n"));
+    assert!(aliases.contains("namespace t { class Reading; }\n"));
+
+    // the payloads are complete through the aliases
+    let command = fs::read_to_string(ws.path("include/t/command.hpp")).unwrap();
+    assert!(command.contains(
+        "#include \"t/nmea_types.hpp\"\n#include \"t/fault.hpp\"\n#include \"t/reading.hpp\"\n"
+    ));
+    let source = fs::read_to_string(ws.path("src/command.gen.cpp")).unwrap();
+    assert!(source.contains("union_.send_val.~vector();"));
+    assert!(source.contains("union_.poll_val.~Result();"));
+
+    let main = format!(
+        "#include \"t/nmea_consts.hpp\"\n#include \"t/command.hpp\"\n{}",
+        MAIN
+    );
+    ws.compile_and_run(&main, &Cfg::UNION, EXPECTED);
+}
+
+#[test]
+fn test_amalgamated_aliases() {
+    let ws = Workspace::new("aliases-amalgamated");
+    let input = ws.write("nmea.rs", INPUT);
+    ws.generate_header_only(&input, "t", &["--amalgamate", "nmea"]);
+
+    assert!(!ws.path("include/t/nmea_types.hpp").exists());
+
+    // stored by value through the aliases, so defined before `Command`
+    let header = fs::read_to_string(ws.path("include/t/nmea.hpp")).unwrap();
+    assert!(header.find("class Fault {").unwrap() < header.find("class Command {").unwrap());
+    assert!(header.find("class Reading {").unwrap() < header.find("class Command {").unwrap());
+
+    let main = format!("#include \"t/nmea.hpp\"\n{}", MAIN);
+    ws.compile_and_run(&main, &Cfg::UNION, EXPECTED);
+}
+
+const ALIASES_ONLY: &str = r#"
+type Payload = Vec<u16>;
+type Maybe<T> = Option<T>;
+"#;
+
+const ALIASES_ONLY_MAIN: &str = r#"#include "t/nmea_types.hpp"
+
+int main() {
+    t::Maybe<t::Payload> payload = t::Maybe<t::Payload>::Some(t::Payload{1, 2});
+    std::cout<<payload.unwrap().size()<<"\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_aliases_without_enums() {
+    let ws = Workspace::new("aliases-without-enums");
+    let input = ws.write("nmea.rs", ALIASES_ONLY);
+    ws.generate(&input, "t", &[]);
+
+    ws.compile_and_run(ALIASES_ONLY_MAIN, &Cfg::UNION, "2\n");
+}
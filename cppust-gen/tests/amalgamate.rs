@@ -0,0 +1,97 @@
+//! Compile test: `--amalgamate` puts all types into one header and one source, ordered so that
+//! payloads are defined before the types storing them.
+
+mod common;
+
+use std::fs;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug, PartialEq)]
+enum Command {
+    Stop,
+    Report(Reading),
+    Batch(Vec<Reading>),
+}
+
+/// A sensor reading
+#[derive(Clone, Debug, PartialEq)]
+#[cppust(header_only)]
+enum Reading {
+    Temperature(i32),
+    Humidity(u16),
+}
+
+#[derive(Debug)]
+enum Status {
+    Idle,
+    Last(Command),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/schema.hpp"
+
+std::size_t batch_size();
+
+int main() {
+    auto status = t::Status::Last(t::Command::Report(t::Reading::Humidity(5)));
+    std::cout<<cppust::as_debug(status)<<" "<<batch_size()<<"\n";
+    return 0;
+}
+"#;
+
+const OTHER: &str = r#"#include "t/schema.hpp"
+
+std::size_t batch_size() {
+    auto batch = t::Command::Batch(std::vector<t::Reading>{t::Reading::Temperature(3)});
+    return batch.batch_ref().size();
+}
+"#;
+
+#[test]
+fn test_amalgamate() {
+    for (backend, header_only, cfg) in [
+        ("union", false, Cfg::UNION),
+        ("variant", true, Cfg::VARIANT),
+    ] {
+        let ws = Workspace::new(&format!("amalgamate-{}", backend));
+        let input = ws.write("schema.rs", INPUT);
+        let mut args = cfg.args.to_vec();
+        args.extend(["--amalgamate", "schema"]);
+        if header_only {
+            ws.generate_header_only(&input, "t", &args);
+        } else {
+            ws.generate(&input, "t", &args);
+        }
+
+        let files: Vec<_> = fs::read_dir(ws.path("include/t"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(files, ["schema.hpp"], "{}", backend);
+        assert_eq!(
+            ws.path("src/schema.cpp").exists(),
+            !header_only,
+            "{}",
+            backend
+        );
+
+        let header = fs::read_to_string(ws.path("include/t/schema.hpp")).unwrap();
+        assert_eq!(header.matches("namespace t {").count(), 1, "{}", backend);
+        assert!(header.contains("class Reading;\nclass Command;\nclass Status;\n"));
+        let reading = header.find("class Reading {").unwrap();
+        let command = header.find("class Command {").unwrap();
+        let status = header.find("class Status {").unwrap();
+        assert!(reading < command && command < status, "{}", backend);
+        assert!(header.contains("/// @brief A sensor reading\nclass Reading {"));
+        // the `header_only` attribute still defines the members of Reading inline
+        assert!(header.contains("inline bool Reading::operator==("));
+
+        let cfg = Cfg {
+            files: &[("other.cpp", OTHER)],
+            ..cfg
+        };
+        ws.compile_and_run(MAIN, &cfg, "Last(Report(Humidity(5))) 1\n");
+    }
+}
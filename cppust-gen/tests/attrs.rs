@@ -0,0 +1,79 @@
+//! Compile test: `#[cppust(...)]` attributes skip, rename, move and inline the generated code.
+
+mod common;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+#[cppust(skip)]
+enum Internal {
+    A,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cppust(rename = "Reading", namespace = "t::sensors")]
+enum SensorReading {
+    #[cppust(rename = "Temp")]
+    Temperature(i16),
+    Off,
+}
+
+#[derive(Clone, PartialEq)]
+#[cppust(header_only)]
+enum Command {
+    Stop,
+    Move(i32),
+    Range(#[cppust(type = "std::pair<::cppust::u8, ::cppust::u8>")] (u8, u8)),
+    Sense(SensorReading),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/sensors/reading.hpp"
+#include "t/command.hpp"
+
+int other();
+
+int main() {
+    auto reading = t::sensors::Reading::Temp(3);
+    if (!reading.is_temp() || reading.temp_ref() != 3) return 1;
+
+    auto range = t::Command::Range(std::make_pair(cppust::u8(1), cppust::u8(2)));
+    if (range.range_ref().second != 2 || !(range == range)) return 1;
+
+    auto sense = t::Command::Sense(reading);
+    if (!sense.sense_ref().is_temp()) return 1;
+
+    std::cout<<cppust::as_debug(reading)<<" "<<other()<<"\n";
+    return 0;
+}
+"#;
+
+// a second translation unit, the inline definitions must not clash at link time
+const OTHER: &str = r#"#include "t/command.hpp"
+
+int other() {
+    return t::Command::Move(5).move_ref();
+}
+"#;
+
+#[test]
+fn test_cppust_attrs() {
+    let ws = Workspace::new("attrs");
+    let input = ws.write("attrs.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+
+    assert!(!ws.path("include/t/internal.hpp").exists());
+    assert!(ws.path("include/t/sensors/reading.hpp").exists());
+    assert!(ws.path("src/sensors/reading.gen.cpp").exists());
+    assert!(ws.path("include/t/command.impl.hpp").exists());
+    assert!(!ws.path("src/command.gen.cpp").exists());
+    assert!(std::fs::read_to_string(ws.path("include/t/command.hpp"))
+        .unwrap()
+        .contains("#include \"t/sensors/reading.hpp\"\n"));
+
+    let cfg = Cfg {
+        files: &[("other.cpp", OTHER)],
+        ..Cfg::UNION
+    };
+    ws.compile_and_run(MAIN, &cfg, "Temperature(3) 5\n");
+}
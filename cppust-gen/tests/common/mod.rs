@@ -0,0 +1,331 @@
+#![allow(dead_code)]
+
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The C++ compiler used by compile tests, which fail when there is none unless
+/// `CPPUST_SKIP_CXX=1` explicitly skips them
+pub fn cxx() -> Option<String> {
+    if std::env::var("CPPUST_SKIP_CXX").is_ok_and(|skip| skip == "1") {
+        eprintln!("CPPUST_SKIP_CXX is set, skipping");
+        return None;
+    }
+
+    let cxx = std::env::var("CXX").unwrap_or_else(|_| "c++".to_owned());
+    match Command::new(&cxx).arg("--version").output() {
+        Ok(output) if output.status.success() => Some(cxx),
+        _ => panic!(
+            "no C++ compiler `{}` found, set CXX to one or CPPUST_SKIP_CXX=1 to skip compile tests",
+            cxx
+        ),
+    }
+}
+
+/// The Rust compiler used by FFI tests, tests are skipped when there is none
+pub fn rustc() -> Option<String> {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+
+    match Command::new(&rustc).arg("--version").output() {
+        Ok(output) if output.status.success() => Some(rustc),
+        _ => {
+            eprintln!("no Rust compiler found, skipping");
+            None
+        }
+    }
+}
+
+pub fn cppust_include_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../cppust/include")
+}
+
+/// `CHECK(cond)` for test programs, returning 1 from `main` when `cond` does not hold
+const CHECK_HPP: &str = r#"#pragma once
+
+#include <cstdio>
+
+#define CHECK(cond) do { if (!(cond)) { fprintf(stderr, "check failed: %s\n", #cond); return 1; } } while (0)
+"#;
+
+/// How a compile test generates its code and builds its C++ program
+#[derive(Clone, Copy)]
+pub struct Cfg<'a> {
+    /// Arguments of cppust-gen besides the input, the output directories and the namespace
+    pub args: &'a [&'a str],
+    pub header_only: bool,
+    pub std: &'a str,
+    pub cxx_args: &'a [&'a str],
+    /// Files written into the workspace first, `.cpp` ones are compiled along with the program
+    pub files: &'a [(&'a str, &'a str)],
+}
+
+impl Cfg<'static> {
+    /// The union backend under C++11
+    pub const UNION: Self = Cfg {
+        args: &[],
+        header_only: false,
+        std: "c++11",
+        cxx_args: &[],
+        files: &[],
+    };
+
+    /// The `std::variant` backend under C++17
+    pub const VARIANT: Self = Cfg {
+        args: &["--backend", "variant", "--cpp-std", "17"],
+        std: "c++17",
+        ..Self::UNION
+    };
+}
+
+/// Generates `input` into namespace `t` of a fresh workspace named `name`, then builds the
+/// generated sources with the C++ program `main` and checks it prints `expected`
+pub fn compile_and_run(name: &str, input: &str, main: &str, cfg: &Cfg, expected: &str) {
+    let ws = Workspace::new(name);
+    ws.write_files(cfg);
+    let input = ws.write("input.rs", input);
+    if cfg.header_only {
+        ws.generate_header_only(&input, "t", cfg.args);
+    } else {
+        ws.generate(&input, "t", cfg.args);
+    }
+
+    ws.compile_and_run(main, cfg, expected);
+}
+
+/// A scratch directory holding the input, generated code and binaries of one test
+pub struct Workspace {
+    dir: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join("cppust-gen-tests").join(format!(
+            "{}-{}",
+            name,
+            std::process::id()
+        ));
+
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+
+        Self { dir }
+    }
+
+    pub fn path(&self, rel: &str) -> PathBuf {
+        self.dir.join(rel)
+    }
+
+    pub fn write(&self, rel: &str, content: &str) -> PathBuf {
+        let path = self.path(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn write_files(&self, cfg: &Cfg) -> Vec<PathBuf> {
+        cfg.files
+            .iter()
+            .map(|(rel, content)| self.write(rel, content))
+            .collect()
+    }
+
+    /// Runs cppust-gen on `input`, saving into `include/` and `src/`
+    pub fn generate(&self, input: &Path, namespace: &str, extra_args: &[&str]) {
+        let source_dir = self.path("src");
+        let mut args = vec![OsStr::new("-O"), source_dir.as_os_str()];
+        args.extend(extra_args.iter().map(OsStr::new));
+        self.run_generator(input, namespace, &args);
+    }
+
+    /// Runs cppust-gen on `input` in header-only mode, saving into `include/` only
+    pub fn generate_header_only(&self, input: &Path, namespace: &str, extra_args: &[&str]) {
+        let mut args = vec![OsStr::new("--header-only")];
+        args.extend(extra_args.iter().map(OsStr::new));
+        self.run_generator(input, namespace, &args);
+    }
+
+    fn run_generator(&self, input: &Path, namespace: &str, args: &[&OsStr]) {
+        let output = Command::new(env!("CARGO_BIN_EXE_cppust-gen"))
+            .current_dir(&self.dir)
+            .arg("-i")
+            .arg(input)
+            .arg("-I")
+            .arg(self.path("include"))
+            .arg("-n")
+            .arg(namespace)
+            .args(args)
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success(),
+            "cppust-gen failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// All generated sources, header-only output has none
+    pub fn generated_sources(&self) -> Vec<PathBuf> {
+        let mut sources = Vec::new();
+        let mut dirs: Vec<_> = Some(self.path("src"))
+            .filter(|src| src.exists())
+            .into_iter()
+            .collect();
+
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path.extension() == Some(OsStr::new("cpp")) {
+                    sources.push(path);
+                }
+            }
+        }
+
+        sources.sort();
+        sources
+    }
+
+    /// Compiles `sources` against the generated headers and cppust, returning the executable
+    pub fn compile(
+        &self,
+        cxx: &str,
+        std: &str,
+        sources: &[PathBuf],
+        extra_args: &[&str],
+    ) -> PathBuf {
+        let exe = self.path(&format!("test-{}", std));
+
+        let output = Command::new(cxx)
+            .current_dir(&self.dir)
+            .arg(format!("-std={}", std))
+            .args(["-Wall", "-Werror"])
+            .arg("-I")
+            .arg(self.path("include"))
+            .arg("-I")
+            .arg(cppust_include_dir())
+            .args(sources)
+            .args(extra_args)
+            .arg("-o")
+            .arg(&exe)
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success(),
+            "{} -std={} failed: {}",
+            cxx,
+            std,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        exe
+    }
+
+    /// Builds the generated sources with the C++ program `main`, which can include `check.hpp`,
+    /// and checks it prints `expected`
+    pub fn compile_and_run(&self, main: &str, cfg: &Cfg, expected: &str) {
+        let cxx = match cxx() {
+            Some(cxx) => cxx,
+            None => return,
+        };
+
+        self.write("include/check.hpp", CHECK_HPP);
+        let mut sources = self.generated_sources();
+        sources.extend(
+            self.write_files(cfg)
+                .into_iter()
+                .filter(|path| path.extension() == Some(OsStr::new("cpp"))),
+        );
+        sources.push(self.write("main.cpp", main));
+
+        let exe = self.compile(&cxx, cfg.std, &sources, cfg.cxx_args);
+        assert_eq!(
+            self.run(&exe),
+            expected,
+            "{} -std={}",
+            self.dir.file_name().unwrap().to_string_lossy(),
+            cfg.std
+        );
+    }
+
+    /// Compiles the Rust crate rooted at `root` into a static library, returning its path
+    pub fn compile_staticlib(&self, rustc: &str, root: &Path) -> PathBuf {
+        let lib = self.path("libtest.a");
+
+        let output = Command::new(rustc)
+            .args([
+                "--edition",
+                "2021",
+                "--crate-type",
+                "staticlib",
+                "-D",
+                "warnings",
+            ])
+            .arg(root)
+            .arg("-o")
+            .arg(&lib)
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success(),
+            "{} failed: {}",
+            rustc,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        lib
+    }
+
+    pub fn run(&self, exe: &Path) -> String {
+        let output = Command::new(exe).output().unwrap();
+
+        assert!(
+            output.status.success(),
+            "{} failed: {}",
+            exe.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        String::from_utf8(output.stdout).unwrap()
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+}
+
+/// A tiny xorshift generator, so property tests are reproducible without extra dependencies
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
@@ -0,0 +1,82 @@
+//! Compile test: `const` and `static` items become `constexpr` values with the same values as
+//! in Rust, in `<module>_consts.hpp` or in the amalgamated header.
+
+mod common;
+
+use std::fs;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+/// Longest sentence, including `$` and the line ending
+pub const MAX_SENTENCE_LEN: usize = BODY_LEN + 2;
+const BODY_LEN: usize = 80;
+static TALKER: &str = "GP";
+const SEPARATOR: char = ',';
+const NARROW_MASK: u8 = !0u8 >> 4;
+const FLAGS: u32 = 1 << 3 | 1 << 1 & 0xff;
+const LIMIT: i32 = i32::MIN / 2 - -1;
+const LONG: bool = !(MAX_SENTENCE_LEN < 64) && BODY_LEN as u8 == 80;
+const RATIO: f64 = 1.0 / 4.0;
+// unsuffixed literals have the type of the constant, not `int`
+const BIG: u64 = 1 << 40;
+const PRODUCT: u64 = 100000 * 100000;
+const LOW_NIBBLE: u8 = !0 >> 4;
+
+#[derive(Debug)]
+enum Status {
+    Idle,
+    Busy(u8),
+}
+"#;
+
+const ASSERTS: &str = r#"
+static_assert(t::MAX_SENTENCE_LEN == 82, "MAX_SENTENCE_LEN");
+static_assert(t::SEPARATOR == U',', "SEPARATOR");
+static_assert(t::NARROW_MASK == 0x0f, "NARROW_MASK");
+static_assert(t::FLAGS == 10, "FLAGS");
+static_assert(t::LIMIT == -1073741823, "LIMIT");
+static_assert(t::LONG, "LONG");
+static_assert(t::RATIO == 0.25, "RATIO");
+static_assert(t::BIG == 1099511627776ULL, "BIG");
+static_assert(t::PRODUCT == 10000000000ULL, "PRODUCT");
+static_assert(t::LOW_NIBBLE == 0x0f, "LOW_NIBBLE");
+
+int main() {
+    std::cout<<t::TALKER<<" "<<cppust::as_debug(t::Status::Idle())<<"\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_consts() {
+    let ws = Workspace::new("consts");
+    let input = ws.write("nmea.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+
+    let consts = fs::read_to_string(ws.path("include/t/nmea_consts.hpp")).unwrap();
+    assert!(consts.contains(
+        "/// @brief Longest sentence, including `$` and the line ending\n\
+         constexpr ::cppust::usize MAX_SENTENCE_LEN = BODY_LEN + ::cppust::usize(2);\n"
+    ));
+    // constants are defined after those they refer to
+    assert!(consts.find("BODY_LEN =").unwrap() < consts.find("MAX_SENTENCE_LEN =").unwrap());
+
+    let main = format!(
+        "#include \"t/nmea_consts.hpp\"\n#include \"t/status.hpp\"\n{}",
+        ASSERTS
+    );
+    ws.compile_and_run(&main, &Cfg::UNION, "GP Idle\n");
+}
+
+#[test]
+fn test_amalgamated_consts() {
+    let ws = Workspace::new("consts-amalgamated");
+    let input = ws.write("nmea.rs", INPUT);
+    ws.generate_header_only(&input, "t", &["--amalgamate", "nmea"]);
+
+    assert!(!ws.path("include/t/nmea_consts.hpp").exists());
+
+    let main = format!("#include \"t/nmea.hpp\"\n{}", ASSERTS);
+    ws.compile_and_run(&main, &Cfg::UNION, "GP Idle\n");
+}
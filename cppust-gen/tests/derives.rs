@@ -0,0 +1,78 @@
+//! Compile test: members only exist with the `#[derive(...)]` they come from, so a type
+//! without `Clone` cannot be copied and one without `PartialEq` cannot be compared.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+#[derive(Debug)]
+enum Bare {
+    Empty,
+    Bytes(Vec<u8>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+enum Full {
+    #[default]
+    Empty,
+    Bytes(Vec<u8>),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/bare.hpp"
+#include "t/full.hpp"
+
+#include <type_traits>
+#include <utility>
+
+template <typename T, typename = void>
+struct has_eq: std::false_type {};
+template <typename T>
+struct has_eq<T, decltype(void(std::declval<const T&>() == std::declval<const T&>()))>: std::true_type {};
+
+template <typename T, typename = void>
+struct has_ne: std::false_type {};
+template <typename T>
+struct has_ne<T, decltype(void(std::declval<const T&>() != std::declval<const T&>()))>: std::true_type {};
+
+template <typename T, typename = void>
+struct has_lt: std::false_type {};
+template <typename T>
+struct has_lt<T, decltype(void(std::declval<const T&>() < std::declval<const T&>()))>: std::true_type {};
+
+// Clone
+static_assert(!std::is_copy_constructible<t::Bare>::value, "Bare is not Clone");
+static_assert(!std::is_copy_assignable<t::Bare>::value, "Bare is not Clone");
+static_assert(std::is_move_constructible<t::Bare>::value, "moves need no derive");
+static_assert(std::is_copy_constructible<t::Full>::value, "Full is Clone");
+static_assert(std::is_copy_assignable<t::Full>::value, "Full is Clone");
+
+// PartialEq and PartialOrd
+static_assert(!has_eq<t::Bare>::value && !has_ne<t::Bare>::value, "Bare is not PartialEq");
+static_assert(!has_lt<t::Bare>::value, "Bare is not PartialOrd");
+static_assert(has_eq<t::Full>::value && has_ne<t::Full>::value, "Full is PartialEq");
+static_assert(has_lt<t::Full>::value, "Full is PartialOrd");
+
+// Default
+static_assert(!std::is_default_constructible<t::Bare>::value, "Bare is not Default");
+static_assert(std::is_default_constructible<t::Full>::value, "Full is Default");
+
+int main() {
+    std::cout<<cppust::as_debug(t::Bare::Empty())<<" "<<cppust::as_debug(t::Full())<<"\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_derive_gating() {
+    for (backend, cfg) in [("union", Cfg::UNION), ("variant", Cfg::VARIANT)] {
+        common::compile_and_run(
+            &format!("derives-{}", backend),
+            INPUT,
+            MAIN,
+            &cfg,
+            "Empty Empty\n",
+        );
+    }
+}
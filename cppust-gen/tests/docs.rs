@@ -0,0 +1,78 @@
+//! Generation test: Rust doc comments end up as Doxygen comments in the generated headers,
+//! which still compile.
+
+mod common;
+
+use std::fs;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+/// State of the decoder, see [`Self::Bytes`].
+///
+/// # Examples
+///
+/// ```
+/// # use status::DecoderStatus;
+/// let status = DecoderStatus::Idle;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+enum DecoderStatus {
+    /// Waiting for input
+    Idle,
+    /// Bytes read since [`Idle`],
+    /// in `read()` order
+    Bytes(Vec<u8>),
+    Pair(u8, i32),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/decoder_status.hpp"
+
+int main() {
+    std::cout<<cppust::as_debug(t::DecoderStatus::Bytes(std::vector<cppust::u8>{1}))<<"\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_doc_comments() {
+    let ws = Workspace::new("docs");
+    let input = ws.write("docs.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+
+    let header = fs::read_to_string(ws.path("include/t/decoder_status.hpp")).unwrap();
+    assert!(header.contains(
+        "/// @brief State of the decoder, see @ref DecoderStatus::Bytes.\n\
+         ///\n\
+         /// @par Examples\n\
+         ///\n\
+         /// ```\n\
+         /// let status = DecoderStatus::Idle;\n\
+         /// ```\n\
+         class DecoderStatus {"
+    ));
+
+    let inc = fs::read_to_string(ws.path("include/t/decoder_status.inc.hpp")).unwrap();
+    assert!(inc.contains("        /// Waiting for input\n        Idle,\n"));
+    assert!(inc.contains(
+        "    /// @brief Bytes read since @ref DecoderStatus::Idle,\n\
+         \x20   /// in `read()` order\n\
+         \x20   static DecoderStatus Bytes(const std::vector<::cppust::u8>&);\n"
+    ));
+    assert!(inc.contains(
+        "    /// @brief Whether the value is `Bytes`\n\
+         \x20   /// @details Bytes read since @ref DecoderStatus::Idle,\n\
+         \x20   /// in `read()` order\n\
+         \x20   bool is_bytes() const;\n"
+    ));
+    assert!(
+        inc.contains("    /// @brief Payload of `Bytes`, panics unless it is the active variant\n")
+    );
+
+    // undocumented variants get no comments
+    assert!(inc.contains("\n\n    bool is_pair() const;\n"));
+    assert!(!inc.contains("`Pair`"));
+
+    ws.compile_and_run(MAIN, &Cfg::UNION, "Bytes(<01>)\n");
+}
@@ -0,0 +1,90 @@
+//! Compile test: `--no-exceptions --no-iostream` code builds with `-fno-exceptions`,
+//! reports failures through the panic handler and formats through `cppust::Writer`; headers
+//! without `Vec` payloads include neither `<vector>` nor POSIX headers.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug, PartialEq, PartialOrd, Hash)]
+enum Command {
+    Stop,
+    Move(i32, i16),
+    Speed(Option<u16>),
+}
+
+#[derive(Clone, Debug)]
+enum Frame {
+    Empty,
+    Data(Vec<u8>),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/command.hpp"
+
+#if defined(_GLIBCXX_IOSTREAM) || defined(_GLIBCXX_OSTREAM) || defined(_LIBCPP_IOSTREAM) || defined(_LIBCPP_OSTREAM)
+#error "generated headers must not include iostream"
+#endif
+#if defined(_GLIBCXX_VECTOR) || defined(_LIBCPP_VECTOR)
+#error "headers without Vec payloads must not include vector"
+#endif
+#if defined(_UNISTD_H)
+#error "generated headers must not include unistd.h"
+#endif
+
+#include "t/frame.hpp"
+
+#include <cstdio>
+#include <cstdlib>
+#include <cstring>
+
+void on_panic(const char* msg) {
+    printf("panic: %s\n", msg);
+    exit(0);
+}
+
+template <typename T>
+void print_debug(const T& value) {
+    char buf[64];
+    cppust::SliceWriter writer(buf, sizeof(buf));
+    writer<<cppust::as_debug(value);
+    printf("%s\n", writer.c_str());
+}
+
+int main() {
+    print_debug(t::Command::Stop());
+    print_debug(t::Command::Move(-12, 3));
+    print_debug(t::Command::Speed(cppust::Option<cppust::u16>::Some(250)));
+    print_debug(t::Command::Speed(cppust::Option<cppust::u16>::None()));
+    print_debug(t::Frame::Data(std::vector<cppust::u8>{0x01, 0xab, 0x10}));
+
+    char small[8];
+    cppust::SliceWriter truncated(small, sizeof(small));
+    truncated<<cppust::as_debug(t::Command::Move(123456, 7));
+    printf("%s\n", truncated.c_str());
+
+    t::Command::Stop().expect_move("expected a move");
+    printf("unreachable\n");
+    return 1;
+}
+"#;
+
+const EXPECTED: &str = "Stop
+Move((-12,3))
+Speed(Some(250))
+Speed(None)
+Data(<01 ab 10>)
+Move((1
+panic: expected a move
+";
+
+#[test]
+fn test_no_exceptions_no_iostream() {
+    let cfg = Cfg {
+        args: &["--no-exceptions", "--no-iostream"],
+        cxx_args: &["-fno-exceptions", "-DCPPUST_PANIC_HANDLER=on_panic"],
+        ..Cfg::UNION
+    };
+    common::compile_and_run("embedded", INPUT, MAIN, &cfg, EXPECTED);
+}
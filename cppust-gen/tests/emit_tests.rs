@@ -0,0 +1,218 @@
+//! Runs the C++ tests generated with `--emit-tests` under AddressSanitizer, which checks the
+//! copies, moves and assignments of the generated classes between every pair of variants.
+
+mod common;
+
+use std::{fs, process::Command};
+
+use common::Workspace;
+
+const INPUT: &str = r#"
+type Res<T> = Result<T, Fault>;
+
+#[derive(Clone, Debug, PartialEq)]
+enum DecoderStatus {
+    Idle,
+    Done(Frame),
+    Failed(String),
+    Skipped(u16, char),
+    Batch(Vec<Frame>),
+    Poll(Res<Option<i64>>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cppust(namespace = "t::frames", rename = "Sentence")]
+enum Frame {
+    Nested(Vec<Frame>),
+    Gga(f64),
+    #[cppust(rename = "Flagged")]
+    Flag(bool, usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Fault {
+    Timeout,
+    Code(i8),
+}
+
+/// Neither copyable nor comparable
+enum Upload {
+    Chunk(String),
+    Parts(Vec<String>, Option<String>),
+    Finished,
+}
+"#;
+
+/// `-fsanitize=address` when the compiler and platform support it
+fn sanitizer_args(ws: &Workspace, cxx: &str) -> Vec<&'static str> {
+    let args = vec!["-fsanitize=address", "-fno-omit-frame-pointer"];
+    let probe = ws.write("probe.cpp", "int main() { return 0; }\n");
+    let exe = ws.path("probe");
+
+    let supported = Command::new(cxx)
+        .args(&args)
+        .arg(&probe)
+        .arg("-o")
+        .arg(&exe)
+        .output()
+        .is_ok_and(|output| output.status.success())
+        && Command::new(&exe)
+            .status()
+            .is_ok_and(|status| status.success());
+    if supported {
+        args
+    } else {
+        eprintln!("no AddressSanitizer, running the tests without it");
+        Vec::new()
+    }
+}
+
+#[test]
+fn test_emit_tests() {
+    let ws = Workspace::new("emit-tests");
+    let input = ws.write("nmea.rs", INPUT);
+    let tests_dir = ws.path("tests");
+    ws.generate(&input, "t", &["--emit-tests", tests_dir.to_str().unwrap()]);
+
+    let test = fs::read_to_string(ws.path("tests/t/upload.test.cpp")).unwrap();
+    assert!(test.contains("#include \"t/upload.hpp\"\n"));
+    assert!(test.contains(
+        "        return Upload::Parts(std::tuple<std::vector<std::string>, \
+         ::cppust::Option<std::string>>(vec_of<std::string>(std::string(kText)), \
+         ::cppust::Option<std::string>::Some(std::string(kText))));\n"
+    ));
+    // copies and comparisons only with the derives
+    assert!(!test.contains("Upload copy"));
+    assert!(!test.contains("== sample("));
+    let test = fs::read_to_string(ws.path("tests/t/decoder_status.test.cpp")).unwrap();
+    assert!(test.contains(
+        "        return DecoderStatus::Done(::t::frames::Sentence::Nested(\
+         vec_of<::t::frames::Sentence>(::t::frames::Sentence::Nested(\
+         std::vector<::t::frames::Sentence>()))));\n"
+    ));
+    assert!(test.contains("            copy = value;\n"));
+    assert!(test.contains("        CHECK(debugs_as(value, kNames[i]));\n"));
+
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+    let sanitizer = sanitizer_args(&ws, &cxx);
+
+    let sources = ws.generated_sources();
+    for (test, class) in [
+        ("t/decoder_status", "DecoderStatus"),
+        ("t/frames/sentence", "Frame"),
+        ("t/fault", "Fault"),
+        ("t/upload", "Upload"),
+    ] {
+        let mut sources = sources.clone();
+        sources.push(ws.path(&format!("tests/{}.test.cpp", test)));
+        let exe = ws.compile(&cxx, "c++11", &sources, &sanitizer);
+        assert_eq!(ws.run(&exe), format!("{}: all checks passed\n", class));
+    }
+
+    if sanitizer.is_empty() {
+        return;
+    }
+
+    // the payload of the previous variant leaks when moving over another variant
+    let source = ws.path("src/upload.gen.cpp");
+    let code = fs::read_to_string(&source).unwrap();
+    let broken = code.replacen(
+        "    } else {\n        deinit_union_();\n",
+        "    } else {\n",
+        1,
+    );
+    assert_ne!(broken, code);
+    fs::write(&source, broken).unwrap();
+    let mut sources = sources.clone();
+    sources.push(ws.path("tests/t/upload.test.cpp"));
+    let exe = ws.compile(&cxx, "c++11", &sources, &sanitizer);
+    let output = Command::new(exe).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("LeakSanitizer"));
+}
+
+#[test]
+fn test_amalgamated_emit_tests() {
+    let ws = Workspace::new("emit-tests-amalgamated");
+    // amalgamated types share one namespace
+    let input = ws.write("nmea.rs", &INPUT.replace("namespace = \"t::frames\", ", ""));
+    let tests_dir = ws.path("tests");
+    ws.generate_header_only(
+        &input,
+        "t",
+        &[
+            "--amalgamate",
+            "nmea",
+            "--backend",
+            "variant",
+            "--cpp-std",
+            "17",
+            "--emit-tests",
+            tests_dir.to_str().unwrap(),
+        ],
+    );
+
+    let test = fs::read_to_string(ws.path("tests/t/sentence.test.cpp")).unwrap();
+    assert!(test.contains("#include \"t/nmea.hpp\"\n"));
+
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+    let sanitizer = sanitizer_args(&ws, &cxx);
+
+    for test in ["decoder_status", "sentence", "fault", "upload"] {
+        let test = ws.path(&format!("tests/t/{}.test.cpp", test));
+        let exe = ws.compile(&cxx, "c++17", &[test], &sanitizer);
+        assert!(ws.run(&exe).ends_with(": all checks passed\n"));
+    }
+}
+
+#[test]
+fn test_embedded_emit_tests() {
+    let ws = Workspace::new("emit-tests-embedded");
+    // `cppust::Writer` only formats what needs no heap
+    let input = ws.write(
+        "embedded.rs",
+        r#"
+#[derive(Clone, Debug, PartialEq)]
+enum Command {
+    Stop,
+    Move(i32, i16),
+    Speed(Option<u16>),
+}
+"#,
+    );
+    let tests_dir = ws.path("tests");
+    ws.generate(
+        &input,
+        "t",
+        &[
+            "--no-exceptions",
+            "--no-iostream",
+            "--emit-tests",
+            tests_dir.to_str().unwrap(),
+        ],
+    );
+
+    let test = fs::read_to_string(ws.path("tests/t/command.test.cpp")).unwrap();
+    assert!(!test.contains("panics"));
+    assert!(test.contains("    ::cppust::SliceWriter writer(buf, sizeof(buf));\n"));
+    assert!(test.contains("    int failures = t::run();\n"));
+
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+    let sanitizer = sanitizer_args(&ws, &cxx);
+
+    let mut sources = ws.generated_sources();
+    sources.push(ws.path("tests/t/command.test.cpp"));
+    let mut args = vec!["-fno-exceptions"];
+    args.extend(sanitizer);
+    let exe = ws.compile(&cxx, "c++11", &sources, &args);
+    assert_eq!(ws.run(&exe), "Command: all checks passed\n");
+}
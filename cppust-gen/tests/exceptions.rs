@@ -0,0 +1,186 @@
+//! Runtime test: assignments keep the value when copying a payload throws and leave it valueless
+//! when moving one in throws, with both backends, and factories destroy no payload they failed to
+//! construct; `swap` exchanges values and is found by ADL.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+#[derive(Clone)]
+enum Slot {
+    Empty,
+    Text(String),
+    Fragile(#[cppust(type = "CopyThrows")] CopyThrows),
+    Volatile(#[cppust(type = "MoveThrows")] MoveThrows),
+    Both(#[cppust(type = "MoveThrows")] MoveThrows, String),
+}
+"#;
+
+/// Payloads throwing on demand, counting their live instances to catch leaks and double frees
+const PAYLOADS: &str = r#"#pragma once
+
+#include <stdexcept>
+
+struct Counted {
+    static int live;
+    Counted() { ++live; }
+    Counted(const Counted&) { ++live; }
+    ~Counted() { --live; }
+};
+
+// copies throw once armed, moves never do
+struct CopyThrows: Counted {
+    static bool armed;
+    CopyThrows() = default;
+    CopyThrows(const CopyThrows& other): Counted(other) {
+        if (armed) {
+            throw std::runtime_error("copy");
+        }
+    }
+    CopyThrows(CopyThrows&&) noexcept = default;
+    CopyThrows& operator=(const CopyThrows&) = default;
+    CopyThrows& operator=(CopyThrows&&) noexcept = default;
+};
+
+// moves throw once armed
+struct MoveThrows: Counted {
+    static bool armed;
+    MoveThrows() = default;
+    MoveThrows(const MoveThrows&) = default;
+    MoveThrows(MoveThrows&& other): Counted(other) {
+        if (armed) {
+            throw std::runtime_error("move");
+        }
+    }
+    MoveThrows& operator=(const MoveThrows&) = default;
+    MoveThrows& operator=(MoveThrows&&) = default;
+};
+"#;
+
+const MAIN: &str = r#"#include "t/slot.hpp"
+
+#include <cstdio>
+#include <utility>
+
+int Counted::live = 0;
+bool CopyThrows::armed = false;
+bool MoveThrows::armed = false;
+
+const char* state(const t::Slot& slot) {
+    if (slot.valueless_by_exception()) return "valueless";
+    if (slot.is_empty()) return "empty";
+    if (slot.is_text()) return slot.text_ref().c_str();
+    if (slot.is_fragile()) return "fragile";
+    if (slot.is_both()) return "both";
+    return "volatile";
+}
+
+int main() {
+    {
+        // a throwing copy leaves the assigned value as it was
+        t::Slot slot = t::Slot::Text("kept");
+        const t::Slot fragile = t::Slot::Fragile(CopyThrows());
+        CopyThrows::armed = true;
+        try {
+            slot = fragile;
+        } catch (const std::runtime_error&) {
+            printf("copy threw: ");
+        }
+        CopyThrows::armed = false;
+        printf("%s\n", state(slot));
+    }
+    {
+        // a throwing move leaves the value valueless, yet assignable and destructible
+        t::Slot slot = t::Slot::Text("lost");
+        t::Slot volatile_ = t::Slot::Volatile(MoveThrows());
+        MoveThrows::armed = true;
+        try {
+            slot = std::move(volatile_);
+        } catch (const std::runtime_error&) {
+            printf("move threw: ");
+        }
+        MoveThrows::armed = false;
+        printf("%s", state(slot));
+        slot = t::Slot::Empty();
+        printf(", then %s\n", state(slot));
+    }
+    {
+        t::Slot slot = t::Slot::Text("lost");
+        MoveThrows::armed = true;
+        try {
+            slot.emplace_volatile(MoveThrows());
+        } catch (const std::runtime_error&) {
+            printf("emplace threw: ");
+        }
+        MoveThrows::armed = false;
+        printf("%s\n", state(slot));
+    }
+    {
+        // factories throwing while constructing the payload leave nothing to destroy
+        const CopyThrows fragile;
+        const int live = Counted::live;
+        CopyThrows::armed = true;
+        try {
+            t::Slot::Fragile(fragile);
+        } catch (const std::runtime_error&) {
+            printf("copying factory threw, ");
+        }
+        CopyThrows::armed = false;
+        MoveThrows::armed = true;
+        try {
+            t::Slot::Volatile(MoveThrows());
+        } catch (const std::runtime_error&) {
+            printf("moving factory threw, ");
+        }
+        try {
+            t::Slot::Both(MoveThrows(), std::string("b"));
+        } catch (const std::runtime_error&) {
+            printf("per-field factory threw: ");
+        }
+        MoveThrows::armed = false;
+        printf("%d\n", Counted::live - live);
+    }
+    {
+        t::Slot a = t::Slot::Text("a");
+        t::Slot b = t::Slot::Fragile(CopyThrows());
+        swap(a, b);
+        printf("swapped: %s %s", state(a), state(b));
+        a.swap(b);
+        printf(", back: %s %s\n", state(a), state(b));
+        using std::swap;
+        swap(a, a);
+        printf("self: %s\n", state(a));
+    }
+    printf("live: %d\n", Counted::live);
+    return 0;
+}
+"#;
+
+const EXPECTED: &str = "\
+copy threw: kept
+move threw: valueless, then empty
+emplace threw: valueless
+copying factory threw, moving factory threw, per-field factory threw: 0
+swapped: fragile a, back: a fragile
+self: a
+live: 0
+";
+
+#[test]
+fn test_exception_safety() {
+    for (backend, cfg) in [("union", Cfg::UNION), ("variant", Cfg::VARIANT)] {
+        let cfg = Cfg {
+            cxx_args: &["-include", "payloads.hpp"],
+            files: &[("payloads.hpp", PAYLOADS)],
+            ..cfg
+        };
+        common::compile_and_run(
+            &format!("exceptions-{}", backend),
+            INPUT,
+            MAIN,
+            &cfg,
+            EXPECTED,
+        );
+    }
+}
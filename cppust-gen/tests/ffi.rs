@@ -0,0 +1,128 @@
+//! Link test: the `#[repr(C)]` mirrors generated with `--ffi` are compiled by rustc into a
+//! static library, which a C++ program calls with the generated classes.
+
+mod common;
+
+use std::fs;
+
+use common::Workspace;
+
+const INPUT: &str = r#"
+type Id = u16;
+
+#[derive(Clone, Debug)]
+pub enum DecoderStatus {
+    Idle,
+    Done(Frame),
+    Failed(String),
+    Skipped(Id, char),
+}
+
+#[derive(Clone, Debug)]
+pub enum Frame {
+    Gga(u16),
+    Empty,
+}
+"#;
+
+const LIB: &str = r#"
+pub mod nmea {
+    include!("nmea.rs");
+
+    pub mod ffi {
+        include!("ffi/nmea_ffi.rs");
+    }
+}
+
+use nmea::{ffi::DecoderStatusFfi, DecoderStatus, Frame};
+
+#[no_mangle]
+pub extern "C" fn decoder_step(status: DecoderStatusFfi) -> DecoderStatusFfi {
+    match DecoderStatus::from(status) {
+        DecoderStatus::Idle => DecoderStatus::Done(Frame::Gga(7)),
+        DecoderStatus::Done(frame) => DecoderStatus::Failed(format!("{:?}", frame)),
+        DecoderStatus::Failed(message) => DecoderStatus::Skipped(message.len() as u16, 'λ'),
+        DecoderStatus::Skipped(..) => DecoderStatus::Idle,
+    }
+    .into()
+}
+"#;
+
+const MAIN: &str = r#"
+#include "t/nmea_ffi.hpp"
+
+#include <iostream>
+
+extern "C" t::ffi::DecoderStatusFfi decoder_step(t::ffi::DecoderStatusFfi status);
+
+int main() {
+    t::DecoderStatus status = t::DecoderStatus::Failed("checksum mismatch");
+    for (int i = 0; i < 4; ++i) {
+        status = t::ffi::take_ffi(decoder_step(t::ffi::to_ffi(status)));
+        if (status.is_skipped()) {
+            std::cout<<std::get<0>(status.skipped_ref())<<" "
+                <<static_cast<unsigned>(std::get<1>(status.skipped_ref()))<<" ";
+        } else {
+            std::cout<<cppust::as_debug(status)<<" ";
+        }
+    }
+    std::cout<<"\n";
+    return 0;
+}
+"#;
+
+fn link(ws: &Workspace, sources: &[std::path::PathBuf]) -> Option<std::path::PathBuf> {
+    let cxx = common::cxx()?;
+    let rustc = common::rustc()?;
+
+    let lib = ws.compile_staticlib(&rustc, &ws.write("lib.rs", LIB));
+    let mut sources = sources.to_vec();
+    sources.push(ws.write("main.cpp", MAIN));
+    sources.push(lib);
+
+    Some(ws.compile(&cxx, "c++11", &sources, &["-lpthread", "-ldl", "-lm"]))
+}
+
+#[test]
+fn test_ffi() {
+    let ws = Workspace::new("ffi");
+    let input = ws.write("nmea.rs", INPUT);
+    let ffi_dir = ws.path("ffi");
+    ws.generate(&input, "t", &["--ffi", ffi_dir.to_str().unwrap()]);
+
+    let mirrors = fs::read_to_string(ws.path("ffi/nmea_ffi.rs")).unwrap();
+    assert!(mirrors
+        .contains("#[repr(C, u32)]\n#[derive(Clone, Copy, Debug)]\npub enum DecoderStatusFfi {\n"));
+    assert!(mirrors.contains("    Skipped(u16, u32),\n"));
+    // mirrors store each other by value, so `Frame` comes first
+    assert!(
+        mirrors.find("enum FrameFfi").unwrap() < mirrors.find("enum DecoderStatusFfi").unwrap()
+    );
+
+    let header = fs::read_to_string(ws.path("include/t/nmea_ffi.hpp")).unwrap();
+    assert!(header.contains("#include \"t/frame.hpp\"\n#include \"t/decoder_status.hpp\"\n"));
+    assert!(header.contains("extern \"C\" void decoder_status_ffi_drop(DecoderStatusFfi value);"));
+
+    if let Some(exe) = link(&ws, &ws.generated_sources()) {
+        assert_eq!(ws.run(&exe), "17 955 Idle Done(Gga(7)) Failed(Gga(7)) \n");
+    }
+}
+
+#[test]
+fn test_amalgamated_ffi() {
+    let ws = Workspace::new("ffi-amalgamated");
+    let input = ws.write("nmea.rs", INPUT);
+    let ffi_dir = ws.path("ffi");
+    ws.generate_header_only(
+        &input,
+        "t",
+        &["--amalgamate", "nmea", "--ffi", ffi_dir.to_str().unwrap()],
+    );
+
+    let header = fs::read_to_string(ws.path("include/t/nmea_ffi.hpp")).unwrap();
+    assert!(header.contains("#include \"t/nmea.hpp\"\n"));
+
+    if let Some(exe) = link(&ws, &[]) {
+        assert_eq!(ws.run(&exe), "17 955 Idle Done(Gga(7)) Failed(Gga(7)) \n");
+    }
+}
@@ -0,0 +1,579 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+
+#pragma once
+
+#include <cppust/types.hpp>
+#include <cppust/result.hpp>
+#include <cppust/vec.hpp>
+#include <cppust/utils.hpp>
+#include <cppust/option.hpp>
+#include <cppust/fmt.hpp>
+#include <cppust/hash.hpp>
+#include <cppust/cmp.hpp>
+
+#include <functional>
+#include <new>
+#include <utility>
+#include <stdexcept>
+#include <iostream>
+#include <string>
+#include <tuple>
+
+namespace t {
+
+class Fault;
+class Sentence;
+class Upload;
+
+template <typename T>
+using Res = ::cppust::Result<T, Fault>;
+
+using Satellites = std::vector<::cppust::u8>;
+
+/// @brief Longest sentence, including `$` and the line ending
+constexpr ::cppust::usize MAX_SENTENCE_LEN = 82;
+
+constexpr char32_t SEPARATOR = U',';
+
+class Fault {
+private: // type definition
+    enum class _Tag {
+        Timeout = 1,
+        Checksum,
+        Overflow = 8,
+        _Valueless = 9,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<>();
+
+    union alignas(_kUnionAlign) _Union {
+        char _unit;
+        constexpr _Union(): _unit() {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    constexpr Fault(): Fault(_Tag::Timeout) {}  // Default
+    Fault(const Fault&) = default;  // Clone
+    Fault(Fault&&) = default;
+    ~Fault() = default;
+
+    Fault& operator=(const Fault&) = default;  // Clone
+    Fault& operator=(Fault&&) = default;
+
+    void swap(Fault& other) noexcept { std::swap(*this, other); }
+    friend void swap(Fault& lhs, Fault& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    constexpr bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+    bool operator==(const Fault&) const;  // PartialEq
+    bool operator!=(const Fault&) const;  // PartialEq
+    ::cppust::Option<::cppust::Ordering> partial_cmp(const Fault&) const;  // PartialOrd
+    bool operator<(const Fault&) const;  // PartialOrd
+    bool operator<=(const Fault&) const;  // PartialOrd
+    bool operator>(const Fault&) const;  // PartialOrd
+    bool operator>=(const Fault&) const;  // PartialOrd
+#if __cplusplus > 201703L
+    std::strong_ordering operator<=>(const Fault&) const;  // PartialOrd
+#endif
+    template<typename> friend struct ::cppust::debug;  // Debug
+    friend struct ::std::hash<Fault>;  // Hash
+
+public: // enum constructors
+    static constexpr Fault Timeout() { return Fault(_Tag::Timeout); }
+    static constexpr Fault Checksum() { return Fault(_Tag::Checksum); }
+    static constexpr Fault Overflow() { return Fault(_Tag::Overflow); }
+
+public: // in-place constructors
+public: // value accessors
+    constexpr bool is_timeout() const { return tag_ == _Tag::Timeout; }
+
+    constexpr bool is_checksum() const { return tag_ == _Tag::Checksum; }
+
+    constexpr bool is_overflow() const { return tag_ == _Tag::Overflow; }
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    constexpr Fault(_Tag tag): union_(), tag_(tag) {}
+    constexpr Fault(_Tag tag, const _Union& union_val): union_(union_val), tag_(tag) {}
+};
+
+/// @brief A decoded sentence
+class Sentence {
+private: // type definition
+    enum class _Tag {
+        /// Nothing received yet
+        Empty,
+        /// Latitude and longitude of the fix
+        Gga,
+        Gsv,
+        Talker,
+        Checked,
+        Raw,
+        _Valueless,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+        , std::tuple<::cppust::f64, ::cppust::f64>
+        , Satellites
+        , std::string
+        , Res<::cppust::Option<::cppust::i32>>
+        , std::vector<std::string>
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<std::tuple<::cppust::f64, ::cppust::f64>, Satellites, std::string, Res<::cppust::Option<::cppust::i32>>, std::vector<std::string>>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<std::tuple<::cppust::f64, ::cppust::f64>, Satellites, std::string, Res<::cppust::Option<::cppust::i32>>, std::vector<std::string>>();
+
+    union alignas(_kUnionAlign) _Union {
+        std::tuple<::cppust::f64, ::cppust::f64> gga_val;
+        Satellites gsv_val;
+        std::string talker_val;
+        Res<::cppust::Option<::cppust::i32>> checked_val;
+        std::vector<std::string> raw_val;
+        inline _Union() {}
+        inline ~_Union() {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    Sentence(const Sentence&);  // Clone
+    Sentence(Sentence&&) noexcept(_kNothrowMoveConstructible);
+    ~Sentence();
+
+    Sentence& operator=(const Sentence&);  // Clone
+    Sentence& operator=(Sentence&&) noexcept(_kNothrowMoveAssignable);
+
+    void swap(Sentence&) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable);
+    friend void swap(Sentence& lhs, Sentence& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+    bool operator==(const Sentence&) const;  // PartialEq
+    bool operator!=(const Sentence&) const;  // PartialEq
+    ::cppust::Option<::cppust::Ordering> partial_cmp(const Sentence&) const;  // PartialOrd
+    bool operator<(const Sentence&) const;  // PartialOrd
+    bool operator<=(const Sentence&) const;  // PartialOrd
+    bool operator>(const Sentence&) const;  // PartialOrd
+    bool operator>=(const Sentence&) const;  // PartialOrd
+#if __cplusplus > 201703L
+    std::partial_ordering operator<=>(const Sentence&) const;  // PartialOrd
+#endif
+    template<typename> friend struct ::cppust::debug;  // Debug
+
+public: // enum constructors
+    /// @brief Nothing received yet
+    static Sentence Empty();
+    /// @brief Latitude and longitude of the fix
+    static Sentence Gga(const ::cppust::f64&, const ::cppust::f64&);
+    static Sentence Gga(std::tuple<::cppust::f64, ::cppust::f64>&&);
+    static Sentence Gga(::cppust::f64&&, ::cppust::f64&&);
+    static Sentence Gsv(const Satellites&);
+    static Sentence Gsv(Satellites&&);
+    static Sentence Talker(const std::string&);
+    static Sentence Talker(std::string&&);
+    static Sentence Checked(const Res<::cppust::Option<::cppust::i32>>&);
+    static Sentence Checked(Res<::cppust::Option<::cppust::i32>>&&);
+    static Sentence Raw(const std::vector<std::string>&);
+    static Sentence Raw(std::vector<std::string>&&);
+
+public: // in-place constructors
+    /// @brief Replaces the value with a `Gga` constructed from `args`
+    /// @details Latitude and longitude of the fix
+    template <typename... Args>
+    std::tuple<::cppust::f64, ::cppust::f64>& emplace_gga(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.gga_val) std::tuple<::cppust::f64, ::cppust::f64>(std::forward<Args>(args)...);
+        tag_ = _Tag::Gga;
+        return union_.gga_val;
+    }
+
+    template <typename... Args>
+    Satellites& emplace_gsv(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.gsv_val) Satellites(std::forward<Args>(args)...);
+        tag_ = _Tag::Gsv;
+        return union_.gsv_val;
+    }
+
+    template <typename... Args>
+    std::string& emplace_talker(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.talker_val) std::string(std::forward<Args>(args)...);
+        tag_ = _Tag::Talker;
+        return union_.talker_val;
+    }
+
+    template <typename... Args>
+    Res<::cppust::Option<::cppust::i32>>& emplace_checked(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.checked_val) Res<::cppust::Option<::cppust::i32>>(std::forward<Args>(args)...);
+        tag_ = _Tag::Checked;
+        return union_.checked_val;
+    }
+
+    template <typename... Args>
+    std::vector<std::string>& emplace_raw(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.raw_val) std::vector<std::string>(std::forward<Args>(args)...);
+        tag_ = _Tag::Raw;
+        return union_.raw_val;
+    }
+
+public: // value accessors
+    /// @brief Whether the value is `Empty`
+    /// @details Nothing received yet
+    bool is_empty() const;
+
+    /// @brief Whether the value is `Gga`
+    /// @details Latitude and longitude of the fix
+    bool is_gga() const;
+
+    /// @brief Payload of `Gga`, which must be the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& gga_ref_uncheck() const;
+    std::tuple<::cppust::f64, ::cppust::f64>& gga_ref_uncheck();
+
+    /// @brief Payload of `Gga`, panics unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& gga_ref() const;
+    std::tuple<::cppust::f64, ::cppust::f64>& gga_ref();
+
+    /// @brief Payload of `Gga`, or `nullptr` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>* gga_ptr() const;
+    std::tuple<::cppust::f64, ::cppust::f64>* gga_ptr();
+
+    /// @brief Payload of `Gga`, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>> as_gga() const;
+    ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>> as_gga();
+
+    /// @brief Payload of `Gga`, panics with `msg` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& expect_gga(const char* msg) const &;
+    std::tuple<::cppust::f64, ::cppust::f64>& expect_gga(const char* msg) &;
+    std::tuple<::cppust::f64, ::cppust::f64> expect_gga(const char* msg) &&;
+
+    /// @brief Moves out the payload of `Gga`, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> into_gga() &&;
+
+    /// @brief Moves out the payload of `Gga`, leaving it moved-from, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> take_gga() &;
+
+    bool is_gsv() const;
+
+    const Satellites& gsv_ref_uncheck() const;
+    Satellites& gsv_ref_uncheck();
+
+    const Satellites& gsv_ref() const;
+    Satellites& gsv_ref();
+
+    const Satellites* gsv_ptr() const;
+    Satellites* gsv_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const Satellites>> as_gsv() const;
+    ::cppust::Option<std::reference_wrapper<Satellites>> as_gsv();
+
+    const Satellites& expect_gsv(const char* msg) const &;
+    Satellites& expect_gsv(const char* msg) &;
+    Satellites expect_gsv(const char* msg) &&;
+
+    ::cppust::Option<Satellites> into_gsv() &&;
+
+    ::cppust::Option<Satellites> take_gsv() &;
+
+    bool is_talker() const;
+
+    const std::string& talker_ref_uncheck() const;
+    std::string& talker_ref_uncheck();
+
+    const std::string& talker_ref() const;
+    std::string& talker_ref();
+
+    const std::string* talker_ptr() const;
+    std::string* talker_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::string>> as_talker() const;
+    ::cppust::Option<std::reference_wrapper<std::string>> as_talker();
+
+    const std::string& expect_talker(const char* msg) const &;
+    std::string& expect_talker(const char* msg) &;
+    std::string expect_talker(const char* msg) &&;
+
+    ::cppust::Option<std::string> into_talker() &&;
+
+    ::cppust::Option<std::string> take_talker() &;
+
+    bool is_checked() const;
+
+    const Res<::cppust::Option<::cppust::i32>>& checked_ref_uncheck() const;
+    Res<::cppust::Option<::cppust::i32>>& checked_ref_uncheck();
+
+    const Res<::cppust::Option<::cppust::i32>>& checked_ref() const;
+    Res<::cppust::Option<::cppust::i32>>& checked_ref();
+
+    const Res<::cppust::Option<::cppust::i32>>* checked_ptr() const;
+    Res<::cppust::Option<::cppust::i32>>* checked_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>> as_checked() const;
+    ::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>> as_checked();
+
+    const Res<::cppust::Option<::cppust::i32>>& expect_checked(const char* msg) const &;
+    Res<::cppust::Option<::cppust::i32>>& expect_checked(const char* msg) &;
+    Res<::cppust::Option<::cppust::i32>> expect_checked(const char* msg) &&;
+
+    ::cppust::Option<Res<::cppust::Option<::cppust::i32>>> into_checked() &&;
+
+    ::cppust::Option<Res<::cppust::Option<::cppust::i32>>> take_checked() &;
+
+    bool is_raw() const;
+
+    const std::vector<std::string>& raw_ref_uncheck() const;
+    std::vector<std::string>& raw_ref_uncheck();
+
+    const std::vector<std::string>& raw_ref() const;
+    std::vector<std::string>& raw_ref();
+
+    const std::vector<std::string>* raw_ptr() const;
+    std::vector<std::string>* raw_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::vector<std::string>>> as_raw() const;
+    ::cppust::Option<std::reference_wrapper<std::vector<std::string>>> as_raw();
+
+    const std::vector<std::string>& expect_raw(const char* msg) const &;
+    std::vector<std::string>& expect_raw(const char* msg) &;
+    std::vector<std::string> expect_raw(const char* msg) &&;
+
+    ::cppust::Option<std::vector<std::string>> into_raw() &&;
+
+    ::cppust::Option<std::vector<std::string>> take_raw() &;
+
+public: // methods
+    /// @brief Whether the sentence carries a position
+    bool has_fix() const;
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    Sentence(_Tag);
+    Sentence(_Tag, const _Union&);
+    Sentence(_Tag, _Union&&);
+
+    void tagged_init_(_Tag, const _Union&);
+    void tagged_init_(_Tag, _Union&&);
+    void deinit_union_();
+};
+
+/// @brief Neither copyable nor comparable
+class Upload {
+private: // type definition
+    enum class _Tag {
+        Chunk,
+        Parts,
+        Finished,
+        _Valueless,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+        , std::string
+        , std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<std::string, std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<std::string, std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>();
+
+    union alignas(_kUnionAlign) _Union {
+        std::string chunk_val;
+        std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>> parts_val;
+        inline _Union() {}
+        inline ~_Union() {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    Upload(const Upload&) = delete;
+    Upload(Upload&&) noexcept(_kNothrowMoveConstructible);
+    ~Upload();
+
+    Upload& operator=(const Upload&) = delete;
+    Upload& operator=(Upload&&) noexcept(_kNothrowMoveAssignable);
+
+    void swap(Upload&) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable);
+    friend void swap(Upload& lhs, Upload& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+public: // enum constructors
+    static Upload Chunk(const std::string&);
+    static Upload Chunk(std::string&&);
+    static Upload Parts(const std::vector<Sentence>&, const ::cppust::Option<Fault>&);
+    static Upload Parts(std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>&&);
+    static Upload Parts(std::vector<Sentence>&&, ::cppust::Option<Fault>&&);
+    static Upload Finished();
+
+public: // in-place constructors
+    template <typename... Args>
+    std::string& emplace_chunk(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.chunk_val) std::string(std::forward<Args>(args)...);
+        tag_ = _Tag::Chunk;
+        return union_.chunk_val;
+    }
+
+    template <typename... Args>
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& emplace_parts(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.parts_val) std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>(std::forward<Args>(args)...);
+        tag_ = _Tag::Parts;
+        return union_.parts_val;
+    }
+
+public: // value accessors
+    bool is_chunk() const;
+
+    const std::string& chunk_ref_uncheck() const;
+    std::string& chunk_ref_uncheck();
+
+    const std::string& chunk_ref() const;
+    std::string& chunk_ref();
+
+    const std::string* chunk_ptr() const;
+    std::string* chunk_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::string>> as_chunk() const;
+    ::cppust::Option<std::reference_wrapper<std::string>> as_chunk();
+
+    const std::string& expect_chunk(const char* msg) const &;
+    std::string& expect_chunk(const char* msg) &;
+    std::string expect_chunk(const char* msg) &&;
+
+    ::cppust::Option<std::string> into_chunk() &&;
+
+    ::cppust::Option<std::string> take_chunk() &;
+
+    bool is_parts() const;
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref_uncheck() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref_uncheck();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* parts_ptr() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* parts_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> as_parts() const;
+    ::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> as_parts();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& expect_parts(const char* msg) const &;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& expect_parts(const char* msg) &;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>> expect_parts(const char* msg) &&;
+
+    ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> into_parts() &&;
+
+    ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> take_parts() &;
+
+    bool is_finished() const;
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    Upload(_Tag);
+    Upload(_Tag, _Union&&);
+
+    void tagged_init_(_Tag, _Union&&);
+    void deinit_union_();
+};
+
+}
+
+namespace cppust {
+    template <> struct debug<t::Fault> { // Debug
+        template <typename Os>
+        static Os& fmt(const t::Fault& self, Os& os) {
+            switch (self.tag_) {
+            case t::Fault::_Tag::Timeout:
+                os<<"Timeout";
+                break;
+            case t::Fault::_Tag::Checksum:
+                os<<"Checksum";
+                break;
+            case t::Fault::_Tag::Overflow:
+                os<<"Overflow";
+                break;
+            default:
+                os<<"<?>("<<int(self.tag_)<<")";
+                break;
+            }
+            return os;
+        }
+    };
+}
+
+namespace std {
+    template <> struct hash<t::Fault> { // Hash
+        size_t operator()(const t::Fault& self) const {
+            size_t seed = std::hash<int>()(int(self.tag_));
+            switch (self.tag_) {
+            default:
+                return seed;
+            }
+        }
+    };
+}
+
+namespace cppust {
+    template <> struct debug<t::Sentence> { // Debug
+        template <typename Os>
+        static Os& fmt(const t::Sentence& self, Os& os) {
+            switch (self.tag_) {
+            case t::Sentence::_Tag::Empty:
+                os<<"Empty";
+                break;
+            case t::Sentence::_Tag::Gga:
+                os<<"Gga("<<::cppust::as_debug(self.union_.gga_val)<<")";
+                break;
+            case t::Sentence::_Tag::Gsv:
+                os<<"Gsv("<<::cppust::as_debug(self.union_.gsv_val)<<")";
+                break;
+            case t::Sentence::_Tag::Talker:
+                os<<"Talker("<<::cppust::as_debug(self.union_.talker_val)<<")";
+                break;
+            case t::Sentence::_Tag::Checked:
+                os<<"Checked("<<::cppust::as_debug(self.union_.checked_val)<<")";
+                break;
+            case t::Sentence::_Tag::Raw:
+                os<<"Unknown("<<::cppust::as_debug(self.union_.raw_val)<<")";
+                break;
+            default:
+                os<<"<?>("<<int(self.tag_)<<")";
+                break;
+            }
+            return os;
+        }
+    };
+}
+
@@ -0,0 +1,15 @@
+//! Commands of a microcontroller, with payloads needing no heap
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Hash)]
+enum Command {
+    Stop,
+    Move(i32, i16),
+    Speed(Option<u16>),
+    Toggle(bool),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Led {
+    Off,
+    On(u8),
+}
@@ -0,0 +1,47 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE command.hpp INSTEAD
+
+namespace cppust {
+    template <> struct debug<t::Command> { // Debug
+        template <typename Os>
+        static Os& fmt(const t::Command& self, Os& os) {
+            switch (self.tag_) {
+            case t::Command::_Tag::Stop:
+                os<<"Stop";
+                break;
+            case t::Command::_Tag::Move:
+                os<<"Move("<<::cppust::as_debug(self.union_.move_val)<<")";
+                break;
+            case t::Command::_Tag::Speed:
+                os<<"Speed("<<::cppust::as_debug(self.union_.speed_val)<<")";
+                break;
+            case t::Command::_Tag::Toggle:
+                os<<"Toggle("<<::cppust::as_debug(self.union_.toggle_val)<<")";
+                break;
+            default:
+                os<<"<?>("<<int(self.tag_)<<")";
+                break;
+            }
+            return os;
+        }
+    };
+}
+
+namespace std {
+    template <> struct hash<t::Command> { // Hash
+        size_t operator()(const t::Command& self) const {
+            size_t seed = std::hash<int>()(int(self.tag_));
+            switch (self.tag_) {
+            case t::Command::_Tag::Move:
+                return ::cppust::hash_combine(seed, ::cppust::hash_of(self.union_.move_val));
+            case t::Command::_Tag::Speed:
+                return ::cppust::hash_combine(seed, ::cppust::hash_of(self.union_.speed_val));
+            case t::Command::_Tag::Toggle:
+                return ::cppust::hash_combine(seed, ::cppust::hash_of(self.union_.toggle_val));
+            default:
+                return seed;
+            }
+        }
+    };
+}
+
@@ -0,0 +1,511 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE command.hpp INSTEAD
+
+#pragma once
+
+#include "t/command.hpp"
+
+namespace t {
+
+// public constructors
+inline Command::Command(const Command& rhs): Command(rhs.tag_, rhs.union_) { } // copy constructor
+inline Command::Command(Command&& rhs) noexcept(_kNothrowMoveConstructible): Command(rhs.tag_, std::move(rhs.union_)) { } // move constructor
+
+// private constructors
+inline Command::Command(_Tag tag): tag_(tag) { }
+
+inline Command::Command(_Tag tag, const _Union& union_val): tag_(tag) {
+    tagged_init_(tag, union_val);
+}
+
+inline Command::Command(_Tag tag, _Union&& union_val): tag_(tag) {
+    tagged_init_(tag, std::move(union_val));
+}
+
+
+// destructor
+inline Command::~Command() {
+    deinit_union_();
+}
+
+// assign operators
+inline Command& Command::operator=(const Command& rhs) { // assign
+    if (this == &rhs) { return *this; }
+
+    // copied aside first, so this value is kept when copying a payload throws
+    Command copy(rhs);
+    return *this = std::move(copy);
+}
+
+inline Command& Command::operator=(Command&& rhs) noexcept(_kNothrowMoveAssignable) { // move
+    if (this == &rhs) { return *this; }
+
+    if (tag_ == rhs.tag_) {
+        switch (tag_) {
+        case _Tag::Move:
+            union_.move_val = std::move(rhs.union_.move_val);
+            break;
+        case _Tag::Speed:
+            union_.speed_val = std::move(rhs.union_.speed_val);
+            break;
+        case _Tag::Toggle:
+            union_.toggle_val = std::move(rhs.union_.toggle_val);
+            break;
+        default:
+            break;
+        }
+    } else {
+        deinit_union_();
+        // valueless until the payload is moved in, which may throw
+        tag_ = _Tag::_Valueless;
+        tagged_init_(rhs.tag_, std::move(rhs.union_));
+        tag_ = rhs.tag_;
+    }
+    return *this;
+}
+
+inline void Command::swap(Command& other) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable) {
+    if (this == &other) { return; }
+
+    Command moved(std::move(other));
+    other = std::move(*this);
+    *this = std::move(moved);
+}
+
+// operators
+inline bool Command::operator==(const Command& rhs) const { // equal
+    if (tag_ != rhs.tag_) { return false; }
+    switch (tag_) {
+    case _Tag::Move:
+        return union_.move_val == rhs.union_.move_val;
+    case _Tag::Speed:
+        return union_.speed_val == rhs.union_.speed_val;
+    case _Tag::Toggle:
+        return union_.toggle_val == rhs.union_.toggle_val;
+    default:
+        break;
+    }
+    return true;
+}
+
+inline bool Command::operator!=(const Command& rhs) const { // not equal
+    return !(*this == rhs);
+}
+
+inline ::cppust::Option<::cppust::Ordering> Command::partial_cmp(const Command& rhs) const { // partial compare
+    if (tag_ != rhs.tag_) {
+        return ::cppust::Option<::cppust::Ordering>::Some(tag_ < rhs.tag_ ? ::cppust::Ordering::Less : ::cppust::Ordering::Greater);
+    }
+    switch (tag_) {
+    case _Tag::Move:
+        return ::cppust::partial_cmp(union_.move_val, rhs.union_.move_val);
+    case _Tag::Speed:
+        return ::cppust::partial_cmp(union_.speed_val, rhs.union_.speed_val);
+    case _Tag::Toggle:
+        return ::cppust::partial_cmp(union_.toggle_val, rhs.union_.toggle_val);
+    default:
+        break;
+    }
+    return ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+inline bool Command::operator<(const Command& rhs) const { // less than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less);
+}
+
+inline bool Command::operator<=(const Command& rhs) const { // less or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+inline bool Command::operator>(const Command& rhs) const { // greater than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater);
+}
+
+inline bool Command::operator>=(const Command& rhs) const { // greater or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+#if __cplusplus > 201703L
+inline std::partial_ordering Command::operator<=>(const Command& rhs) const { // three-way compare
+    return ::cppust::to_partial_ordering(partial_cmp(rhs));
+}
+#endif
+
+// enum constructors
+inline Command Command::Stop() {
+    return Command(_Tag::Stop);
+}
+
+inline Command Command::Move(const ::cppust::i32& v0, const ::cppust::i16& v1) {
+    Command output(_Tag::_Valueless);
+    new (&output.union_.move_val) std::tuple<::cppust::i32, ::cppust::i16>(v0, v1);
+    output.tag_ = _Tag::Move;
+    return output;
+}
+
+inline Command Command::Move(std::tuple<::cppust::i32, ::cppust::i16>&& value) {
+    Command output(_Tag::_Valueless);
+    new (&output.union_.move_val) std::tuple<::cppust::i32, ::cppust::i16>(std::move(value));
+    output.tag_ = _Tag::Move;
+    return output;
+}
+
+inline Command Command::Move(::cppust::i32&& v0, ::cppust::i16&& v1) {
+    Command output(_Tag::_Valueless);
+    new (&output.union_.move_val) std::tuple<::cppust::i32, ::cppust::i16>(std::move(v0), std::move(v1));
+    output.tag_ = _Tag::Move;
+    return output;
+}
+
+inline Command Command::Speed(const ::cppust::Option<::cppust::u16>& v0) {
+    Command output(_Tag::_Valueless);
+    new (&output.union_.speed_val) ::cppust::Option<::cppust::u16>(v0);
+    output.tag_ = _Tag::Speed;
+    return output;
+}
+
+inline Command Command::Speed(::cppust::Option<::cppust::u16>&& value) {
+    Command output(_Tag::_Valueless);
+    new (&output.union_.speed_val) ::cppust::Option<::cppust::u16>(std::move(value));
+    output.tag_ = _Tag::Speed;
+    return output;
+}
+
+inline Command Command::Toggle(const bool& v0) {
+    Command output(_Tag::_Valueless);
+    new (&output.union_.toggle_val) bool(v0);
+    output.tag_ = _Tag::Toggle;
+    return output;
+}
+
+inline Command Command::Toggle(bool&& value) {
+    Command output(_Tag::_Valueless);
+    new (&output.union_.toggle_val) bool(std::move(value));
+    output.tag_ = _Tag::Toggle;
+    return output;
+}
+
+// accessors
+inline bool Command::is_stop() const {
+    return tag_ == _Tag::Stop;
+}
+
+inline bool Command::is_move() const {
+    return tag_ == _Tag::Move;
+}
+
+inline const std::tuple<::cppust::i32, ::cppust::i16>& Command::move_ref_uncheck() const {
+    assert(tag_ == _Tag::Move);
+    return union_.move_val;
+}
+
+inline std::tuple<::cppust::i32, ::cppust::i16>& Command::move_ref_uncheck() {
+    assert(tag_ == _Tag::Move);
+    return union_.move_val;
+}
+
+inline const std::tuple<::cppust::i32, ::cppust::i16>& Command::move_ref() const {
+    if (tag_ != _Tag::Move) {
+        CPPUST_PANIC("requires Move");
+    }
+    return union_.move_val;
+}
+
+inline std::tuple<::cppust::i32, ::cppust::i16>& Command::move_ref() {
+    if (tag_ != _Tag::Move) {
+        CPPUST_PANIC("requires Move");
+    }
+    return union_.move_val;
+}
+
+inline const std::tuple<::cppust::i32, ::cppust::i16>* Command::move_ptr() const {
+    if (tag_ != _Tag::Move) {
+        return nullptr;
+    }
+    return &union_.move_val;
+}
+
+inline std::tuple<::cppust::i32, ::cppust::i16>* Command::move_ptr() {
+    if (tag_ != _Tag::Move) {
+        return nullptr;
+    }
+    return &union_.move_val;
+}
+
+inline ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::i32, ::cppust::i16>>> Command::as_move() const {
+    if (tag_ != _Tag::Move) {
+        return ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::i32, ::cppust::i16>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::i32, ::cppust::i16>>>::Some(std::cref(union_.move_val));
+}
+
+inline ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::i32, ::cppust::i16>>> Command::as_move() {
+    if (tag_ != _Tag::Move) {
+        return ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::i32, ::cppust::i16>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::i32, ::cppust::i16>>>::Some(std::ref(union_.move_val));
+}
+
+inline const std::tuple<::cppust::i32, ::cppust::i16>& Command::expect_move(const char* msg) const & {
+    if (tag_ != _Tag::Move) {
+        CPPUST_PANIC(msg);
+    }
+    return union_.move_val;
+}
+
+inline std::tuple<::cppust::i32, ::cppust::i16>& Command::expect_move(const char* msg) & {
+    if (tag_ != _Tag::Move) {
+        CPPUST_PANIC(msg);
+    }
+    return union_.move_val;
+}
+
+inline std::tuple<::cppust::i32, ::cppust::i16> Command::expect_move(const char* msg) && {
+    if (tag_ != _Tag::Move) {
+        CPPUST_PANIC(msg);
+    }
+    return std::move(union_.move_val);
+}
+
+inline ::cppust::Option<std::tuple<::cppust::i32, ::cppust::i16>> Command::into_move() && {
+    if (tag_ != _Tag::Move) {
+        return ::cppust::Option<std::tuple<::cppust::i32, ::cppust::i16>>::None();
+    }
+    return ::cppust::Option<std::tuple<::cppust::i32, ::cppust::i16>>::Some(std::move(union_.move_val));
+}
+
+inline ::cppust::Option<std::tuple<::cppust::i32, ::cppust::i16>> Command::take_move() & {
+    return std::move(*this).into_move();
+}
+
+inline bool Command::is_speed() const {
+    return tag_ == _Tag::Speed;
+}
+
+inline const ::cppust::Option<::cppust::u16>& Command::speed_ref_uncheck() const {
+    assert(tag_ == _Tag::Speed);
+    return union_.speed_val;
+}
+
+inline ::cppust::Option<::cppust::u16>& Command::speed_ref_uncheck() {
+    assert(tag_ == _Tag::Speed);
+    return union_.speed_val;
+}
+
+inline const ::cppust::Option<::cppust::u16>& Command::speed_ref() const {
+    if (tag_ != _Tag::Speed) {
+        CPPUST_PANIC("requires Speed");
+    }
+    return union_.speed_val;
+}
+
+inline ::cppust::Option<::cppust::u16>& Command::speed_ref() {
+    if (tag_ != _Tag::Speed) {
+        CPPUST_PANIC("requires Speed");
+    }
+    return union_.speed_val;
+}
+
+inline const ::cppust::Option<::cppust::u16>* Command::speed_ptr() const {
+    if (tag_ != _Tag::Speed) {
+        return nullptr;
+    }
+    return &union_.speed_val;
+}
+
+inline ::cppust::Option<::cppust::u16>* Command::speed_ptr() {
+    if (tag_ != _Tag::Speed) {
+        return nullptr;
+    }
+    return &union_.speed_val;
+}
+
+inline ::cppust::Option<std::reference_wrapper<const ::cppust::Option<::cppust::u16>>> Command::as_speed() const {
+    if (tag_ != _Tag::Speed) {
+        return ::cppust::Option<std::reference_wrapper<const ::cppust::Option<::cppust::u16>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const ::cppust::Option<::cppust::u16>>>::Some(std::cref(union_.speed_val));
+}
+
+inline ::cppust::Option<std::reference_wrapper<::cppust::Option<::cppust::u16>>> Command::as_speed() {
+    if (tag_ != _Tag::Speed) {
+        return ::cppust::Option<std::reference_wrapper<::cppust::Option<::cppust::u16>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<::cppust::Option<::cppust::u16>>>::Some(std::ref(union_.speed_val));
+}
+
+inline const ::cppust::Option<::cppust::u16>& Command::expect_speed(const char* msg) const & {
+    if (tag_ != _Tag::Speed) {
+        CPPUST_PANIC(msg);
+    }
+    return union_.speed_val;
+}
+
+inline ::cppust::Option<::cppust::u16>& Command::expect_speed(const char* msg) & {
+    if (tag_ != _Tag::Speed) {
+        CPPUST_PANIC(msg);
+    }
+    return union_.speed_val;
+}
+
+inline ::cppust::Option<::cppust::u16> Command::expect_speed(const char* msg) && {
+    if (tag_ != _Tag::Speed) {
+        CPPUST_PANIC(msg);
+    }
+    return std::move(union_.speed_val);
+}
+
+inline ::cppust::Option<::cppust::Option<::cppust::u16>> Command::into_speed() && {
+    if (tag_ != _Tag::Speed) {
+        return ::cppust::Option<::cppust::Option<::cppust::u16>>::None();
+    }
+    return ::cppust::Option<::cppust::Option<::cppust::u16>>::Some(std::move(union_.speed_val));
+}
+
+inline ::cppust::Option<::cppust::Option<::cppust::u16>> Command::take_speed() & {
+    return std::move(*this).into_speed();
+}
+
+inline bool Command::is_toggle() const {
+    return tag_ == _Tag::Toggle;
+}
+
+inline const bool& Command::toggle_ref_uncheck() const {
+    assert(tag_ == _Tag::Toggle);
+    return union_.toggle_val;
+}
+
+inline bool& Command::toggle_ref_uncheck() {
+    assert(tag_ == _Tag::Toggle);
+    return union_.toggle_val;
+}
+
+inline const bool& Command::toggle_ref() const {
+    if (tag_ != _Tag::Toggle) {
+        CPPUST_PANIC("requires Toggle");
+    }
+    return union_.toggle_val;
+}
+
+inline bool& Command::toggle_ref() {
+    if (tag_ != _Tag::Toggle) {
+        CPPUST_PANIC("requires Toggle");
+    }
+    return union_.toggle_val;
+}
+
+inline const bool* Command::toggle_ptr() const {
+    if (tag_ != _Tag::Toggle) {
+        return nullptr;
+    }
+    return &union_.toggle_val;
+}
+
+inline bool* Command::toggle_ptr() {
+    if (tag_ != _Tag::Toggle) {
+        return nullptr;
+    }
+    return &union_.toggle_val;
+}
+
+inline ::cppust::Option<std::reference_wrapper<const bool>> Command::as_toggle() const {
+    if (tag_ != _Tag::Toggle) {
+        return ::cppust::Option<std::reference_wrapper<const bool>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const bool>>::Some(std::cref(union_.toggle_val));
+}
+
+inline ::cppust::Option<std::reference_wrapper<bool>> Command::as_toggle() {
+    if (tag_ != _Tag::Toggle) {
+        return ::cppust::Option<std::reference_wrapper<bool>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<bool>>::Some(std::ref(union_.toggle_val));
+}
+
+inline const bool& Command::expect_toggle(const char* msg) const & {
+    if (tag_ != _Tag::Toggle) {
+        CPPUST_PANIC(msg);
+    }
+    return union_.toggle_val;
+}
+
+inline bool& Command::expect_toggle(const char* msg) & {
+    if (tag_ != _Tag::Toggle) {
+        CPPUST_PANIC(msg);
+    }
+    return union_.toggle_val;
+}
+
+inline bool Command::expect_toggle(const char* msg) && {
+    if (tag_ != _Tag::Toggle) {
+        CPPUST_PANIC(msg);
+    }
+    return std::move(union_.toggle_val);
+}
+
+inline ::cppust::Option<bool> Command::into_toggle() && {
+    if (tag_ != _Tag::Toggle) {
+        return ::cppust::Option<bool>::None();
+    }
+    return ::cppust::Option<bool>::Some(std::move(union_.toggle_val));
+}
+
+inline ::cppust::Option<bool> Command::take_toggle() & {
+    return std::move(*this).into_toggle();
+}
+
+// private methods
+inline void Command::tagged_init_(_Tag tag, const _Union& union_val) {
+    switch (tag) {
+    case _Tag::Move:
+        new (&union_.move_val) std::tuple<::cppust::i32, ::cppust::i16>(union_val.move_val);
+        break;
+    case _Tag::Speed:
+        new (&union_.speed_val) ::cppust::Option<::cppust::u16>(union_val.speed_val);
+        break;
+    case _Tag::Toggle:
+        new (&union_.toggle_val) bool(union_val.toggle_val);
+        break;
+    default:
+        break;
+    }
+}
+
+inline void Command::tagged_init_(_Tag tag, _Union&& union_val) {
+    switch (tag) {
+    case _Tag::Move:
+        new (&union_.move_val) std::tuple<::cppust::i32, ::cppust::i16>(std::move(union_val.move_val));
+        break;
+    case _Tag::Speed:
+        new (&union_.speed_val) ::cppust::Option<::cppust::u16>(std::move(union_val.speed_val));
+        break;
+    case _Tag::Toggle:
+        new (&union_.toggle_val) bool(std::move(union_val.toggle_val));
+        break;
+    default:
+        break;
+    }
+}
+
+inline void Command::deinit_union_() {
+    switch (tag_) {
+    case _Tag::Move:
+        union_.move_val.~tuple();
+        break;
+    case _Tag::Speed:
+        union_.speed_val.~Option();
+        break;
+    case _Tag::Toggle:
+        union_.toggle_val.::cppust::bool_::~bool_();
+        break;
+    default:
+        break;
+    }
+}
+
+
+}
@@ -0,0 +1,176 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE command.hpp INSTEAD
+
+private: // type definition
+    enum class _Tag {
+        Stop,
+        Move,
+        Speed,
+        Toggle,
+        _Valueless,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+        , std::tuple<::cppust::i32, ::cppust::i16>
+        , ::cppust::Option<::cppust::u16>
+        , bool
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<std::tuple<::cppust::i32, ::cppust::i16>, ::cppust::Option<::cppust::u16>, bool>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<std::tuple<::cppust::i32, ::cppust::i16>, ::cppust::Option<::cppust::u16>, bool>();
+
+    union alignas(_kUnionAlign) _Union {
+        std::tuple<::cppust::i32, ::cppust::i16> move_val;
+        ::cppust::Option<::cppust::u16> speed_val;
+        bool toggle_val;
+        inline _Union() {}
+        inline ~_Union() {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    Command(const Command&);  // Clone
+    Command(Command&&) noexcept(_kNothrowMoveConstructible);
+    ~Command();
+
+    Command& operator=(const Command&);  // Clone
+    Command& operator=(Command&&) noexcept(_kNothrowMoveAssignable);
+
+    void swap(Command&) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable);
+    friend void swap(Command& lhs, Command& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+    bool operator==(const Command&) const;  // PartialEq
+    bool operator!=(const Command&) const;  // PartialEq
+    ::cppust::Option<::cppust::Ordering> partial_cmp(const Command&) const;  // PartialOrd
+    bool operator<(const Command&) const;  // PartialOrd
+    bool operator<=(const Command&) const;  // PartialOrd
+    bool operator>(const Command&) const;  // PartialOrd
+    bool operator>=(const Command&) const;  // PartialOrd
+#if __cplusplus > 201703L
+    std::partial_ordering operator<=>(const Command&) const;  // PartialOrd
+#endif
+    template<typename> friend struct ::cppust::debug;  // Debug
+    friend struct ::std::hash<Command>;  // Hash
+
+public: // enum constructors
+    static Command Stop();
+    static Command Move(const ::cppust::i32&, const ::cppust::i16&);
+    static Command Move(std::tuple<::cppust::i32, ::cppust::i16>&&);
+    static Command Move(::cppust::i32&&, ::cppust::i16&&);
+    static Command Speed(const ::cppust::Option<::cppust::u16>&);
+    static Command Speed(::cppust::Option<::cppust::u16>&&);
+    static Command Toggle(const bool&);
+    static Command Toggle(bool&&);
+
+public: // in-place constructors
+    template <typename... Args>
+    std::tuple<::cppust::i32, ::cppust::i16>& emplace_move(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.move_val) std::tuple<::cppust::i32, ::cppust::i16>(std::forward<Args>(args)...);
+        tag_ = _Tag::Move;
+        return union_.move_val;
+    }
+
+    template <typename... Args>
+    ::cppust::Option<::cppust::u16>& emplace_speed(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.speed_val) ::cppust::Option<::cppust::u16>(std::forward<Args>(args)...);
+        tag_ = _Tag::Speed;
+        return union_.speed_val;
+    }
+
+    template <typename... Args>
+    bool& emplace_toggle(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.toggle_val) bool(std::forward<Args>(args)...);
+        tag_ = _Tag::Toggle;
+        return union_.toggle_val;
+    }
+
+public: // value accessors
+    bool is_stop() const;
+
+    bool is_move() const;
+
+    const std::tuple<::cppust::i32, ::cppust::i16>& move_ref_uncheck() const;
+    std::tuple<::cppust::i32, ::cppust::i16>& move_ref_uncheck();
+
+    const std::tuple<::cppust::i32, ::cppust::i16>& move_ref() const;
+    std::tuple<::cppust::i32, ::cppust::i16>& move_ref();
+
+    const std::tuple<::cppust::i32, ::cppust::i16>* move_ptr() const;
+    std::tuple<::cppust::i32, ::cppust::i16>* move_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::i32, ::cppust::i16>>> as_move() const;
+    ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::i32, ::cppust::i16>>> as_move();
+
+    const std::tuple<::cppust::i32, ::cppust::i16>& expect_move(const char* msg) const &;
+    std::tuple<::cppust::i32, ::cppust::i16>& expect_move(const char* msg) &;
+    std::tuple<::cppust::i32, ::cppust::i16> expect_move(const char* msg) &&;
+
+    ::cppust::Option<std::tuple<::cppust::i32, ::cppust::i16>> into_move() &&;
+
+    ::cppust::Option<std::tuple<::cppust::i32, ::cppust::i16>> take_move() &;
+
+    bool is_speed() const;
+
+    const ::cppust::Option<::cppust::u16>& speed_ref_uncheck() const;
+    ::cppust::Option<::cppust::u16>& speed_ref_uncheck();
+
+    const ::cppust::Option<::cppust::u16>& speed_ref() const;
+    ::cppust::Option<::cppust::u16>& speed_ref();
+
+    const ::cppust::Option<::cppust::u16>* speed_ptr() const;
+    ::cppust::Option<::cppust::u16>* speed_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const ::cppust::Option<::cppust::u16>>> as_speed() const;
+    ::cppust::Option<std::reference_wrapper<::cppust::Option<::cppust::u16>>> as_speed();
+
+    const ::cppust::Option<::cppust::u16>& expect_speed(const char* msg) const &;
+    ::cppust::Option<::cppust::u16>& expect_speed(const char* msg) &;
+    ::cppust::Option<::cppust::u16> expect_speed(const char* msg) &&;
+
+    ::cppust::Option<::cppust::Option<::cppust::u16>> into_speed() &&;
+
+    ::cppust::Option<::cppust::Option<::cppust::u16>> take_speed() &;
+
+    bool is_toggle() const;
+
+    const bool& toggle_ref_uncheck() const;
+    bool& toggle_ref_uncheck();
+
+    const bool& toggle_ref() const;
+    bool& toggle_ref();
+
+    const bool* toggle_ptr() const;
+    bool* toggle_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const bool>> as_toggle() const;
+    ::cppust::Option<std::reference_wrapper<bool>> as_toggle();
+
+    const bool& expect_toggle(const char* msg) const &;
+    bool& expect_toggle(const char* msg) &;
+    bool expect_toggle(const char* msg) &&;
+
+    ::cppust::Option<bool> into_toggle() &&;
+
+    ::cppust::Option<bool> take_toggle() &;
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    Command(_Tag);
+    Command(_Tag, const _Union&);
+    Command(_Tag, _Union&&);
+
+    void tagged_init_(_Tag, const _Union&);
+    void tagged_init_(_Tag, _Union&&);
+    void deinit_union_();
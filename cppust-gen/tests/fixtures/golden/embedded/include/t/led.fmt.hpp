@@ -0,0 +1,37 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE led.hpp INSTEAD
+
+namespace cppust {
+    template <> struct debug<t::Led> { // Debug
+        template <typename Os>
+        static Os& fmt(const t::Led& self, Os& os) {
+            switch (self.tag_) {
+            case t::Led::_Tag::Off:
+                os<<"Off";
+                break;
+            case t::Led::_Tag::On:
+                os<<"On("<<::cppust::as_debug(self.union_.on_val)<<")";
+                break;
+            default:
+                os<<"<?>("<<int(self.tag_)<<")";
+                break;
+            }
+            return os;
+        }
+    };
+}
+
+namespace std {
+    template <> struct hash<t::Led> { // Hash
+        size_t operator()(const t::Led& self) const {
+            size_t seed = std::hash<int>()(int(self.tag_));
+            switch (self.tag_) {
+            case t::Led::_Tag::On:
+                return ::cppust::hash_combine(seed, ::cppust::hash_of(self.union_.on_val));
+            default:
+                return seed;
+            }
+        }
+    };
+}
+
@@ -0,0 +1,32 @@
+// THIS FILE IS GENERATED BY cppust-gen, YOU CAN CHANGE THIS FILE ON YOUR OWN
+
+#pragma once
+
+#ifndef CPPUST_NO_IOSTREAM
+#define CPPUST_NO_IOSTREAM
+#endif
+
+#include <cppust/types.hpp>
+#include <cppust/utils.hpp>
+#include <cppust/option.hpp>
+#include <cppust/fmt.hpp>
+#include <cppust/write.hpp>
+#include <cppust/hash.hpp>
+
+#include <functional>
+#include <new>
+#include <utility>
+
+namespace t {
+
+class Led {
+#include "led.inc.hpp"
+
+public:
+    // WRITE YOUR OWN CODE HERE
+};
+
+}
+
+#include "led.fmt.hpp"
+#include "led.impl.hpp"
@@ -0,0 +1,54 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE led.hpp INSTEAD
+
+#pragma once
+
+#include "t/led.hpp"
+
+namespace t {
+
+// operators
+inline bool Led::operator==(const Led& rhs) const { // equal
+    if (this == &rhs) { return true; }
+    if (tag_ != rhs.tag_) { return false; }
+    switch (tag_) {
+    case _Tag::On:
+        return union_.on_val == rhs.union_.on_val;
+    default:
+        break;
+    }
+    return true;
+}
+
+inline bool Led::operator!=(const Led& rhs) const { // not equal
+    return !(*this == rhs);
+}
+
+// accessors
+inline ::cppust::Option<std::reference_wrapper<const ::cppust::u8>> Led::as_on() const {
+    if (tag_ != _Tag::On) {
+        return ::cppust::Option<std::reference_wrapper<const ::cppust::u8>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const ::cppust::u8>>::Some(std::cref(union_.on_val));
+}
+
+inline ::cppust::Option<std::reference_wrapper<::cppust::u8>> Led::as_on() {
+    if (tag_ != _Tag::On) {
+        return ::cppust::Option<std::reference_wrapper<::cppust::u8>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<::cppust::u8>>::Some(std::ref(union_.on_val));
+}
+
+inline ::cppust::Option<::cppust::u8> Led::into_on() && {
+    if (tag_ != _Tag::On) {
+        return ::cppust::Option<::cppust::u8>::None();
+    }
+    return ::cppust::Option<::cppust::u8>::Some(std::move(union_.on_val));
+}
+
+inline ::cppust::Option<::cppust::u8> Led::take_on() & {
+    return std::move(*this).into_on();
+}
+
+
+}
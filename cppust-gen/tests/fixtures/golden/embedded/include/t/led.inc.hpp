@@ -0,0 +1,90 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE led.hpp INSTEAD
+
+private: // type definition
+    enum class _Tag {
+        Off,
+        On,
+        _Valueless,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+        , ::cppust::u8
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<::cppust::u8>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<::cppust::u8>();
+
+    union alignas(_kUnionAlign) _Union {
+        char _unit;
+        ::cppust::u8 on_val;
+        constexpr _Union(): _unit() {}
+        constexpr _Union(std::integral_constant<_Tag, _Tag::On>, const ::cppust::u8& value): on_val(value) {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    Led(const Led&) = default;  // Clone
+    Led(Led&&) = default;
+    ~Led() = default;
+
+    Led& operator=(const Led&) = default;  // Clone
+    Led& operator=(Led&&) = default;
+
+    void swap(Led& other) noexcept { std::swap(*this, other); }
+    friend void swap(Led& lhs, Led& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    constexpr bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+    bool operator==(const Led&) const;  // PartialEq
+    bool operator!=(const Led&) const;  // PartialEq
+    template<typename> friend struct ::cppust::debug;  // Debug
+    friend struct ::std::hash<Led>;  // Hash
+
+public: // enum constructors
+    static constexpr Led Off() { return Led(_Tag::Off); }
+    static constexpr Led On(const ::cppust::u8& v0) { return Led(_Tag::On, _Union(std::integral_constant<_Tag, _Tag::On>(), v0)); }
+    static constexpr Led On(::cppust::u8&& value) { return Led(_Tag::On, _Union(std::integral_constant<_Tag, _Tag::On>(), value)); }
+
+public: // in-place constructors
+    template <typename... Args>
+    ::cppust::u8& emplace_on(Args&&... args) {
+        tag_ = _Tag::_Valueless;
+        new (&union_.on_val) ::cppust::u8(std::forward<Args>(args)...);
+        tag_ = _Tag::On;
+        return union_.on_val;
+    }
+
+public: // value accessors
+    constexpr bool is_off() const { return tag_ == _Tag::Off; }
+
+    constexpr bool is_on() const { return tag_ == _Tag::On; }
+
+    constexpr const ::cppust::u8& on_ref_uncheck() const { return assert(tag_ == _Tag::On), union_.on_val; }
+    ::cppust::u8& on_ref_uncheck() { return assert(tag_ == _Tag::On), union_.on_val; }
+
+    constexpr const ::cppust::u8& on_ref() const { return tag_ == _Tag::On ? union_.on_val : (CPPUST_PANIC("requires On"), union_.on_val); }
+    ::cppust::u8& on_ref() { return tag_ == _Tag::On ? union_.on_val : (CPPUST_PANIC("requires On"), union_.on_val); }
+
+    constexpr const ::cppust::u8* on_ptr() const { return tag_ == _Tag::On ? &union_.on_val : nullptr; }
+    ::cppust::u8* on_ptr() { return tag_ == _Tag::On ? &union_.on_val : nullptr; }
+
+    ::cppust::Option<std::reference_wrapper<const ::cppust::u8>> as_on() const;
+    ::cppust::Option<std::reference_wrapper<::cppust::u8>> as_on();
+
+    constexpr const ::cppust::u8& expect_on(const char* msg) const & { return tag_ == _Tag::On ? union_.on_val : (CPPUST_PANIC(msg), union_.on_val); }
+    ::cppust::u8& expect_on(const char* msg) & { return tag_ == _Tag::On ? union_.on_val : (CPPUST_PANIC(msg), union_.on_val); }
+    ::cppust::u8 expect_on(const char* msg) && { return tag_ == _Tag::On ? union_.on_val : (CPPUST_PANIC(msg), union_.on_val); }
+
+    ::cppust::Option<::cppust::u8> into_on() &&;
+
+    ::cppust::Option<::cppust::u8> take_on() &;
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    constexpr Led(_Tag tag): union_(), tag_(tag) {}
+    constexpr Led(_Tag tag, const _Union& union_val): union_(union_val), tag_(tag) {}
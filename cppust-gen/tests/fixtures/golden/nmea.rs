@@ -0,0 +1,44 @@
+//! NMEA sentences, covering the payloads, derives and items cppust-gen generates
+
+/// Longest sentence, including `$` and the line ending
+pub const MAX_SENTENCE_LEN: usize = 82;
+const SEPARATOR: char = ',';
+
+type Res<T> = Result<T, Fault>;
+type Satellites = Vec<u8>;
+
+/// A decoded sentence
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+enum Sentence {
+    /// Nothing received yet
+    Empty,
+    /// Latitude and longitude of the fix
+    Gga(f64, f64),
+    Gsv(Satellites),
+    Talker(String),
+    Checked(Res<Option<i32>>),
+    #[cppust(rename = "Raw")]
+    Unknown(Vec<String>),
+}
+
+impl Sentence {
+    /// Whether the sentence carries a position
+    pub fn has_fix(&self) -> bool {
+        matches!(self, Sentence::Gga(..))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+enum Fault {
+    #[default]
+    Timeout = 1,
+    Checksum,
+    Overflow = 8,
+}
+
+/// Neither copyable nor comparable
+enum Upload {
+    Chunk(String),
+    Parts(Vec<Sentence>, Option<Fault>),
+    Finished,
+}
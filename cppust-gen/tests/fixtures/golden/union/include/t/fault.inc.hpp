@@ -0,0 +1,71 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE fault.hpp INSTEAD
+
+private: // type definition
+    enum class _Tag {
+        Timeout = 1,
+        Checksum,
+        Overflow = 8,
+        _Valueless = 9,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<>();
+
+    union alignas(_kUnionAlign) _Union {
+        char _unit;
+        constexpr _Union(): _unit() {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    constexpr Fault(): Fault(_Tag::Timeout) {}  // Default
+    Fault(const Fault&) = default;  // Clone
+    Fault(Fault&&) = default;
+    ~Fault() = default;
+
+    Fault& operator=(const Fault&) = default;  // Clone
+    Fault& operator=(Fault&&) = default;
+
+    void swap(Fault& other) noexcept { std::swap(*this, other); }
+    friend void swap(Fault& lhs, Fault& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    constexpr bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+    bool operator==(const Fault&) const;  // PartialEq
+    bool operator!=(const Fault&) const;  // PartialEq
+    ::cppust::Option<::cppust::Ordering> partial_cmp(const Fault&) const;  // PartialOrd
+    bool operator<(const Fault&) const;  // PartialOrd
+    bool operator<=(const Fault&) const;  // PartialOrd
+    bool operator>(const Fault&) const;  // PartialOrd
+    bool operator>=(const Fault&) const;  // PartialOrd
+#if __cplusplus > 201703L
+    std::strong_ordering operator<=>(const Fault&) const;  // PartialOrd
+#endif
+    template<typename> friend struct ::cppust::debug;  // Debug
+    friend struct ::std::hash<Fault>;  // Hash
+
+public: // enum constructors
+    static constexpr Fault Timeout() { return Fault(_Tag::Timeout); }
+    static constexpr Fault Checksum() { return Fault(_Tag::Checksum); }
+    static constexpr Fault Overflow() { return Fault(_Tag::Overflow); }
+
+public: // in-place constructors
+public: // value accessors
+    constexpr bool is_timeout() const { return tag_ == _Tag::Timeout; }
+
+    constexpr bool is_checksum() const { return tag_ == _Tag::Checksum; }
+
+    constexpr bool is_overflow() const { return tag_ == _Tag::Overflow; }
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    constexpr Fault(_Tag tag): union_(), tag_(tag) {}
+    constexpr Fault(_Tag tag, const _Union& union_val): union_(union_val), tag_(tag) {}
@@ -0,0 +1,14 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+
+#pragma once
+
+#include <cppust/types.hpp>
+
+namespace t {
+
+/// @brief Longest sentence, including `$` and the line ending
+constexpr ::cppust::usize MAX_SENTENCE_LEN = 82;
+
+constexpr char32_t SEPARATOR = U',';
+
+}
@@ -0,0 +1,18 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+
+#pragma once
+
+#include <cppust/types.hpp>
+#include <cppust/result.hpp>
+#include <cppust/vec.hpp>
+
+namespace t { class Fault; }
+
+namespace t {
+
+template <typename T>
+using Res = ::cppust::Result<T, Fault>;
+
+using Satellites = std::vector<::cppust::u8>;
+
+}
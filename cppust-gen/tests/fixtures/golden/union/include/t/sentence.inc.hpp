@@ -0,0 +1,275 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE sentence.hpp INSTEAD
+
+private: // type definition
+    enum class _Tag {
+        /// Nothing received yet
+        Empty,
+        /// Latitude and longitude of the fix
+        Gga,
+        Gsv,
+        Talker,
+        Checked,
+        Raw,
+        _Valueless,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+        , std::tuple<::cppust::f64, ::cppust::f64>
+        , Satellites
+        , std::string
+        , Res<::cppust::Option<::cppust::i32>>
+        , std::vector<std::string>
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<std::tuple<::cppust::f64, ::cppust::f64>, Satellites, std::string, Res<::cppust::Option<::cppust::i32>>, std::vector<std::string>>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<std::tuple<::cppust::f64, ::cppust::f64>, Satellites, std::string, Res<::cppust::Option<::cppust::i32>>, std::vector<std::string>>();
+
+    union alignas(_kUnionAlign) _Union {
+        std::tuple<::cppust::f64, ::cppust::f64> gga_val;
+        Satellites gsv_val;
+        std::string talker_val;
+        Res<::cppust::Option<::cppust::i32>> checked_val;
+        std::vector<std::string> raw_val;
+        inline _Union() {}
+        inline ~_Union() {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    Sentence(const Sentence&);  // Clone
+    Sentence(Sentence&&) noexcept(_kNothrowMoveConstructible);
+    ~Sentence();
+
+    Sentence& operator=(const Sentence&);  // Clone
+    Sentence& operator=(Sentence&&) noexcept(_kNothrowMoveAssignable);
+
+    void swap(Sentence&) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable);
+    friend void swap(Sentence& lhs, Sentence& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+    bool operator==(const Sentence&) const;  // PartialEq
+    bool operator!=(const Sentence&) const;  // PartialEq
+    ::cppust::Option<::cppust::Ordering> partial_cmp(const Sentence&) const;  // PartialOrd
+    bool operator<(const Sentence&) const;  // PartialOrd
+    bool operator<=(const Sentence&) const;  // PartialOrd
+    bool operator>(const Sentence&) const;  // PartialOrd
+    bool operator>=(const Sentence&) const;  // PartialOrd
+#if __cplusplus > 201703L
+    std::partial_ordering operator<=>(const Sentence&) const;  // PartialOrd
+#endif
+    template<typename> friend struct ::cppust::debug;  // Debug
+
+public: // enum constructors
+    /// @brief Nothing received yet
+    static Sentence Empty();
+    /// @brief Latitude and longitude of the fix
+    static Sentence Gga(const ::cppust::f64&, const ::cppust::f64&);
+    static Sentence Gga(std::tuple<::cppust::f64, ::cppust::f64>&&);
+    static Sentence Gga(::cppust::f64&&, ::cppust::f64&&);
+    static Sentence Gsv(const Satellites&);
+    static Sentence Gsv(Satellites&&);
+    static Sentence Talker(const std::string&);
+    static Sentence Talker(std::string&&);
+    static Sentence Checked(const Res<::cppust::Option<::cppust::i32>>&);
+    static Sentence Checked(Res<::cppust::Option<::cppust::i32>>&&);
+    static Sentence Raw(const std::vector<std::string>&);
+    static Sentence Raw(std::vector<std::string>&&);
+
+public: // in-place constructors
+    /// @brief Replaces the value with a `Gga` constructed from `args`
+    /// @details Latitude and longitude of the fix
+    template <typename... Args>
+    std::tuple<::cppust::f64, ::cppust::f64>& emplace_gga(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.gga_val) std::tuple<::cppust::f64, ::cppust::f64>(std::forward<Args>(args)...);
+        tag_ = _Tag::Gga;
+        return union_.gga_val;
+    }
+
+    template <typename... Args>
+    Satellites& emplace_gsv(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.gsv_val) Satellites(std::forward<Args>(args)...);
+        tag_ = _Tag::Gsv;
+        return union_.gsv_val;
+    }
+
+    template <typename... Args>
+    std::string& emplace_talker(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.talker_val) std::string(std::forward<Args>(args)...);
+        tag_ = _Tag::Talker;
+        return union_.talker_val;
+    }
+
+    template <typename... Args>
+    Res<::cppust::Option<::cppust::i32>>& emplace_checked(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.checked_val) Res<::cppust::Option<::cppust::i32>>(std::forward<Args>(args)...);
+        tag_ = _Tag::Checked;
+        return union_.checked_val;
+    }
+
+    template <typename... Args>
+    std::vector<std::string>& emplace_raw(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.raw_val) std::vector<std::string>(std::forward<Args>(args)...);
+        tag_ = _Tag::Raw;
+        return union_.raw_val;
+    }
+
+public: // value accessors
+    /// @brief Whether the value is `Empty`
+    /// @details Nothing received yet
+    bool is_empty() const;
+
+    /// @brief Whether the value is `Gga`
+    /// @details Latitude and longitude of the fix
+    bool is_gga() const;
+
+    /// @brief Payload of `Gga`, which must be the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& gga_ref_uncheck() const;
+    std::tuple<::cppust::f64, ::cppust::f64>& gga_ref_uncheck();
+
+    /// @brief Payload of `Gga`, panics unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& gga_ref() const;
+    std::tuple<::cppust::f64, ::cppust::f64>& gga_ref();
+
+    /// @brief Payload of `Gga`, or `nullptr` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>* gga_ptr() const;
+    std::tuple<::cppust::f64, ::cppust::f64>* gga_ptr();
+
+    /// @brief Payload of `Gga`, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>> as_gga() const;
+    ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>> as_gga();
+
+    /// @brief Payload of `Gga`, panics with `msg` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& expect_gga(const char* msg) const &;
+    std::tuple<::cppust::f64, ::cppust::f64>& expect_gga(const char* msg) &;
+    std::tuple<::cppust::f64, ::cppust::f64> expect_gga(const char* msg) &&;
+
+    /// @brief Moves out the payload of `Gga`, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> into_gga() &&;
+
+    /// @brief Moves out the payload of `Gga`, leaving it moved-from, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> take_gga() &;
+
+    bool is_gsv() const;
+
+    const Satellites& gsv_ref_uncheck() const;
+    Satellites& gsv_ref_uncheck();
+
+    const Satellites& gsv_ref() const;
+    Satellites& gsv_ref();
+
+    const Satellites* gsv_ptr() const;
+    Satellites* gsv_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const Satellites>> as_gsv() const;
+    ::cppust::Option<std::reference_wrapper<Satellites>> as_gsv();
+
+    const Satellites& expect_gsv(const char* msg) const &;
+    Satellites& expect_gsv(const char* msg) &;
+    Satellites expect_gsv(const char* msg) &&;
+
+    ::cppust::Option<Satellites> into_gsv() &&;
+
+    ::cppust::Option<Satellites> take_gsv() &;
+
+    bool is_talker() const;
+
+    const std::string& talker_ref_uncheck() const;
+    std::string& talker_ref_uncheck();
+
+    const std::string& talker_ref() const;
+    std::string& talker_ref();
+
+    const std::string* talker_ptr() const;
+    std::string* talker_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::string>> as_talker() const;
+    ::cppust::Option<std::reference_wrapper<std::string>> as_talker();
+
+    const std::string& expect_talker(const char* msg) const &;
+    std::string& expect_talker(const char* msg) &;
+    std::string expect_talker(const char* msg) &&;
+
+    ::cppust::Option<std::string> into_talker() &&;
+
+    ::cppust::Option<std::string> take_talker() &;
+
+    bool is_checked() const;
+
+    const Res<::cppust::Option<::cppust::i32>>& checked_ref_uncheck() const;
+    Res<::cppust::Option<::cppust::i32>>& checked_ref_uncheck();
+
+    const Res<::cppust::Option<::cppust::i32>>& checked_ref() const;
+    Res<::cppust::Option<::cppust::i32>>& checked_ref();
+
+    const Res<::cppust::Option<::cppust::i32>>* checked_ptr() const;
+    Res<::cppust::Option<::cppust::i32>>* checked_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>> as_checked() const;
+    ::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>> as_checked();
+
+    const Res<::cppust::Option<::cppust::i32>>& expect_checked(const char* msg) const &;
+    Res<::cppust::Option<::cppust::i32>>& expect_checked(const char* msg) &;
+    Res<::cppust::Option<::cppust::i32>> expect_checked(const char* msg) &&;
+
+    ::cppust::Option<Res<::cppust::Option<::cppust::i32>>> into_checked() &&;
+
+    ::cppust::Option<Res<::cppust::Option<::cppust::i32>>> take_checked() &;
+
+    bool is_raw() const;
+
+    const std::vector<std::string>& raw_ref_uncheck() const;
+    std::vector<std::string>& raw_ref_uncheck();
+
+    const std::vector<std::string>& raw_ref() const;
+    std::vector<std::string>& raw_ref();
+
+    const std::vector<std::string>* raw_ptr() const;
+    std::vector<std::string>* raw_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::vector<std::string>>> as_raw() const;
+    ::cppust::Option<std::reference_wrapper<std::vector<std::string>>> as_raw();
+
+    const std::vector<std::string>& expect_raw(const char* msg) const &;
+    std::vector<std::string>& expect_raw(const char* msg) &;
+    std::vector<std::string> expect_raw(const char* msg) &&;
+
+    ::cppust::Option<std::vector<std::string>> into_raw() &&;
+
+    ::cppust::Option<std::vector<std::string>> take_raw() &;
+
+public: // methods
+    /// @brief Whether the sentence carries a position
+    bool has_fix() const;
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    Sentence(_Tag);
+    Sentence(_Tag, const _Union&);
+    Sentence(_Tag, _Union&&);
+
+    void tagged_init_(_Tag, const _Union&);
+    void tagged_init_(_Tag, _Union&&);
+    void deinit_union_();
@@ -0,0 +1,125 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE upload.hpp INSTEAD
+
+private: // type definition
+    enum class _Tag {
+        Chunk,
+        Parts,
+        Finished,
+        _Valueless,
+    };
+
+    static constexpr const size_t _kUnionAlign = ::cppust::utils::align_of_union_value<
+        ::cppust::u8
+        , std::string
+        , std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>
+    >();
+
+    static constexpr const bool _kNothrowMoveConstructible = ::cppust::utils::is_nothrow_move_constructible_union<std::string, std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>();
+    static constexpr const bool _kNothrowMoveAssignable = ::cppust::utils::is_nothrow_move_assignable_union<std::string, std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>();
+
+    union alignas(_kUnionAlign) _Union {
+        std::string chunk_val;
+        std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>> parts_val;
+        inline _Union() {}
+        inline ~_Union() {}
+    };
+
+
+public: // ctors, dtors, opeartors
+    Upload(const Upload&) = delete;
+    Upload(Upload&&) noexcept(_kNothrowMoveConstructible);
+    ~Upload();
+
+    Upload& operator=(const Upload&) = delete;
+    Upload& operator=(Upload&&) noexcept(_kNothrowMoveAssignable);
+
+    void swap(Upload&) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable);
+    friend void swap(Upload& lhs, Upload& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    bool valueless_by_exception() const { return tag_ == _Tag::_Valueless; }
+
+public: // enum constructors
+    static Upload Chunk(const std::string&);
+    static Upload Chunk(std::string&&);
+    static Upload Parts(const std::vector<Sentence>&, const ::cppust::Option<Fault>&);
+    static Upload Parts(std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>&&);
+    static Upload Parts(std::vector<Sentence>&&, ::cppust::Option<Fault>&&);
+    static Upload Finished();
+
+public: // in-place constructors
+    template <typename... Args>
+    std::string& emplace_chunk(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.chunk_val) std::string(std::forward<Args>(args)...);
+        tag_ = _Tag::Chunk;
+        return union_.chunk_val;
+    }
+
+    template <typename... Args>
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& emplace_parts(Args&&... args) {
+        deinit_union_();
+        tag_ = _Tag::_Valueless;
+        new (&union_.parts_val) std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>(std::forward<Args>(args)...);
+        tag_ = _Tag::Parts;
+        return union_.parts_val;
+    }
+
+public: // value accessors
+    bool is_chunk() const;
+
+    const std::string& chunk_ref_uncheck() const;
+    std::string& chunk_ref_uncheck();
+
+    const std::string& chunk_ref() const;
+    std::string& chunk_ref();
+
+    const std::string* chunk_ptr() const;
+    std::string* chunk_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::string>> as_chunk() const;
+    ::cppust::Option<std::reference_wrapper<std::string>> as_chunk();
+
+    const std::string& expect_chunk(const char* msg) const &;
+    std::string& expect_chunk(const char* msg) &;
+    std::string expect_chunk(const char* msg) &&;
+
+    ::cppust::Option<std::string> into_chunk() &&;
+
+    ::cppust::Option<std::string> take_chunk() &;
+
+    bool is_parts() const;
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref_uncheck() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref_uncheck();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* parts_ptr() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* parts_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> as_parts() const;
+    ::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> as_parts();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& expect_parts(const char* msg) const &;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& expect_parts(const char* msg) &;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>> expect_parts(const char* msg) &&;
+
+    ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> into_parts() &&;
+
+    ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> take_parts() &;
+
+    bool is_finished() const;
+
+private:
+    _Union union_;
+    _Tag tag_;
+
+    Upload(_Tag);
+    Upload(_Tag, _Union&&);
+
+    void tagged_init_(_Tag, _Union&&);
+    void deinit_union_();
@@ -0,0 +1,62 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE fault.cpp INSTEAD
+
+#include "t/fault.hpp"
+
+namespace t {
+
+// operators
+bool Fault::operator==(const Fault& rhs) const { // equal
+    if (this == &rhs) { return true; }
+    if (tag_ != rhs.tag_) { return false; }
+    switch (tag_) {
+    default:
+        break;
+    }
+    return true;
+}
+
+bool Fault::operator!=(const Fault& rhs) const { // not equal
+    return !(*this == rhs);
+}
+
+::cppust::Option<::cppust::Ordering> Fault::partial_cmp(const Fault& rhs) const { // partial compare
+    if (tag_ != rhs.tag_) {
+        return ::cppust::Option<::cppust::Ordering>::Some(tag_ < rhs.tag_ ? ::cppust::Ordering::Less : ::cppust::Ordering::Greater);
+    }
+    switch (tag_) {
+    default:
+        break;
+    }
+    return ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+bool Fault::operator<(const Fault& rhs) const { // less than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less);
+}
+
+bool Fault::operator<=(const Fault& rhs) const { // less or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+bool Fault::operator>(const Fault& rhs) const { // greater than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater);
+}
+
+bool Fault::operator>=(const Fault& rhs) const { // greater or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+#if __cplusplus > 201703L
+std::strong_ordering Fault::operator<=>(const Fault& rhs) const { // three-way compare
+    return ::cppust::to_strong_ordering(partial_cmp(rhs));
+}
+#endif
+
+// accessors
+
+}
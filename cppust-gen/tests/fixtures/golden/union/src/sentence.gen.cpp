@@ -0,0 +1,745 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE sentence.cpp INSTEAD
+
+#include "t/sentence.hpp"
+
+namespace t {
+
+// public constructors
+Sentence::Sentence(const Sentence& rhs): Sentence(rhs.tag_, rhs.union_) { } // copy constructor
+Sentence::Sentence(Sentence&& rhs) noexcept(_kNothrowMoveConstructible): Sentence(rhs.tag_, std::move(rhs.union_)) { } // move constructor
+
+// private constructors
+Sentence::Sentence(_Tag tag): tag_(tag) { }
+
+Sentence::Sentence(_Tag tag, const _Union& union_val): tag_(tag) {
+    tagged_init_(tag, union_val);
+}
+
+Sentence::Sentence(_Tag tag, _Union&& union_val): tag_(tag) {
+    tagged_init_(tag, std::move(union_val));
+}
+
+
+// destructor
+Sentence::~Sentence() {
+    deinit_union_();
+}
+
+// assign operators
+Sentence& Sentence::operator=(const Sentence& rhs) { // assign
+    if (this == &rhs) { return *this; }
+
+    // copied aside first, so this value is kept when copying a payload throws
+    Sentence copy(rhs);
+    return *this = std::move(copy);
+}
+
+Sentence& Sentence::operator=(Sentence&& rhs) noexcept(_kNothrowMoveAssignable) { // move
+    if (this == &rhs) { return *this; }
+
+    if (tag_ == rhs.tag_) {
+        switch (tag_) {
+        case _Tag::Gga:
+            union_.gga_val = std::move(rhs.union_.gga_val);
+            break;
+        case _Tag::Gsv:
+            union_.gsv_val = std::move(rhs.union_.gsv_val);
+            break;
+        case _Tag::Talker:
+            union_.talker_val = std::move(rhs.union_.talker_val);
+            break;
+        case _Tag::Checked:
+            union_.checked_val = std::move(rhs.union_.checked_val);
+            break;
+        case _Tag::Raw:
+            union_.raw_val = std::move(rhs.union_.raw_val);
+            break;
+        default:
+            break;
+        }
+    } else {
+        deinit_union_();
+        // valueless until the payload is moved in, which may throw
+        tag_ = _Tag::_Valueless;
+        tagged_init_(rhs.tag_, std::move(rhs.union_));
+        tag_ = rhs.tag_;
+    }
+    return *this;
+}
+
+void Sentence::swap(Sentence& other) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable) {
+    if (this == &other) { return; }
+
+    Sentence moved(std::move(other));
+    other = std::move(*this);
+    *this = std::move(moved);
+}
+
+// operators
+bool Sentence::operator==(const Sentence& rhs) const { // equal
+    if (tag_ != rhs.tag_) { return false; }
+    switch (tag_) {
+    case _Tag::Gga:
+        return union_.gga_val == rhs.union_.gga_val;
+    case _Tag::Gsv:
+        return union_.gsv_val == rhs.union_.gsv_val;
+    case _Tag::Talker:
+        return union_.talker_val == rhs.union_.talker_val;
+    case _Tag::Checked:
+        return union_.checked_val == rhs.union_.checked_val;
+    case _Tag::Raw:
+        return union_.raw_val == rhs.union_.raw_val;
+    default:
+        break;
+    }
+    return true;
+}
+
+bool Sentence::operator!=(const Sentence& rhs) const { // not equal
+    return !(*this == rhs);
+}
+
+::cppust::Option<::cppust::Ordering> Sentence::partial_cmp(const Sentence& rhs) const { // partial compare
+    if (tag_ != rhs.tag_) {
+        return ::cppust::Option<::cppust::Ordering>::Some(tag_ < rhs.tag_ ? ::cppust::Ordering::Less : ::cppust::Ordering::Greater);
+    }
+    switch (tag_) {
+    case _Tag::Gga:
+        return ::cppust::partial_cmp(union_.gga_val, rhs.union_.gga_val);
+    case _Tag::Gsv:
+        return ::cppust::partial_cmp(union_.gsv_val, rhs.union_.gsv_val);
+    case _Tag::Talker:
+        return ::cppust::partial_cmp(union_.talker_val, rhs.union_.talker_val);
+    case _Tag::Checked:
+        return ::cppust::partial_cmp(union_.checked_val, rhs.union_.checked_val);
+    case _Tag::Raw:
+        return ::cppust::partial_cmp(union_.raw_val, rhs.union_.raw_val);
+    default:
+        break;
+    }
+    return ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+bool Sentence::operator<(const Sentence& rhs) const { // less than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less);
+}
+
+bool Sentence::operator<=(const Sentence& rhs) const { // less or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+bool Sentence::operator>(const Sentence& rhs) const { // greater than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater);
+}
+
+bool Sentence::operator>=(const Sentence& rhs) const { // greater or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+#if __cplusplus > 201703L
+std::partial_ordering Sentence::operator<=>(const Sentence& rhs) const { // three-way compare
+    return ::cppust::to_partial_ordering(partial_cmp(rhs));
+}
+#endif
+
+// enum constructors
+Sentence Sentence::Empty() {
+    return Sentence(_Tag::Empty);
+}
+
+Sentence Sentence::Gga(const ::cppust::f64& v0, const ::cppust::f64& v1) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.gga_val) std::tuple<::cppust::f64, ::cppust::f64>(v0, v1);
+    output.tag_ = _Tag::Gga;
+    return output;
+}
+
+Sentence Sentence::Gga(std::tuple<::cppust::f64, ::cppust::f64>&& value) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.gga_val) std::tuple<::cppust::f64, ::cppust::f64>(std::move(value));
+    output.tag_ = _Tag::Gga;
+    return output;
+}
+
+Sentence Sentence::Gga(::cppust::f64&& v0, ::cppust::f64&& v1) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.gga_val) std::tuple<::cppust::f64, ::cppust::f64>(std::move(v0), std::move(v1));
+    output.tag_ = _Tag::Gga;
+    return output;
+}
+
+Sentence Sentence::Gsv(const Satellites& v0) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.gsv_val) Satellites(v0);
+    output.tag_ = _Tag::Gsv;
+    return output;
+}
+
+Sentence Sentence::Gsv(Satellites&& value) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.gsv_val) Satellites(std::move(value));
+    output.tag_ = _Tag::Gsv;
+    return output;
+}
+
+Sentence Sentence::Talker(const std::string& v0) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.talker_val) std::string(v0);
+    output.tag_ = _Tag::Talker;
+    return output;
+}
+
+Sentence Sentence::Talker(std::string&& value) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.talker_val) std::string(std::move(value));
+    output.tag_ = _Tag::Talker;
+    return output;
+}
+
+Sentence Sentence::Checked(const Res<::cppust::Option<::cppust::i32>>& v0) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.checked_val) Res<::cppust::Option<::cppust::i32>>(v0);
+    output.tag_ = _Tag::Checked;
+    return output;
+}
+
+Sentence Sentence::Checked(Res<::cppust::Option<::cppust::i32>>&& value) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.checked_val) Res<::cppust::Option<::cppust::i32>>(std::move(value));
+    output.tag_ = _Tag::Checked;
+    return output;
+}
+
+Sentence Sentence::Raw(const std::vector<std::string>& v0) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.raw_val) std::vector<std::string>(v0);
+    output.tag_ = _Tag::Raw;
+    return output;
+}
+
+Sentence Sentence::Raw(std::vector<std::string>&& value) {
+    Sentence output(_Tag::_Valueless);
+    new (&output.union_.raw_val) std::vector<std::string>(std::move(value));
+    output.tag_ = _Tag::Raw;
+    return output;
+}
+
+// accessors
+bool Sentence::is_empty() const {
+    return tag_ == _Tag::Empty;
+}
+
+bool Sentence::is_gga() const {
+    return tag_ == _Tag::Gga;
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref_uncheck() const {
+    assert(tag_ == _Tag::Gga);
+    return union_.gga_val;
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref_uncheck() {
+    assert(tag_ == _Tag::Gga);
+    return union_.gga_val;
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref() const {
+    if (tag_ != _Tag::Gga) {
+        throw std::runtime_error("requires Gga");
+    }
+    return union_.gga_val;
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref() {
+    if (tag_ != _Tag::Gga) {
+        throw std::runtime_error("requires Gga");
+    }
+    return union_.gga_val;
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>* Sentence::gga_ptr() const {
+    if (tag_ != _Tag::Gga) {
+        return nullptr;
+    }
+    return &union_.gga_val;
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>* Sentence::gga_ptr() {
+    if (tag_ != _Tag::Gga) {
+        return nullptr;
+    }
+    return &union_.gga_val;
+}
+
+::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>> Sentence::as_gga() const {
+    if (tag_ != _Tag::Gga) {
+        return ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>>::Some(std::cref(union_.gga_val));
+}
+
+::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>> Sentence::as_gga() {
+    if (tag_ != _Tag::Gga) {
+        return ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>>::Some(std::ref(union_.gga_val));
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>& Sentence::expect_gga(const char* msg) const & {
+    if (tag_ != _Tag::Gga) {
+        throw std::runtime_error(msg);
+    }
+    return union_.gga_val;
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>& Sentence::expect_gga(const char* msg) & {
+    if (tag_ != _Tag::Gga) {
+        throw std::runtime_error(msg);
+    }
+    return union_.gga_val;
+}
+
+std::tuple<::cppust::f64, ::cppust::f64> Sentence::expect_gga(const char* msg) && {
+    if (tag_ != _Tag::Gga) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(union_.gga_val);
+}
+
+::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> Sentence::into_gga() && {
+    if (tag_ != _Tag::Gga) {
+        return ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>>::None();
+    }
+    return ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>>::Some(std::move(union_.gga_val));
+}
+
+::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> Sentence::take_gga() & {
+    return std::move(*this).into_gga();
+}
+
+bool Sentence::is_gsv() const {
+    return tag_ == _Tag::Gsv;
+}
+
+const Satellites& Sentence::gsv_ref_uncheck() const {
+    assert(tag_ == _Tag::Gsv);
+    return union_.gsv_val;
+}
+
+Satellites& Sentence::gsv_ref_uncheck() {
+    assert(tag_ == _Tag::Gsv);
+    return union_.gsv_val;
+}
+
+const Satellites& Sentence::gsv_ref() const {
+    if (tag_ != _Tag::Gsv) {
+        throw std::runtime_error("requires Gsv");
+    }
+    return union_.gsv_val;
+}
+
+Satellites& Sentence::gsv_ref() {
+    if (tag_ != _Tag::Gsv) {
+        throw std::runtime_error("requires Gsv");
+    }
+    return union_.gsv_val;
+}
+
+const Satellites* Sentence::gsv_ptr() const {
+    if (tag_ != _Tag::Gsv) {
+        return nullptr;
+    }
+    return &union_.gsv_val;
+}
+
+Satellites* Sentence::gsv_ptr() {
+    if (tag_ != _Tag::Gsv) {
+        return nullptr;
+    }
+    return &union_.gsv_val;
+}
+
+::cppust::Option<std::reference_wrapper<const Satellites>> Sentence::as_gsv() const {
+    if (tag_ != _Tag::Gsv) {
+        return ::cppust::Option<std::reference_wrapper<const Satellites>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const Satellites>>::Some(std::cref(union_.gsv_val));
+}
+
+::cppust::Option<std::reference_wrapper<Satellites>> Sentence::as_gsv() {
+    if (tag_ != _Tag::Gsv) {
+        return ::cppust::Option<std::reference_wrapper<Satellites>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<Satellites>>::Some(std::ref(union_.gsv_val));
+}
+
+const Satellites& Sentence::expect_gsv(const char* msg) const & {
+    if (tag_ != _Tag::Gsv) {
+        throw std::runtime_error(msg);
+    }
+    return union_.gsv_val;
+}
+
+Satellites& Sentence::expect_gsv(const char* msg) & {
+    if (tag_ != _Tag::Gsv) {
+        throw std::runtime_error(msg);
+    }
+    return union_.gsv_val;
+}
+
+Satellites Sentence::expect_gsv(const char* msg) && {
+    if (tag_ != _Tag::Gsv) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(union_.gsv_val);
+}
+
+::cppust::Option<Satellites> Sentence::into_gsv() && {
+    if (tag_ != _Tag::Gsv) {
+        return ::cppust::Option<Satellites>::None();
+    }
+    return ::cppust::Option<Satellites>::Some(std::move(union_.gsv_val));
+}
+
+::cppust::Option<Satellites> Sentence::take_gsv() & {
+    return std::move(*this).into_gsv();
+}
+
+bool Sentence::is_talker() const {
+    return tag_ == _Tag::Talker;
+}
+
+const std::string& Sentence::talker_ref_uncheck() const {
+    assert(tag_ == _Tag::Talker);
+    return union_.talker_val;
+}
+
+std::string& Sentence::talker_ref_uncheck() {
+    assert(tag_ == _Tag::Talker);
+    return union_.talker_val;
+}
+
+const std::string& Sentence::talker_ref() const {
+    if (tag_ != _Tag::Talker) {
+        throw std::runtime_error("requires Talker");
+    }
+    return union_.talker_val;
+}
+
+std::string& Sentence::talker_ref() {
+    if (tag_ != _Tag::Talker) {
+        throw std::runtime_error("requires Talker");
+    }
+    return union_.talker_val;
+}
+
+const std::string* Sentence::talker_ptr() const {
+    if (tag_ != _Tag::Talker) {
+        return nullptr;
+    }
+    return &union_.talker_val;
+}
+
+std::string* Sentence::talker_ptr() {
+    if (tag_ != _Tag::Talker) {
+        return nullptr;
+    }
+    return &union_.talker_val;
+}
+
+::cppust::Option<std::reference_wrapper<const std::string>> Sentence::as_talker() const {
+    if (tag_ != _Tag::Talker) {
+        return ::cppust::Option<std::reference_wrapper<const std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::string>>::Some(std::cref(union_.talker_val));
+}
+
+::cppust::Option<std::reference_wrapper<std::string>> Sentence::as_talker() {
+    if (tag_ != _Tag::Talker) {
+        return ::cppust::Option<std::reference_wrapper<std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::string>>::Some(std::ref(union_.talker_val));
+}
+
+const std::string& Sentence::expect_talker(const char* msg) const & {
+    if (tag_ != _Tag::Talker) {
+        throw std::runtime_error(msg);
+    }
+    return union_.talker_val;
+}
+
+std::string& Sentence::expect_talker(const char* msg) & {
+    if (tag_ != _Tag::Talker) {
+        throw std::runtime_error(msg);
+    }
+    return union_.talker_val;
+}
+
+std::string Sentence::expect_talker(const char* msg) && {
+    if (tag_ != _Tag::Talker) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(union_.talker_val);
+}
+
+::cppust::Option<std::string> Sentence::into_talker() && {
+    if (tag_ != _Tag::Talker) {
+        return ::cppust::Option<std::string>::None();
+    }
+    return ::cppust::Option<std::string>::Some(std::move(union_.talker_val));
+}
+
+::cppust::Option<std::string> Sentence::take_talker() & {
+    return std::move(*this).into_talker();
+}
+
+bool Sentence::is_checked() const {
+    return tag_ == _Tag::Checked;
+}
+
+const Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref_uncheck() const {
+    assert(tag_ == _Tag::Checked);
+    return union_.checked_val;
+}
+
+Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref_uncheck() {
+    assert(tag_ == _Tag::Checked);
+    return union_.checked_val;
+}
+
+const Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref() const {
+    if (tag_ != _Tag::Checked) {
+        throw std::runtime_error("requires Checked");
+    }
+    return union_.checked_val;
+}
+
+Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref() {
+    if (tag_ != _Tag::Checked) {
+        throw std::runtime_error("requires Checked");
+    }
+    return union_.checked_val;
+}
+
+const Res<::cppust::Option<::cppust::i32>>* Sentence::checked_ptr() const {
+    if (tag_ != _Tag::Checked) {
+        return nullptr;
+    }
+    return &union_.checked_val;
+}
+
+Res<::cppust::Option<::cppust::i32>>* Sentence::checked_ptr() {
+    if (tag_ != _Tag::Checked) {
+        return nullptr;
+    }
+    return &union_.checked_val;
+}
+
+::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>> Sentence::as_checked() const {
+    if (tag_ != _Tag::Checked) {
+        return ::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>>::Some(std::cref(union_.checked_val));
+}
+
+::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>> Sentence::as_checked() {
+    if (tag_ != _Tag::Checked) {
+        return ::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>>::Some(std::ref(union_.checked_val));
+}
+
+const Res<::cppust::Option<::cppust::i32>>& Sentence::expect_checked(const char* msg) const & {
+    if (tag_ != _Tag::Checked) {
+        throw std::runtime_error(msg);
+    }
+    return union_.checked_val;
+}
+
+Res<::cppust::Option<::cppust::i32>>& Sentence::expect_checked(const char* msg) & {
+    if (tag_ != _Tag::Checked) {
+        throw std::runtime_error(msg);
+    }
+    return union_.checked_val;
+}
+
+Res<::cppust::Option<::cppust::i32>> Sentence::expect_checked(const char* msg) && {
+    if (tag_ != _Tag::Checked) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(union_.checked_val);
+}
+
+::cppust::Option<Res<::cppust::Option<::cppust::i32>>> Sentence::into_checked() && {
+    if (tag_ != _Tag::Checked) {
+        return ::cppust::Option<Res<::cppust::Option<::cppust::i32>>>::None();
+    }
+    return ::cppust::Option<Res<::cppust::Option<::cppust::i32>>>::Some(std::move(union_.checked_val));
+}
+
+::cppust::Option<Res<::cppust::Option<::cppust::i32>>> Sentence::take_checked() & {
+    return std::move(*this).into_checked();
+}
+
+bool Sentence::is_raw() const {
+    return tag_ == _Tag::Raw;
+}
+
+const std::vector<std::string>& Sentence::raw_ref_uncheck() const {
+    assert(tag_ == _Tag::Raw);
+    return union_.raw_val;
+}
+
+std::vector<std::string>& Sentence::raw_ref_uncheck() {
+    assert(tag_ == _Tag::Raw);
+    return union_.raw_val;
+}
+
+const std::vector<std::string>& Sentence::raw_ref() const {
+    if (tag_ != _Tag::Raw) {
+        throw std::runtime_error("requires Unknown");
+    }
+    return union_.raw_val;
+}
+
+std::vector<std::string>& Sentence::raw_ref() {
+    if (tag_ != _Tag::Raw) {
+        throw std::runtime_error("requires Unknown");
+    }
+    return union_.raw_val;
+}
+
+const std::vector<std::string>* Sentence::raw_ptr() const {
+    if (tag_ != _Tag::Raw) {
+        return nullptr;
+    }
+    return &union_.raw_val;
+}
+
+std::vector<std::string>* Sentence::raw_ptr() {
+    if (tag_ != _Tag::Raw) {
+        return nullptr;
+    }
+    return &union_.raw_val;
+}
+
+::cppust::Option<std::reference_wrapper<const std::vector<std::string>>> Sentence::as_raw() const {
+    if (tag_ != _Tag::Raw) {
+        return ::cppust::Option<std::reference_wrapper<const std::vector<std::string>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::vector<std::string>>>::Some(std::cref(union_.raw_val));
+}
+
+::cppust::Option<std::reference_wrapper<std::vector<std::string>>> Sentence::as_raw() {
+    if (tag_ != _Tag::Raw) {
+        return ::cppust::Option<std::reference_wrapper<std::vector<std::string>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::vector<std::string>>>::Some(std::ref(union_.raw_val));
+}
+
+const std::vector<std::string>& Sentence::expect_raw(const char* msg) const & {
+    if (tag_ != _Tag::Raw) {
+        throw std::runtime_error(msg);
+    }
+    return union_.raw_val;
+}
+
+std::vector<std::string>& Sentence::expect_raw(const char* msg) & {
+    if (tag_ != _Tag::Raw) {
+        throw std::runtime_error(msg);
+    }
+    return union_.raw_val;
+}
+
+std::vector<std::string> Sentence::expect_raw(const char* msg) && {
+    if (tag_ != _Tag::Raw) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(union_.raw_val);
+}
+
+::cppust::Option<std::vector<std::string>> Sentence::into_raw() && {
+    if (tag_ != _Tag::Raw) {
+        return ::cppust::Option<std::vector<std::string>>::None();
+    }
+    return ::cppust::Option<std::vector<std::string>>::Some(std::move(union_.raw_val));
+}
+
+::cppust::Option<std::vector<std::string>> Sentence::take_raw() & {
+    return std::move(*this).into_raw();
+}
+
+// private methods
+void Sentence::tagged_init_(_Tag tag, const _Union& union_val) {
+    switch (tag) {
+    case _Tag::Gga:
+        new (&union_.gga_val) std::tuple<::cppust::f64, ::cppust::f64>(union_val.gga_val);
+        break;
+    case _Tag::Gsv:
+        new (&union_.gsv_val) Satellites(union_val.gsv_val);
+        break;
+    case _Tag::Talker:
+        new (&union_.talker_val) std::string(union_val.talker_val);
+        break;
+    case _Tag::Checked:
+        new (&union_.checked_val) Res<::cppust::Option<::cppust::i32>>(union_val.checked_val);
+        break;
+    case _Tag::Raw:
+        new (&union_.raw_val) std::vector<std::string>(union_val.raw_val);
+        break;
+    default:
+        break;
+    }
+}
+
+void Sentence::tagged_init_(_Tag tag, _Union&& union_val) {
+    switch (tag) {
+    case _Tag::Gga:
+        new (&union_.gga_val) std::tuple<::cppust::f64, ::cppust::f64>(std::move(union_val.gga_val));
+        break;
+    case _Tag::Gsv:
+        new (&union_.gsv_val) Satellites(std::move(union_val.gsv_val));
+        break;
+    case _Tag::Talker:
+        new (&union_.talker_val) std::string(std::move(union_val.talker_val));
+        break;
+    case _Tag::Checked:
+        new (&union_.checked_val) Res<::cppust::Option<::cppust::i32>>(std::move(union_val.checked_val));
+        break;
+    case _Tag::Raw:
+        new (&union_.raw_val) std::vector<std::string>(std::move(union_val.raw_val));
+        break;
+    default:
+        break;
+    }
+}
+
+void Sentence::deinit_union_() {
+    switch (tag_) {
+    case _Tag::Gga:
+        union_.gga_val.~tuple();
+        break;
+    case _Tag::Gsv:
+        union_.gsv_val.~vector();
+        break;
+    case _Tag::Talker:
+        union_.talker_val.~basic_string();
+        break;
+    case _Tag::Checked:
+        union_.checked_val.~Result();
+        break;
+    case _Tag::Raw:
+        union_.raw_val.~vector();
+        break;
+    default:
+        break;
+    }
+}
+
+
+}
@@ -0,0 +1,306 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE upload.cpp INSTEAD
+
+#include "t/upload.hpp"
+
+namespace t {
+
+// public constructors
+Upload::Upload(Upload&& rhs) noexcept(_kNothrowMoveConstructible): Upload(rhs.tag_, std::move(rhs.union_)) { } // move constructor
+
+// private constructors
+Upload::Upload(_Tag tag): tag_(tag) { }
+
+Upload::Upload(_Tag tag, _Union&& union_val): tag_(tag) {
+    tagged_init_(tag, std::move(union_val));
+}
+
+
+// destructor
+Upload::~Upload() {
+    deinit_union_();
+}
+
+// assign operators
+Upload& Upload::operator=(Upload&& rhs) noexcept(_kNothrowMoveAssignable) { // move
+    if (this == &rhs) { return *this; }
+
+    if (tag_ == rhs.tag_) {
+        switch (tag_) {
+        case _Tag::Chunk:
+            union_.chunk_val = std::move(rhs.union_.chunk_val);
+            break;
+        case _Tag::Parts:
+            union_.parts_val = std::move(rhs.union_.parts_val);
+            break;
+        default:
+            break;
+        }
+    } else {
+        deinit_union_();
+        // valueless until the payload is moved in, which may throw
+        tag_ = _Tag::_Valueless;
+        tagged_init_(rhs.tag_, std::move(rhs.union_));
+        tag_ = rhs.tag_;
+    }
+    return *this;
+}
+
+void Upload::swap(Upload& other) noexcept(_kNothrowMoveConstructible && _kNothrowMoveAssignable) {
+    if (this == &other) { return; }
+
+    Upload moved(std::move(other));
+    other = std::move(*this);
+    *this = std::move(moved);
+}
+
+// enum constructors
+Upload Upload::Chunk(const std::string& v0) {
+    Upload output(_Tag::_Valueless);
+    new (&output.union_.chunk_val) std::string(v0);
+    output.tag_ = _Tag::Chunk;
+    return output;
+}
+
+Upload Upload::Chunk(std::string&& value) {
+    Upload output(_Tag::_Valueless);
+    new (&output.union_.chunk_val) std::string(std::move(value));
+    output.tag_ = _Tag::Chunk;
+    return output;
+}
+
+Upload Upload::Parts(const std::vector<Sentence>& v0, const ::cppust::Option<Fault>& v1) {
+    Upload output(_Tag::_Valueless);
+    new (&output.union_.parts_val) std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>(v0, v1);
+    output.tag_ = _Tag::Parts;
+    return output;
+}
+
+Upload Upload::Parts(std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>&& value) {
+    Upload output(_Tag::_Valueless);
+    new (&output.union_.parts_val) std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>(std::move(value));
+    output.tag_ = _Tag::Parts;
+    return output;
+}
+
+Upload Upload::Parts(std::vector<Sentence>&& v0, ::cppust::Option<Fault>&& v1) {
+    Upload output(_Tag::_Valueless);
+    new (&output.union_.parts_val) std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>(std::move(v0), std::move(v1));
+    output.tag_ = _Tag::Parts;
+    return output;
+}
+
+Upload Upload::Finished() {
+    return Upload(_Tag::Finished);
+}
+
+// accessors
+bool Upload::is_chunk() const {
+    return tag_ == _Tag::Chunk;
+}
+
+const std::string& Upload::chunk_ref_uncheck() const {
+    assert(tag_ == _Tag::Chunk);
+    return union_.chunk_val;
+}
+
+std::string& Upload::chunk_ref_uncheck() {
+    assert(tag_ == _Tag::Chunk);
+    return union_.chunk_val;
+}
+
+const std::string& Upload::chunk_ref() const {
+    if (tag_ != _Tag::Chunk) {
+        throw std::runtime_error("requires Chunk");
+    }
+    return union_.chunk_val;
+}
+
+std::string& Upload::chunk_ref() {
+    if (tag_ != _Tag::Chunk) {
+        throw std::runtime_error("requires Chunk");
+    }
+    return union_.chunk_val;
+}
+
+const std::string* Upload::chunk_ptr() const {
+    if (tag_ != _Tag::Chunk) {
+        return nullptr;
+    }
+    return &union_.chunk_val;
+}
+
+std::string* Upload::chunk_ptr() {
+    if (tag_ != _Tag::Chunk) {
+        return nullptr;
+    }
+    return &union_.chunk_val;
+}
+
+::cppust::Option<std::reference_wrapper<const std::string>> Upload::as_chunk() const {
+    if (tag_ != _Tag::Chunk) {
+        return ::cppust::Option<std::reference_wrapper<const std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::string>>::Some(std::cref(union_.chunk_val));
+}
+
+::cppust::Option<std::reference_wrapper<std::string>> Upload::as_chunk() {
+    if (tag_ != _Tag::Chunk) {
+        return ::cppust::Option<std::reference_wrapper<std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::string>>::Some(std::ref(union_.chunk_val));
+}
+
+const std::string& Upload::expect_chunk(const char* msg) const & {
+    if (tag_ != _Tag::Chunk) {
+        throw std::runtime_error(msg);
+    }
+    return union_.chunk_val;
+}
+
+std::string& Upload::expect_chunk(const char* msg) & {
+    if (tag_ != _Tag::Chunk) {
+        throw std::runtime_error(msg);
+    }
+    return union_.chunk_val;
+}
+
+std::string Upload::expect_chunk(const char* msg) && {
+    if (tag_ != _Tag::Chunk) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(union_.chunk_val);
+}
+
+::cppust::Option<std::string> Upload::into_chunk() && {
+    if (tag_ != _Tag::Chunk) {
+        return ::cppust::Option<std::string>::None();
+    }
+    return ::cppust::Option<std::string>::Some(std::move(union_.chunk_val));
+}
+
+::cppust::Option<std::string> Upload::take_chunk() & {
+    return std::move(*this).into_chunk();
+}
+
+bool Upload::is_parts() const {
+    return tag_ == _Tag::Parts;
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref_uncheck() const {
+    assert(tag_ == _Tag::Parts);
+    return union_.parts_val;
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref_uncheck() {
+    assert(tag_ == _Tag::Parts);
+    return union_.parts_val;
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref() const {
+    if (tag_ != _Tag::Parts) {
+        throw std::runtime_error("requires Parts");
+    }
+    return union_.parts_val;
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref() {
+    if (tag_ != _Tag::Parts) {
+        throw std::runtime_error("requires Parts");
+    }
+    return union_.parts_val;
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* Upload::parts_ptr() const {
+    if (tag_ != _Tag::Parts) {
+        return nullptr;
+    }
+    return &union_.parts_val;
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* Upload::parts_ptr() {
+    if (tag_ != _Tag::Parts) {
+        return nullptr;
+    }
+    return &union_.parts_val;
+}
+
+::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> Upload::as_parts() const {
+    if (tag_ != _Tag::Parts) {
+        return ::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::Some(std::cref(union_.parts_val));
+}
+
+::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> Upload::as_parts() {
+    if (tag_ != _Tag::Parts) {
+        return ::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::Some(std::ref(union_.parts_val));
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::expect_parts(const char* msg) const & {
+    if (tag_ != _Tag::Parts) {
+        throw std::runtime_error(msg);
+    }
+    return union_.parts_val;
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::expect_parts(const char* msg) & {
+    if (tag_ != _Tag::Parts) {
+        throw std::runtime_error(msg);
+    }
+    return union_.parts_val;
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>> Upload::expect_parts(const char* msg) && {
+    if (tag_ != _Tag::Parts) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(union_.parts_val);
+}
+
+::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> Upload::into_parts() && {
+    if (tag_ != _Tag::Parts) {
+        return ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>::None();
+    }
+    return ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>::Some(std::move(union_.parts_val));
+}
+
+::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> Upload::take_parts() & {
+    return std::move(*this).into_parts();
+}
+
+bool Upload::is_finished() const {
+    return tag_ == _Tag::Finished;
+}
+
+// private methods
+void Upload::tagged_init_(_Tag tag, _Union&& union_val) {
+    switch (tag) {
+    case _Tag::Chunk:
+        new (&union_.chunk_val) std::string(std::move(union_val.chunk_val));
+        break;
+    case _Tag::Parts:
+        new (&union_.parts_val) std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>(std::move(union_val.parts_val));
+        break;
+    default:
+        break;
+    }
+}
+
+void Upload::deinit_union_() {
+    switch (tag_) {
+    case _Tag::Chunk:
+        union_.chunk_val.~basic_string();
+        break;
+    case _Tag::Parts:
+        union_.parts_val.~tuple();
+        break;
+    default:
+        break;
+    }
+}
+
+
+}
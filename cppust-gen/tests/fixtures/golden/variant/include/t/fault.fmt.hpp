@@ -0,0 +1,38 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE fault.hpp INSTEAD
+
+namespace cppust {
+    template <> struct debug<t::Fault> { // Debug
+        template <typename Os>
+        static Os& fmt(const t::Fault& self, Os& os) {
+            switch (self.tag_()) {
+            case t::Fault::_Tag::Timeout:
+                os<<"Timeout";
+                break;
+            case t::Fault::_Tag::Checksum:
+                os<<"Checksum";
+                break;
+            case t::Fault::_Tag::Overflow:
+                os<<"Overflow";
+                break;
+            default:
+                os<<"<?>("<<int(self.tag_())<<")";
+                break;
+            }
+            return os;
+        }
+    };
+}
+
+namespace std {
+    template <> struct hash<t::Fault> { // Hash
+        size_t operator()(const t::Fault& self) const {
+            size_t seed = std::hash<int>()(int(self.tag_()));
+            switch (self.tag_()) {
+            default:
+                return seed;
+            }
+        }
+    };
+}
+
@@ -0,0 +1,29 @@
+// THIS FILE IS GENERATED BY cppust-gen, YOU CAN CHANGE THIS FILE ON YOUR OWN
+
+#pragma once
+
+#include <cppust/types.hpp>
+#include <cppust/utils.hpp>
+#include <cppust/option.hpp>
+#include <cppust/fmt.hpp>
+#include <cppust/hash.hpp>
+#include <cppust/cmp.hpp>
+
+#include <functional>
+#include <utility>
+#include <variant>
+#include <stdexcept>
+#include <iostream>
+
+namespace t {
+
+class Fault {
+#include "fault.inc.hpp"
+
+public:
+    // WRITE YOUR OWN CODE HERE
+};
+
+}
+
+#include "fault.fmt.hpp"
@@ -0,0 +1,74 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE fault.hpp INSTEAD
+
+private: // type definition
+    static_assert(__cplusplus >= 201703L, "fault is generated for 201703L");
+
+    enum class _Tag {
+        Timeout = 1,
+        Checksum,
+        Overflow = 8,
+        _Valueless = 9,
+    };
+
+    using _Variant = std::variant<
+        std::monostate
+        , std::monostate
+        , std::monostate
+    >;
+
+    static constexpr _Tag _kTags[] = {
+        _Tag::Timeout,
+        _Tag::Checksum,
+        _Tag::Overflow,
+    };
+
+
+public: // ctors, dtors, opeartors
+    constexpr Fault(): value_(std::in_place_index<0>) {}  // Default
+    Fault(const Fault&) = default;  // Clone
+    Fault(Fault&&) = default;
+    ~Fault() = default;
+
+    Fault& operator=(const Fault&) = default;  // Clone
+    Fault& operator=(Fault&&) = default;
+
+    void swap(Fault& other) noexcept(std::is_nothrow_move_constructible<_Variant>::value && std::is_nothrow_swappable<_Variant>::value) { value_.swap(other.value_); }
+    friend void swap(Fault& lhs, Fault& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    constexpr bool valueless_by_exception() const { return value_.valueless_by_exception(); }
+
+    bool operator==(const Fault&) const;  // PartialEq
+    bool operator!=(const Fault&) const;  // PartialEq
+    ::cppust::Option<::cppust::Ordering> partial_cmp(const Fault&) const;  // PartialOrd
+    bool operator<(const Fault&) const;  // PartialOrd
+    bool operator<=(const Fault&) const;  // PartialOrd
+    bool operator>(const Fault&) const;  // PartialOrd
+    bool operator>=(const Fault&) const;  // PartialOrd
+#if __cplusplus > 201703L
+    std::strong_ordering operator<=>(const Fault&) const;  // PartialOrd
+#endif
+    template<typename> friend struct ::cppust::debug;  // Debug
+    friend struct ::std::hash<Fault>;  // Hash
+
+public: // enum constructors
+    static constexpr Fault Timeout() { return Fault(std::in_place_index<0>); }
+    static constexpr Fault Checksum() { return Fault(std::in_place_index<1>); }
+    static constexpr Fault Overflow() { return Fault(std::in_place_index<2>); }
+
+public: // in-place constructors
+public: // value accessors
+    constexpr bool is_timeout() const { return tag_() == _Tag::Timeout; }
+
+    constexpr bool is_checksum() const { return tag_() == _Tag::Checksum; }
+
+    constexpr bool is_overflow() const { return tag_() == _Tag::Overflow; }
+
+private:
+    _Variant value_;
+
+    template <size_t I, typename... Args>
+    constexpr explicit Fault(std::in_place_index_t<I> index, Args&&... args): value_(index, std::forward<Args>(args)...) {}
+
+    constexpr _Tag tag_() const { return value_.valueless_by_exception() ? _Tag::_Valueless : _kTags[value_.index()]; }
@@ -0,0 +1,35 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE sentence.hpp INSTEAD
+
+namespace cppust {
+    template <> struct debug<t::Sentence> { // Debug
+        template <typename Os>
+        static Os& fmt(const t::Sentence& self, Os& os) {
+            switch (self.tag_()) {
+            case t::Sentence::_Tag::Empty:
+                os<<"Empty";
+                break;
+            case t::Sentence::_Tag::Gga:
+                os<<"Gga("<<::cppust::as_debug(std::get<1>(self.value_))<<")";
+                break;
+            case t::Sentence::_Tag::Gsv:
+                os<<"Gsv("<<::cppust::as_debug(std::get<2>(self.value_))<<")";
+                break;
+            case t::Sentence::_Tag::Talker:
+                os<<"Talker("<<::cppust::as_debug(std::get<3>(self.value_))<<")";
+                break;
+            case t::Sentence::_Tag::Checked:
+                os<<"Checked("<<::cppust::as_debug(std::get<4>(self.value_))<<")";
+                break;
+            case t::Sentence::_Tag::Raw:
+                os<<"Unknown("<<::cppust::as_debug(std::get<5>(self.value_))<<")";
+                break;
+            default:
+                os<<"<?>("<<int(self.tag_())<<")";
+                break;
+            }
+            return os;
+        }
+    };
+}
+
@@ -0,0 +1,35 @@
+// THIS FILE IS GENERATED BY cppust-gen, YOU CAN CHANGE THIS FILE ON YOUR OWN
+
+#pragma once
+
+#include <cppust/types.hpp>
+#include <cppust/utils.hpp>
+#include <cppust/option.hpp>
+#include <cppust/fmt.hpp>
+#include <cppust/cmp.hpp>
+#include <cppust/vec.hpp>
+
+#include <functional>
+#include <utility>
+#include <string>
+#include <tuple>
+#include <variant>
+#include <stdexcept>
+#include <iostream>
+
+#include "t/nmea_types.hpp"
+#include "t/fault.hpp"
+
+namespace t {
+
+/// @brief A decoded sentence
+class Sentence {
+#include "sentence.inc.hpp"
+
+public:
+    // WRITE YOUR OWN CODE HERE
+};
+
+}
+
+#include "sentence.fmt.hpp"
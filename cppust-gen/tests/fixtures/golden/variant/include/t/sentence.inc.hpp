@@ -0,0 +1,249 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE sentence.hpp INSTEAD
+
+private: // type definition
+    static_assert(__cplusplus >= 201703L, "sentence is generated for 201703L");
+
+    enum class _Tag {
+        /// Nothing received yet
+        Empty,
+        /// Latitude and longitude of the fix
+        Gga,
+        Gsv,
+        Talker,
+        Checked,
+        Raw,
+        _Valueless,
+    };
+
+    using _Variant = std::variant<
+        std::monostate
+        , std::tuple<::cppust::f64, ::cppust::f64>
+        , Satellites
+        , std::string
+        , Res<::cppust::Option<::cppust::i32>>
+        , std::vector<std::string>
+    >;
+
+    static constexpr _Tag _kTags[] = {
+        _Tag::Empty,
+        _Tag::Gga,
+        _Tag::Gsv,
+        _Tag::Talker,
+        _Tag::Checked,
+        _Tag::Raw,
+    };
+
+
+public: // ctors, dtors, opeartors
+    Sentence(const Sentence&) = default;  // Clone
+    Sentence(Sentence&&) = default;
+    ~Sentence() = default;
+
+    Sentence& operator=(const Sentence&) = default;  // Clone
+    Sentence& operator=(Sentence&&) = default;
+
+    void swap(Sentence& other) noexcept(std::is_nothrow_move_constructible<_Variant>::value && std::is_nothrow_swappable<_Variant>::value) { value_.swap(other.value_); }
+    friend void swap(Sentence& lhs, Sentence& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    bool valueless_by_exception() const { return value_.valueless_by_exception(); }
+
+    bool operator==(const Sentence&) const;  // PartialEq
+    bool operator!=(const Sentence&) const;  // PartialEq
+    ::cppust::Option<::cppust::Ordering> partial_cmp(const Sentence&) const;  // PartialOrd
+    bool operator<(const Sentence&) const;  // PartialOrd
+    bool operator<=(const Sentence&) const;  // PartialOrd
+    bool operator>(const Sentence&) const;  // PartialOrd
+    bool operator>=(const Sentence&) const;  // PartialOrd
+#if __cplusplus > 201703L
+    std::partial_ordering operator<=>(const Sentence&) const;  // PartialOrd
+#endif
+    template<typename> friend struct ::cppust::debug;  // Debug
+
+public: // enum constructors
+    /// @brief Nothing received yet
+    static Sentence Empty();
+    /// @brief Latitude and longitude of the fix
+    static Sentence Gga(const ::cppust::f64&, const ::cppust::f64&);
+    static Sentence Gga(std::tuple<::cppust::f64, ::cppust::f64>&&);
+    static Sentence Gga(::cppust::f64&&, ::cppust::f64&&);
+    static Sentence Gsv(const Satellites&);
+    static Sentence Gsv(Satellites&&);
+    static Sentence Talker(const std::string&);
+    static Sentence Talker(std::string&&);
+    static Sentence Checked(const Res<::cppust::Option<::cppust::i32>>&);
+    static Sentence Checked(Res<::cppust::Option<::cppust::i32>>&&);
+    static Sentence Raw(const std::vector<std::string>&);
+    static Sentence Raw(std::vector<std::string>&&);
+
+public: // in-place constructors
+    /// @brief Replaces the value with a `Gga` constructed from `args`
+    /// @details Latitude and longitude of the fix
+    template <typename... Args>
+    std::tuple<::cppust::f64, ::cppust::f64>& emplace_gga(Args&&... args) {
+        return value_.template emplace<1>(std::forward<Args>(args)...);
+    }
+
+    template <typename... Args>
+    Satellites& emplace_gsv(Args&&... args) {
+        return value_.template emplace<2>(std::forward<Args>(args)...);
+    }
+
+    template <typename... Args>
+    std::string& emplace_talker(Args&&... args) {
+        return value_.template emplace<3>(std::forward<Args>(args)...);
+    }
+
+    template <typename... Args>
+    Res<::cppust::Option<::cppust::i32>>& emplace_checked(Args&&... args) {
+        return value_.template emplace<4>(std::forward<Args>(args)...);
+    }
+
+    template <typename... Args>
+    std::vector<std::string>& emplace_raw(Args&&... args) {
+        return value_.template emplace<5>(std::forward<Args>(args)...);
+    }
+
+public: // value accessors
+    /// @brief Whether the value is `Empty`
+    /// @details Nothing received yet
+    bool is_empty() const;
+
+    /// @brief Whether the value is `Gga`
+    /// @details Latitude and longitude of the fix
+    bool is_gga() const;
+
+    /// @brief Payload of `Gga`, which must be the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& gga_ref_uncheck() const;
+    std::tuple<::cppust::f64, ::cppust::f64>& gga_ref_uncheck();
+
+    /// @brief Payload of `Gga`, panics unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& gga_ref() const;
+    std::tuple<::cppust::f64, ::cppust::f64>& gga_ref();
+
+    /// @brief Payload of `Gga`, or `nullptr` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>* gga_ptr() const;
+    std::tuple<::cppust::f64, ::cppust::f64>* gga_ptr();
+
+    /// @brief Payload of `Gga`, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>> as_gga() const;
+    ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>> as_gga();
+
+    /// @brief Payload of `Gga`, panics with `msg` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    const std::tuple<::cppust::f64, ::cppust::f64>& expect_gga(const char* msg) const &;
+    std::tuple<::cppust::f64, ::cppust::f64>& expect_gga(const char* msg) &;
+    std::tuple<::cppust::f64, ::cppust::f64> expect_gga(const char* msg) &&;
+
+    /// @brief Moves out the payload of `Gga`, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> into_gga() &&;
+
+    /// @brief Moves out the payload of `Gga`, leaving it moved-from, or `None` unless it is the active variant
+    /// @details Latitude and longitude of the fix
+    ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> take_gga() &;
+
+    bool is_gsv() const;
+
+    const Satellites& gsv_ref_uncheck() const;
+    Satellites& gsv_ref_uncheck();
+
+    const Satellites& gsv_ref() const;
+    Satellites& gsv_ref();
+
+    const Satellites* gsv_ptr() const;
+    Satellites* gsv_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const Satellites>> as_gsv() const;
+    ::cppust::Option<std::reference_wrapper<Satellites>> as_gsv();
+
+    const Satellites& expect_gsv(const char* msg) const &;
+    Satellites& expect_gsv(const char* msg) &;
+    Satellites expect_gsv(const char* msg) &&;
+
+    ::cppust::Option<Satellites> into_gsv() &&;
+
+    ::cppust::Option<Satellites> take_gsv() &;
+
+    bool is_talker() const;
+
+    const std::string& talker_ref_uncheck() const;
+    std::string& talker_ref_uncheck();
+
+    const std::string& talker_ref() const;
+    std::string& talker_ref();
+
+    const std::string* talker_ptr() const;
+    std::string* talker_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::string>> as_talker() const;
+    ::cppust::Option<std::reference_wrapper<std::string>> as_talker();
+
+    const std::string& expect_talker(const char* msg) const &;
+    std::string& expect_talker(const char* msg) &;
+    std::string expect_talker(const char* msg) &&;
+
+    ::cppust::Option<std::string> into_talker() &&;
+
+    ::cppust::Option<std::string> take_talker() &;
+
+    bool is_checked() const;
+
+    const Res<::cppust::Option<::cppust::i32>>& checked_ref_uncheck() const;
+    Res<::cppust::Option<::cppust::i32>>& checked_ref_uncheck();
+
+    const Res<::cppust::Option<::cppust::i32>>& checked_ref() const;
+    Res<::cppust::Option<::cppust::i32>>& checked_ref();
+
+    const Res<::cppust::Option<::cppust::i32>>* checked_ptr() const;
+    Res<::cppust::Option<::cppust::i32>>* checked_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>> as_checked() const;
+    ::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>> as_checked();
+
+    const Res<::cppust::Option<::cppust::i32>>& expect_checked(const char* msg) const &;
+    Res<::cppust::Option<::cppust::i32>>& expect_checked(const char* msg) &;
+    Res<::cppust::Option<::cppust::i32>> expect_checked(const char* msg) &&;
+
+    ::cppust::Option<Res<::cppust::Option<::cppust::i32>>> into_checked() &&;
+
+    ::cppust::Option<Res<::cppust::Option<::cppust::i32>>> take_checked() &;
+
+    bool is_raw() const;
+
+    const std::vector<std::string>& raw_ref_uncheck() const;
+    std::vector<std::string>& raw_ref_uncheck();
+
+    const std::vector<std::string>& raw_ref() const;
+    std::vector<std::string>& raw_ref();
+
+    const std::vector<std::string>* raw_ptr() const;
+    std::vector<std::string>* raw_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::vector<std::string>>> as_raw() const;
+    ::cppust::Option<std::reference_wrapper<std::vector<std::string>>> as_raw();
+
+    const std::vector<std::string>& expect_raw(const char* msg) const &;
+    std::vector<std::string>& expect_raw(const char* msg) &;
+    std::vector<std::string> expect_raw(const char* msg) &&;
+
+    ::cppust::Option<std::vector<std::string>> into_raw() &&;
+
+    ::cppust::Option<std::vector<std::string>> take_raw() &;
+
+public: // methods
+    /// @brief Whether the sentence carries a position
+    bool has_fix() const;
+
+private:
+    _Variant value_;
+
+    template <size_t I, typename... Args>
+    explicit Sentence(std::in_place_index_t<I> index, Args&&... args): value_(index, std::forward<Args>(args)...) {}
+
+    _Tag tag_() const { return value_.valueless_by_exception() ? _Tag::_Valueless : _kTags[value_.index()]; }
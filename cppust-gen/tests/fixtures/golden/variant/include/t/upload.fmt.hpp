@@ -0,0 +1,3 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE upload.hpp INSTEAD
+
@@ -0,0 +1,33 @@
+// THIS FILE IS GENERATED BY cppust-gen, YOU CAN CHANGE THIS FILE ON YOUR OWN
+
+#pragma once
+
+#include <cppust/types.hpp>
+#include <cppust/utils.hpp>
+#include <cppust/option.hpp>
+#include <cppust/vec.hpp>
+
+#include <functional>
+#include <utility>
+#include <string>
+#include <tuple>
+#include <variant>
+#include <stdexcept>
+#include <iostream>
+
+#include "t/fault.hpp"
+#include "t/sentence.hpp"
+
+namespace t {
+
+/// @brief Neither copyable nor comparable
+class Upload {
+#include "upload.inc.hpp"
+
+public:
+    // WRITE YOUR OWN CODE HERE
+};
+
+}
+
+#include "upload.fmt.hpp"
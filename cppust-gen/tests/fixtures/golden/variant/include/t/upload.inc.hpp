@@ -0,0 +1,113 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE upload.hpp INSTEAD
+
+private: // type definition
+    static_assert(__cplusplus >= 201703L, "upload is generated for 201703L");
+
+    enum class _Tag {
+        Chunk,
+        Parts,
+        Finished,
+        _Valueless,
+    };
+
+    using _Variant = std::variant<
+        std::string
+        , std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>
+        , std::monostate
+    >;
+
+    static constexpr _Tag _kTags[] = {
+        _Tag::Chunk,
+        _Tag::Parts,
+        _Tag::Finished,
+    };
+
+
+public: // ctors, dtors, opeartors
+    Upload(const Upload&) = delete;
+    Upload(Upload&&) = default;
+    ~Upload() = default;
+
+    Upload& operator=(const Upload&) = delete;
+    Upload& operator=(Upload&&) = default;
+
+    void swap(Upload& other) noexcept(std::is_nothrow_move_constructible<_Variant>::value && std::is_nothrow_swappable<_Variant>::value) { value_.swap(other.value_); }
+    friend void swap(Upload& lhs, Upload& rhs) noexcept(noexcept(lhs.swap(rhs))) { lhs.swap(rhs); }
+
+    // whether a payload threw while replacing the value, which then has no variant
+    bool valueless_by_exception() const { return value_.valueless_by_exception(); }
+
+public: // enum constructors
+    static Upload Chunk(const std::string&);
+    static Upload Chunk(std::string&&);
+    static Upload Parts(const std::vector<Sentence>&, const ::cppust::Option<Fault>&);
+    static Upload Parts(std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>&&);
+    static Upload Parts(std::vector<Sentence>&&, ::cppust::Option<Fault>&&);
+    static Upload Finished();
+
+public: // in-place constructors
+    template <typename... Args>
+    std::string& emplace_chunk(Args&&... args) {
+        return value_.template emplace<0>(std::forward<Args>(args)...);
+    }
+
+    template <typename... Args>
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& emplace_parts(Args&&... args) {
+        return value_.template emplace<1>(std::forward<Args>(args)...);
+    }
+
+public: // value accessors
+    bool is_chunk() const;
+
+    const std::string& chunk_ref_uncheck() const;
+    std::string& chunk_ref_uncheck();
+
+    const std::string& chunk_ref() const;
+    std::string& chunk_ref();
+
+    const std::string* chunk_ptr() const;
+    std::string* chunk_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::string>> as_chunk() const;
+    ::cppust::Option<std::reference_wrapper<std::string>> as_chunk();
+
+    const std::string& expect_chunk(const char* msg) const &;
+    std::string& expect_chunk(const char* msg) &;
+    std::string expect_chunk(const char* msg) &&;
+
+    ::cppust::Option<std::string> into_chunk() &&;
+
+    ::cppust::Option<std::string> take_chunk() &;
+
+    bool is_parts() const;
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref_uncheck() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref_uncheck();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& parts_ref();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* parts_ptr() const;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* parts_ptr();
+
+    ::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> as_parts() const;
+    ::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> as_parts();
+
+    const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& expect_parts(const char* msg) const &;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& expect_parts(const char* msg) &;
+    std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>> expect_parts(const char* msg) &&;
+
+    ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> into_parts() &&;
+
+    ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> take_parts() &;
+
+    bool is_finished() const;
+
+private:
+    _Variant value_;
+
+    template <size_t I, typename... Args>
+    explicit Upload(std::in_place_index_t<I> index, Args&&... args): value_(index, std::forward<Args>(args)...) {}
+
+    _Tag tag_() const { return value_.valueless_by_exception() ? _Tag::_Valueless : _kTags[value_.index()]; }
@@ -0,0 +1,574 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE sentence.cpp INSTEAD
+
+#include "t/sentence.hpp"
+
+namespace t {
+
+// operators
+bool Sentence::operator==(const Sentence& rhs) const { // equal
+    if (tag_() != rhs.tag_()) { return false; }
+    switch (tag_()) {
+    case _Tag::Gga:
+        return std::get<1>(value_) == std::get<1>(rhs.value_);
+    case _Tag::Gsv:
+        return std::get<2>(value_) == std::get<2>(rhs.value_);
+    case _Tag::Talker:
+        return std::get<3>(value_) == std::get<3>(rhs.value_);
+    case _Tag::Checked:
+        return std::get<4>(value_) == std::get<4>(rhs.value_);
+    case _Tag::Raw:
+        return std::get<5>(value_) == std::get<5>(rhs.value_);
+    default:
+        break;
+    }
+    return true;
+}
+
+bool Sentence::operator!=(const Sentence& rhs) const { // not equal
+    return !(*this == rhs);
+}
+
+::cppust::Option<::cppust::Ordering> Sentence::partial_cmp(const Sentence& rhs) const { // partial compare
+    if (tag_() != rhs.tag_()) {
+        return ::cppust::Option<::cppust::Ordering>::Some(tag_() < rhs.tag_() ? ::cppust::Ordering::Less : ::cppust::Ordering::Greater);
+    }
+    switch (tag_()) {
+    case _Tag::Gga:
+        return ::cppust::partial_cmp(std::get<1>(value_), std::get<1>(rhs.value_));
+    case _Tag::Gsv:
+        return ::cppust::partial_cmp(std::get<2>(value_), std::get<2>(rhs.value_));
+    case _Tag::Talker:
+        return ::cppust::partial_cmp(std::get<3>(value_), std::get<3>(rhs.value_));
+    case _Tag::Checked:
+        return ::cppust::partial_cmp(std::get<4>(value_), std::get<4>(rhs.value_));
+    case _Tag::Raw:
+        return ::cppust::partial_cmp(std::get<5>(value_), std::get<5>(rhs.value_));
+    default:
+        break;
+    }
+    return ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+bool Sentence::operator<(const Sentence& rhs) const { // less than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less);
+}
+
+bool Sentence::operator<=(const Sentence& rhs) const { // less or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Less) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+bool Sentence::operator>(const Sentence& rhs) const { // greater than
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater);
+}
+
+bool Sentence::operator>=(const Sentence& rhs) const { // greater or equal
+    auto ord = partial_cmp(rhs);
+    return ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Greater) || ord == ::cppust::Option<::cppust::Ordering>::Some(::cppust::Ordering::Equal);
+}
+
+#if __cplusplus > 201703L
+std::partial_ordering Sentence::operator<=>(const Sentence& rhs) const { // three-way compare
+    return ::cppust::to_partial_ordering(partial_cmp(rhs));
+}
+#endif
+
+// enum constructors
+Sentence Sentence::Empty() {
+    return Sentence(std::in_place_index<0>);
+}
+
+Sentence Sentence::Gga(const ::cppust::f64& v0, const ::cppust::f64& v1) {
+    return Sentence(std::in_place_index<1>, v0, v1);
+}
+
+Sentence Sentence::Gga(std::tuple<::cppust::f64, ::cppust::f64>&& value) {
+    return Sentence(std::in_place_index<1>, std::move(value));
+}
+
+Sentence Sentence::Gga(::cppust::f64&& v0, ::cppust::f64&& v1) {
+    return Sentence(std::in_place_index<1>, std::move(v0), std::move(v1));
+}
+
+Sentence Sentence::Gsv(const Satellites& v0) {
+    return Sentence(std::in_place_index<2>, v0);
+}
+
+Sentence Sentence::Gsv(Satellites&& value) {
+    return Sentence(std::in_place_index<2>, std::move(value));
+}
+
+Sentence Sentence::Talker(const std::string& v0) {
+    return Sentence(std::in_place_index<3>, v0);
+}
+
+Sentence Sentence::Talker(std::string&& value) {
+    return Sentence(std::in_place_index<3>, std::move(value));
+}
+
+Sentence Sentence::Checked(const Res<::cppust::Option<::cppust::i32>>& v0) {
+    return Sentence(std::in_place_index<4>, v0);
+}
+
+Sentence Sentence::Checked(Res<::cppust::Option<::cppust::i32>>&& value) {
+    return Sentence(std::in_place_index<4>, std::move(value));
+}
+
+Sentence Sentence::Raw(const std::vector<std::string>& v0) {
+    return Sentence(std::in_place_index<5>, v0);
+}
+
+Sentence Sentence::Raw(std::vector<std::string>&& value) {
+    return Sentence(std::in_place_index<5>, std::move(value));
+}
+
+// accessors
+bool Sentence::is_empty() const {
+    return tag_() == _Tag::Empty;
+}
+
+bool Sentence::is_gga() const {
+    return tag_() == _Tag::Gga;
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref_uncheck() const {
+    assert(tag_() == _Tag::Gga);
+    return std::get<1>(value_);
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref_uncheck() {
+    assert(tag_() == _Tag::Gga);
+    return std::get<1>(value_);
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref() const {
+    if (tag_() != _Tag::Gga) {
+        throw std::runtime_error("requires Gga");
+    }
+    return std::get<1>(value_);
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>& Sentence::gga_ref() {
+    if (tag_() != _Tag::Gga) {
+        throw std::runtime_error("requires Gga");
+    }
+    return std::get<1>(value_);
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>* Sentence::gga_ptr() const {
+    if (tag_() != _Tag::Gga) {
+        return nullptr;
+    }
+    return &std::get<1>(value_);
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>* Sentence::gga_ptr() {
+    if (tag_() != _Tag::Gga) {
+        return nullptr;
+    }
+    return &std::get<1>(value_);
+}
+
+::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>> Sentence::as_gga() const {
+    if (tag_() != _Tag::Gga) {
+        return ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::tuple<::cppust::f64, ::cppust::f64>>>::Some(std::cref(std::get<1>(value_)));
+}
+
+::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>> Sentence::as_gga() {
+    if (tag_() != _Tag::Gga) {
+        return ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::tuple<::cppust::f64, ::cppust::f64>>>::Some(std::ref(std::get<1>(value_)));
+}
+
+const std::tuple<::cppust::f64, ::cppust::f64>& Sentence::expect_gga(const char* msg) const & {
+    if (tag_() != _Tag::Gga) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<1>(value_);
+}
+
+std::tuple<::cppust::f64, ::cppust::f64>& Sentence::expect_gga(const char* msg) & {
+    if (tag_() != _Tag::Gga) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<1>(value_);
+}
+
+std::tuple<::cppust::f64, ::cppust::f64> Sentence::expect_gga(const char* msg) && {
+    if (tag_() != _Tag::Gga) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(std::get<1>(value_));
+}
+
+::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> Sentence::into_gga() && {
+    if (tag_() != _Tag::Gga) {
+        return ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>>::None();
+    }
+    return ::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>>::Some(std::move(std::get<1>(value_)));
+}
+
+::cppust::Option<std::tuple<::cppust::f64, ::cppust::f64>> Sentence::take_gga() & {
+    return std::move(*this).into_gga();
+}
+
+bool Sentence::is_gsv() const {
+    return tag_() == _Tag::Gsv;
+}
+
+const Satellites& Sentence::gsv_ref_uncheck() const {
+    assert(tag_() == _Tag::Gsv);
+    return std::get<2>(value_);
+}
+
+Satellites& Sentence::gsv_ref_uncheck() {
+    assert(tag_() == _Tag::Gsv);
+    return std::get<2>(value_);
+}
+
+const Satellites& Sentence::gsv_ref() const {
+    if (tag_() != _Tag::Gsv) {
+        throw std::runtime_error("requires Gsv");
+    }
+    return std::get<2>(value_);
+}
+
+Satellites& Sentence::gsv_ref() {
+    if (tag_() != _Tag::Gsv) {
+        throw std::runtime_error("requires Gsv");
+    }
+    return std::get<2>(value_);
+}
+
+const Satellites* Sentence::gsv_ptr() const {
+    if (tag_() != _Tag::Gsv) {
+        return nullptr;
+    }
+    return &std::get<2>(value_);
+}
+
+Satellites* Sentence::gsv_ptr() {
+    if (tag_() != _Tag::Gsv) {
+        return nullptr;
+    }
+    return &std::get<2>(value_);
+}
+
+::cppust::Option<std::reference_wrapper<const Satellites>> Sentence::as_gsv() const {
+    if (tag_() != _Tag::Gsv) {
+        return ::cppust::Option<std::reference_wrapper<const Satellites>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const Satellites>>::Some(std::cref(std::get<2>(value_)));
+}
+
+::cppust::Option<std::reference_wrapper<Satellites>> Sentence::as_gsv() {
+    if (tag_() != _Tag::Gsv) {
+        return ::cppust::Option<std::reference_wrapper<Satellites>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<Satellites>>::Some(std::ref(std::get<2>(value_)));
+}
+
+const Satellites& Sentence::expect_gsv(const char* msg) const & {
+    if (tag_() != _Tag::Gsv) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<2>(value_);
+}
+
+Satellites& Sentence::expect_gsv(const char* msg) & {
+    if (tag_() != _Tag::Gsv) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<2>(value_);
+}
+
+Satellites Sentence::expect_gsv(const char* msg) && {
+    if (tag_() != _Tag::Gsv) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(std::get<2>(value_));
+}
+
+::cppust::Option<Satellites> Sentence::into_gsv() && {
+    if (tag_() != _Tag::Gsv) {
+        return ::cppust::Option<Satellites>::None();
+    }
+    return ::cppust::Option<Satellites>::Some(std::move(std::get<2>(value_)));
+}
+
+::cppust::Option<Satellites> Sentence::take_gsv() & {
+    return std::move(*this).into_gsv();
+}
+
+bool Sentence::is_talker() const {
+    return tag_() == _Tag::Talker;
+}
+
+const std::string& Sentence::talker_ref_uncheck() const {
+    assert(tag_() == _Tag::Talker);
+    return std::get<3>(value_);
+}
+
+std::string& Sentence::talker_ref_uncheck() {
+    assert(tag_() == _Tag::Talker);
+    return std::get<3>(value_);
+}
+
+const std::string& Sentence::talker_ref() const {
+    if (tag_() != _Tag::Talker) {
+        throw std::runtime_error("requires Talker");
+    }
+    return std::get<3>(value_);
+}
+
+std::string& Sentence::talker_ref() {
+    if (tag_() != _Tag::Talker) {
+        throw std::runtime_error("requires Talker");
+    }
+    return std::get<3>(value_);
+}
+
+const std::string* Sentence::talker_ptr() const {
+    if (tag_() != _Tag::Talker) {
+        return nullptr;
+    }
+    return &std::get<3>(value_);
+}
+
+std::string* Sentence::talker_ptr() {
+    if (tag_() != _Tag::Talker) {
+        return nullptr;
+    }
+    return &std::get<3>(value_);
+}
+
+::cppust::Option<std::reference_wrapper<const std::string>> Sentence::as_talker() const {
+    if (tag_() != _Tag::Talker) {
+        return ::cppust::Option<std::reference_wrapper<const std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::string>>::Some(std::cref(std::get<3>(value_)));
+}
+
+::cppust::Option<std::reference_wrapper<std::string>> Sentence::as_talker() {
+    if (tag_() != _Tag::Talker) {
+        return ::cppust::Option<std::reference_wrapper<std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::string>>::Some(std::ref(std::get<3>(value_)));
+}
+
+const std::string& Sentence::expect_talker(const char* msg) const & {
+    if (tag_() != _Tag::Talker) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<3>(value_);
+}
+
+std::string& Sentence::expect_talker(const char* msg) & {
+    if (tag_() != _Tag::Talker) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<3>(value_);
+}
+
+std::string Sentence::expect_talker(const char* msg) && {
+    if (tag_() != _Tag::Talker) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(std::get<3>(value_));
+}
+
+::cppust::Option<std::string> Sentence::into_talker() && {
+    if (tag_() != _Tag::Talker) {
+        return ::cppust::Option<std::string>::None();
+    }
+    return ::cppust::Option<std::string>::Some(std::move(std::get<3>(value_)));
+}
+
+::cppust::Option<std::string> Sentence::take_talker() & {
+    return std::move(*this).into_talker();
+}
+
+bool Sentence::is_checked() const {
+    return tag_() == _Tag::Checked;
+}
+
+const Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref_uncheck() const {
+    assert(tag_() == _Tag::Checked);
+    return std::get<4>(value_);
+}
+
+Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref_uncheck() {
+    assert(tag_() == _Tag::Checked);
+    return std::get<4>(value_);
+}
+
+const Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref() const {
+    if (tag_() != _Tag::Checked) {
+        throw std::runtime_error("requires Checked");
+    }
+    return std::get<4>(value_);
+}
+
+Res<::cppust::Option<::cppust::i32>>& Sentence::checked_ref() {
+    if (tag_() != _Tag::Checked) {
+        throw std::runtime_error("requires Checked");
+    }
+    return std::get<4>(value_);
+}
+
+const Res<::cppust::Option<::cppust::i32>>* Sentence::checked_ptr() const {
+    if (tag_() != _Tag::Checked) {
+        return nullptr;
+    }
+    return &std::get<4>(value_);
+}
+
+Res<::cppust::Option<::cppust::i32>>* Sentence::checked_ptr() {
+    if (tag_() != _Tag::Checked) {
+        return nullptr;
+    }
+    return &std::get<4>(value_);
+}
+
+::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>> Sentence::as_checked() const {
+    if (tag_() != _Tag::Checked) {
+        return ::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const Res<::cppust::Option<::cppust::i32>>>>::Some(std::cref(std::get<4>(value_)));
+}
+
+::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>> Sentence::as_checked() {
+    if (tag_() != _Tag::Checked) {
+        return ::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<Res<::cppust::Option<::cppust::i32>>>>::Some(std::ref(std::get<4>(value_)));
+}
+
+const Res<::cppust::Option<::cppust::i32>>& Sentence::expect_checked(const char* msg) const & {
+    if (tag_() != _Tag::Checked) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<4>(value_);
+}
+
+Res<::cppust::Option<::cppust::i32>>& Sentence::expect_checked(const char* msg) & {
+    if (tag_() != _Tag::Checked) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<4>(value_);
+}
+
+Res<::cppust::Option<::cppust::i32>> Sentence::expect_checked(const char* msg) && {
+    if (tag_() != _Tag::Checked) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(std::get<4>(value_));
+}
+
+::cppust::Option<Res<::cppust::Option<::cppust::i32>>> Sentence::into_checked() && {
+    if (tag_() != _Tag::Checked) {
+        return ::cppust::Option<Res<::cppust::Option<::cppust::i32>>>::None();
+    }
+    return ::cppust::Option<Res<::cppust::Option<::cppust::i32>>>::Some(std::move(std::get<4>(value_)));
+}
+
+::cppust::Option<Res<::cppust::Option<::cppust::i32>>> Sentence::take_checked() & {
+    return std::move(*this).into_checked();
+}
+
+bool Sentence::is_raw() const {
+    return tag_() == _Tag::Raw;
+}
+
+const std::vector<std::string>& Sentence::raw_ref_uncheck() const {
+    assert(tag_() == _Tag::Raw);
+    return std::get<5>(value_);
+}
+
+std::vector<std::string>& Sentence::raw_ref_uncheck() {
+    assert(tag_() == _Tag::Raw);
+    return std::get<5>(value_);
+}
+
+const std::vector<std::string>& Sentence::raw_ref() const {
+    if (tag_() != _Tag::Raw) {
+        throw std::runtime_error("requires Unknown");
+    }
+    return std::get<5>(value_);
+}
+
+std::vector<std::string>& Sentence::raw_ref() {
+    if (tag_() != _Tag::Raw) {
+        throw std::runtime_error("requires Unknown");
+    }
+    return std::get<5>(value_);
+}
+
+const std::vector<std::string>* Sentence::raw_ptr() const {
+    if (tag_() != _Tag::Raw) {
+        return nullptr;
+    }
+    return &std::get<5>(value_);
+}
+
+std::vector<std::string>* Sentence::raw_ptr() {
+    if (tag_() != _Tag::Raw) {
+        return nullptr;
+    }
+    return &std::get<5>(value_);
+}
+
+::cppust::Option<std::reference_wrapper<const std::vector<std::string>>> Sentence::as_raw() const {
+    if (tag_() != _Tag::Raw) {
+        return ::cppust::Option<std::reference_wrapper<const std::vector<std::string>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::vector<std::string>>>::Some(std::cref(std::get<5>(value_)));
+}
+
+::cppust::Option<std::reference_wrapper<std::vector<std::string>>> Sentence::as_raw() {
+    if (tag_() != _Tag::Raw) {
+        return ::cppust::Option<std::reference_wrapper<std::vector<std::string>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::vector<std::string>>>::Some(std::ref(std::get<5>(value_)));
+}
+
+const std::vector<std::string>& Sentence::expect_raw(const char* msg) const & {
+    if (tag_() != _Tag::Raw) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<5>(value_);
+}
+
+std::vector<std::string>& Sentence::expect_raw(const char* msg) & {
+    if (tag_() != _Tag::Raw) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<5>(value_);
+}
+
+std::vector<std::string> Sentence::expect_raw(const char* msg) && {
+    if (tag_() != _Tag::Raw) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(std::get<5>(value_));
+}
+
+::cppust::Option<std::vector<std::string>> Sentence::into_raw() && {
+    if (tag_() != _Tag::Raw) {
+        return ::cppust::Option<std::vector<std::string>>::None();
+    }
+    return ::cppust::Option<std::vector<std::string>>::Some(std::move(std::get<5>(value_)));
+}
+
+::cppust::Option<std::vector<std::string>> Sentence::take_raw() & {
+    return std::move(*this).into_raw();
+}
+
+
+}
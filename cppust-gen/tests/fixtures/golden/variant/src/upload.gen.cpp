@@ -0,0 +1,215 @@
+// THIS FILE IS GENERATED AND MANAGED BY cppust-gen, DO NOT CHANGE
+// PLEASE CHANGE upload.cpp INSTEAD
+
+#include "t/upload.hpp"
+
+namespace t {
+
+// enum constructors
+Upload Upload::Chunk(const std::string& v0) {
+    return Upload(std::in_place_index<0>, v0);
+}
+
+Upload Upload::Chunk(std::string&& value) {
+    return Upload(std::in_place_index<0>, std::move(value));
+}
+
+Upload Upload::Parts(const std::vector<Sentence>& v0, const ::cppust::Option<Fault>& v1) {
+    return Upload(std::in_place_index<1>, v0, v1);
+}
+
+Upload Upload::Parts(std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>&& value) {
+    return Upload(std::in_place_index<1>, std::move(value));
+}
+
+Upload Upload::Parts(std::vector<Sentence>&& v0, ::cppust::Option<Fault>&& v1) {
+    return Upload(std::in_place_index<1>, std::move(v0), std::move(v1));
+}
+
+Upload Upload::Finished() {
+    return Upload(std::in_place_index<2>);
+}
+
+// accessors
+bool Upload::is_chunk() const {
+    return tag_() == _Tag::Chunk;
+}
+
+const std::string& Upload::chunk_ref_uncheck() const {
+    assert(tag_() == _Tag::Chunk);
+    return std::get<0>(value_);
+}
+
+std::string& Upload::chunk_ref_uncheck() {
+    assert(tag_() == _Tag::Chunk);
+    return std::get<0>(value_);
+}
+
+const std::string& Upload::chunk_ref() const {
+    if (tag_() != _Tag::Chunk) {
+        throw std::runtime_error("requires Chunk");
+    }
+    return std::get<0>(value_);
+}
+
+std::string& Upload::chunk_ref() {
+    if (tag_() != _Tag::Chunk) {
+        throw std::runtime_error("requires Chunk");
+    }
+    return std::get<0>(value_);
+}
+
+const std::string* Upload::chunk_ptr() const {
+    if (tag_() != _Tag::Chunk) {
+        return nullptr;
+    }
+    return &std::get<0>(value_);
+}
+
+std::string* Upload::chunk_ptr() {
+    if (tag_() != _Tag::Chunk) {
+        return nullptr;
+    }
+    return &std::get<0>(value_);
+}
+
+::cppust::Option<std::reference_wrapper<const std::string>> Upload::as_chunk() const {
+    if (tag_() != _Tag::Chunk) {
+        return ::cppust::Option<std::reference_wrapper<const std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::string>>::Some(std::cref(std::get<0>(value_)));
+}
+
+::cppust::Option<std::reference_wrapper<std::string>> Upload::as_chunk() {
+    if (tag_() != _Tag::Chunk) {
+        return ::cppust::Option<std::reference_wrapper<std::string>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::string>>::Some(std::ref(std::get<0>(value_)));
+}
+
+const std::string& Upload::expect_chunk(const char* msg) const & {
+    if (tag_() != _Tag::Chunk) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<0>(value_);
+}
+
+std::string& Upload::expect_chunk(const char* msg) & {
+    if (tag_() != _Tag::Chunk) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<0>(value_);
+}
+
+std::string Upload::expect_chunk(const char* msg) && {
+    if (tag_() != _Tag::Chunk) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(std::get<0>(value_));
+}
+
+::cppust::Option<std::string> Upload::into_chunk() && {
+    if (tag_() != _Tag::Chunk) {
+        return ::cppust::Option<std::string>::None();
+    }
+    return ::cppust::Option<std::string>::Some(std::move(std::get<0>(value_)));
+}
+
+::cppust::Option<std::string> Upload::take_chunk() & {
+    return std::move(*this).into_chunk();
+}
+
+bool Upload::is_parts() const {
+    return tag_() == _Tag::Parts;
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref_uncheck() const {
+    assert(tag_() == _Tag::Parts);
+    return std::get<1>(value_);
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref_uncheck() {
+    assert(tag_() == _Tag::Parts);
+    return std::get<1>(value_);
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref() const {
+    if (tag_() != _Tag::Parts) {
+        throw std::runtime_error("requires Parts");
+    }
+    return std::get<1>(value_);
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::parts_ref() {
+    if (tag_() != _Tag::Parts) {
+        throw std::runtime_error("requires Parts");
+    }
+    return std::get<1>(value_);
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* Upload::parts_ptr() const {
+    if (tag_() != _Tag::Parts) {
+        return nullptr;
+    }
+    return &std::get<1>(value_);
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>* Upload::parts_ptr() {
+    if (tag_() != _Tag::Parts) {
+        return nullptr;
+    }
+    return &std::get<1>(value_);
+}
+
+::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> Upload::as_parts() const {
+    if (tag_() != _Tag::Parts) {
+        return ::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::Some(std::cref(std::get<1>(value_)));
+}
+
+::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>> Upload::as_parts() {
+    if (tag_() != _Tag::Parts) {
+        return ::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::None();
+    }
+    return ::cppust::Option<std::reference_wrapper<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>>::Some(std::ref(std::get<1>(value_)));
+}
+
+const std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::expect_parts(const char* msg) const & {
+    if (tag_() != _Tag::Parts) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<1>(value_);
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>& Upload::expect_parts(const char* msg) & {
+    if (tag_() != _Tag::Parts) {
+        throw std::runtime_error(msg);
+    }
+    return std::get<1>(value_);
+}
+
+std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>> Upload::expect_parts(const char* msg) && {
+    if (tag_() != _Tag::Parts) {
+        throw std::runtime_error(msg);
+    }
+    return std::move(std::get<1>(value_));
+}
+
+::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> Upload::into_parts() && {
+    if (tag_() != _Tag::Parts) {
+        return ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>::None();
+    }
+    return ::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>>::Some(std::move(std::get<1>(value_)));
+}
+
+::cppust::Option<std::tuple<std::vector<Sentence>, ::cppust::Option<Fault>>> Upload::take_parts() & {
+    return std::move(*this).into_parts();
+}
+
+bool Upload::is_finished() const {
+    return tag_() == _Tag::Finished;
+}
+
+
+}
@@ -0,0 +1,18 @@
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+enum Sample {
+    Empty,
+    Int(i32),
+    Float(f32),
+    Pair(u8, f32),
+    Bytes(Vec<u8>),
+    Floats(Vec<f32>),
+    Maybe(Option<i16>),
+    Last,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Reordered {
+    A = 3,
+    B = 1,
+    C,
+}
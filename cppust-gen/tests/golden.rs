@@ -0,0 +1,193 @@
+//! Golden test: the code generated from `fixtures/golden/<input>.rs` matches the files saved in
+//! `fixtures/golden/<case>/`, which are then compiled with `-Wall -Werror` under each supported
+//! standard. Run with `CPPUST_GEN_BLESS=1` to save the generated code as the new golden files,
+//! and review the diff before committing it.
+
+mod common;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::Workspace;
+
+/// One way of generating an input
+struct Case {
+    name: &'static str,
+    input: &'static str,
+    args: &'static [&'static str],
+    header_only: bool,
+    stds: &'static [&'static str],
+    cxx_args: &'static [&'static str],
+}
+
+const ALL_STDS: &[&str] = &["c++11", "c++14", "c++17"];
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden")
+}
+
+/// Generated files under `root`, relative to it and sorted, skipping the hidden merge bases
+fn files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        if !dir.exists() {
+            continue;
+        }
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.file_name().unwrap().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path.strip_prefix(root).unwrap().to_path_buf());
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+fn bless(generated: &Path, expected: &Path) {
+    if expected.exists() {
+        fs::remove_dir_all(expected).unwrap();
+    }
+    for file in files(generated) {
+        let path = expected.join(&file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::copy(generated.join(&file), path).unwrap();
+    }
+}
+
+fn compare(generated: &Path, expected: &Path, case: &str) {
+    let hint = "run with CPPUST_GEN_BLESS=1 to update the golden files";
+    assert_eq!(
+        files(generated),
+        files(expected),
+        "{}: generated files differ, {}",
+        case,
+        hint
+    );
+
+    for file in files(generated) {
+        let actual = fs::read_to_string(generated.join(&file)).unwrap();
+        let golden = fs::read_to_string(expected.join(&file)).unwrap();
+        if actual == golden {
+            continue;
+        }
+        let line = actual
+            .lines()
+            .zip(golden.lines())
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| actual.lines().count().min(golden.lines().count()));
+        panic!(
+            "{}: {} differs from line {}\n  generated: {:?}\n  golden:    {:?}\n{}",
+            case,
+            file.display(),
+            line + 1,
+            actual.lines().nth(line).unwrap_or("<end of file>"),
+            golden.lines().nth(line).unwrap_or("<end of file>"),
+            hint
+        );
+    }
+}
+
+/// A program including every generated header, so header-only code is compiled too
+fn main_source(ws: &Workspace) -> PathBuf {
+    let include = ws.path("include");
+    let mut main = String::new();
+    for file in files(&include) {
+        let name = file.file_name().unwrap().to_string_lossy();
+        // `.inc.hpp`, `.fmt.hpp` and `.impl.hpp` are parts of the class headers
+        if name.matches('.').count() == 1 && name.ends_with(".hpp") {
+            main.push_str(&format!("#include \"{}\"\n", file.display()));
+        }
+    }
+    main.push_str("\nint main() {\n    return 0;\n}\n");
+    ws.write("main.cpp", &main)
+}
+
+fn check(case: &Case) {
+    let ws = Workspace::new(&format!("golden-{}", case.name));
+    let input = ws.write(
+        case.input,
+        &fs::read_to_string(golden_dir().join(case.input)).unwrap(),
+    );
+    if case.header_only {
+        ws.generate_header_only(&input, "t", case.args);
+    } else {
+        ws.generate(&input, "t", case.args);
+    }
+
+    let expected = golden_dir().join(case.name);
+    for dir in ["include", "src"] {
+        if std::env::var_os("CPPUST_GEN_BLESS").is_some() {
+            bless(&ws.path(dir), &expected.join(dir));
+        } else {
+            compare(&ws.path(dir), &expected.join(dir), case.name);
+        }
+    }
+
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+    let mut sources = ws.generated_sources();
+    sources.push(main_source(&ws));
+    for std in case.stds {
+        ws.compile(&cxx, std, &sources, case.cxx_args);
+    }
+}
+
+#[test]
+fn test_union_golden() {
+    check(&Case {
+        name: "union",
+        input: "nmea.rs",
+        args: &[],
+        header_only: false,
+        stds: ALL_STDS,
+        cxx_args: &[],
+    });
+}
+
+#[test]
+fn test_variant_golden() {
+    check(&Case {
+        name: "variant",
+        input: "nmea.rs",
+        args: &["--backend", "variant", "--cpp-std", "17"],
+        header_only: false,
+        stds: &["c++17"],
+        cxx_args: &[],
+    });
+}
+
+#[test]
+fn test_amalgamated_golden() {
+    check(&Case {
+        name: "amalgamated",
+        input: "nmea.rs",
+        args: &["--amalgamate", "nmea"],
+        header_only: false,
+        stds: ALL_STDS,
+        cxx_args: &[],
+    });
+}
+
+#[test]
+fn test_embedded_golden() {
+    check(&Case {
+        name: "embedded",
+        input: "embedded.rs",
+        args: &["--no-exceptions", "--no-iostream"],
+        header_only: true,
+        stds: ALL_STDS,
+        cxx_args: &["-fno-exceptions"],
+    });
+}
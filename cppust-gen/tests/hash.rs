@@ -0,0 +1,84 @@
+//! Runtime test: the generated `std::hash` specializations hash equal values equally, payloads
+//! of `Option` and `Result` included, so the classes work as `std::unordered_set` keys.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+type Res<T> = Result<T, String>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Key {
+    Empty,
+    Id(u32),
+    Name(String),
+    Path(Vec<String>),
+    Pair(u8, Option<i64>),
+    Nested(Inner),
+    Reply(Result<u32, Inner>),
+    Status(Res<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Inner {
+    Off,
+    Level(u8),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/key.hpp"
+#include "check.hpp"
+
+#include <unordered_set>
+
+using t::Key;
+
+std::vector<Key> samples() {
+    return {
+        Key::Empty(),
+        Key::Id(7),
+        Key::Id(8),
+        Key::Name("a"),
+        Key::Name("b"),
+        Key::Path(std::vector<std::string>{"a", "b"}),
+        Key::Path(std::vector<std::string>{"ab"}),
+        Key::Pair(1, cppust::Option<cppust::i64>::None()),
+        Key::Pair(1, cppust::Option<cppust::i64>::Some(0)),
+        Key::Nested(t::Inner::Off()),
+        Key::Nested(t::Inner::Level(0)),
+        Key::Reply(cppust::Result<cppust::u32, t::Inner>::Ok(0)),
+        Key::Reply(cppust::Result<cppust::u32, t::Inner>::Err(t::Inner::Off())),
+        Key::Status(t::Res<cppust::u8>::Ok(1)),
+        Key::Status(t::Res<cppust::u8>::Err("1")),
+    };
+}
+
+int main() {
+    // equal values built separately hash equally
+    auto first = samples(), second = samples();
+    for (size_t i = 0; i < first.size(); i++) {
+        CHECK(first[i] == second[i]);
+        CHECK(std::hash<Key>()(first[i]) == std::hash<Key>()(second[i]));
+    }
+
+    std::unordered_set<Key> set(first.begin(), first.end());
+    set.insert(second.begin(), second.end());
+    CHECK(set.size() == first.size());
+    for (const auto& key : second) {
+        CHECK(set.count(key) == 1);
+    }
+    CHECK(set.count(Key::Id(9)) == 0);
+    CHECK(set.count(Key::Name("")) == 0);
+
+    std::cout<<set.size()<<"\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_hash() {
+    for (backend, cfg) in [("union", Cfg::UNION), ("variant", Cfg::VARIANT)] {
+        common::compile_and_run(&format!("hash-{}", backend), INPUT, MAIN, &cfg, "15\n");
+    }
+}
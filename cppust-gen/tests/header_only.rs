@@ -0,0 +1,73 @@
+//! Compile test: `--header-only` output needs no generated sources, and the inline
+//! definitions can be included from several translation units.
+
+mod common;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Packet {
+    #[default]
+    Empty,
+    Bytes(Vec<u8>),
+    Pair(u8, i32),
+}
+
+#[derive(Debug)]
+enum Owned {
+    Nothing,
+    Text(String),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/packet.hpp"
+#include "t/owned.hpp"
+
+#include <unordered_set>
+
+std::size_t count_unique();
+
+int main() {
+    auto packet = t::Packet::Pair(1, 2);
+    if (!(t::Packet() < packet) || packet.pair_ref() != std::make_tuple(cppust::u8(1), 2)) return 1;
+
+    auto owned = t::Owned::Text("text");
+    t::Owned moved(std::move(owned));
+    if (moved.text_ref() != "text") return 1;
+    std::cout<<cppust::as_debug(t::Owned::Nothing())<<" "<<count_unique()<<"\n";
+    return 0;
+}
+"#;
+
+const OTHER: &str = r#"#include "t/packet.hpp"
+
+#include <unordered_set>
+
+std::size_t count_unique() {
+    std::unordered_set<t::Packet> packets{
+        t::Packet::Empty(),
+        t::Packet::Bytes(std::vector<cppust::u8>{1}),
+        t::Packet::Bytes(std::vector<cppust::u8>{1}),
+    };
+    return packets.size();
+}
+"#;
+
+#[test]
+fn test_header_only() {
+    for (backend, cfg) in [("union", Cfg::UNION), ("variant", Cfg::VARIANT)] {
+        let ws = Workspace::new(&format!("header-only-{}", backend));
+        let input = ws.write("header_only.rs", INPUT);
+        ws.generate_header_only(&input, "t", cfg.args);
+
+        assert!(!ws.path("src").exists(), "{}", backend);
+        assert!(ws.path("include/t/packet.impl.hpp").exists(), "{}", backend);
+
+        let cfg = Cfg {
+            files: &[("other.cpp", OTHER)],
+            ..cfg
+        };
+        ws.compile_and_run(MAIN, &cfg, "Nothing 2\n");
+    }
+}
@@ -0,0 +1,181 @@
+//! Layout test: classes of enums marked `#[cppust(layout)]` assert the layout of their
+//! `#[repr(C)]` counterpart when compiling, which is compared with the sizes and offsets rustc
+//! gives to the same enums.
+
+mod common;
+
+use std::{fs, process::Command};
+
+use common::Workspace;
+
+const INPUT: &str = r#"
+type Level = i16;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, u8)]
+#[cppust(layout)]
+pub enum Sample {
+    Empty,
+    Byte(u8),
+    Wide(u64),
+    Flag(bool),
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cppust(layout)]
+pub enum Reading {
+    Raw(Sample),
+    Temp(Level),
+    Letter(char),
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, u16)]
+#[cppust(layout)]
+pub enum Short {
+    Idle = 3,
+    Count(u8),
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, u8)]
+#[cppust(layout)]
+pub enum Edge {
+    Level(u8),
+    Top = 255,
+}
+"#;
+
+const RUST_MAIN: &str = r#"
+include!("nmea.rs");
+
+fn offset<E, T>(value: &E, payload: &T) -> usize {
+    payload as *const T as usize - value as *const E as usize
+}
+
+fn print<E, T>(name: &str, value: &E, payload: &T) {
+    println!(
+        "{} {} {} {}",
+        name,
+        std::mem::size_of::<E>(),
+        std::mem::align_of::<E>(),
+        offset(value, payload)
+    );
+}
+
+fn main() {
+    let sample = Sample::Wide(1);
+    if let Sample::Wide(payload) = &sample {
+        print("Sample", &sample, payload);
+    }
+    let reading = Reading::Temp(-4);
+    if let Reading::Temp(payload) = &reading {
+        print("Reading", &reading, payload);
+    }
+    let short = Short::Count(2);
+    if let Short::Count(payload) = &short {
+        print("Short", &short, payload);
+    }
+    let edge = Edge::Level(1);
+    if let Edge::Level(payload) = &edge {
+        print("Edge", &edge, payload);
+    }
+}
+"#;
+
+const CPP_MAIN: &str = r#"
+#include "t/reading.hpp"
+#include "t/short.hpp"
+#include "t/edge.hpp"
+
+template <typename E, typename T>
+void print(const char* name, const E& value, const T& payload) {
+    std::cout<<name<<" "<<sizeof(E)<<" "<<alignof(E)<<" "
+        <<(reinterpret_cast<const char*>(&payload) - reinterpret_cast<const char*>(&value))<<"\n";
+}
+
+int main() {
+    t::Sample sample = t::Sample::Wide(1);
+    print("Sample", sample, sample.wide_ref());
+    t::Reading reading = t::Reading::Temp(-4);
+    print("Reading", reading, reading.temp_ref());
+    t::Short short_ = t::Short::Count(2);
+    print("Short", short_, short_.count_ref());
+    t::Edge edge = t::Edge::Level(1);
+    print("Edge", edge, edge.level_ref());
+    return 0;
+}
+"#;
+
+#[test]
+fn test_layout() {
+    let ws = Workspace::new("layout");
+    let input = ws.write("nmea.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+
+    let inc = fs::read_to_string(ws.path("include/t/short.inc.hpp")).unwrap();
+    assert!(inc.contains("    enum class _Tag : ::cppust::u16 {\n"));
+    assert!(inc.contains("    _Tag tag_;\n    _Union union_;\n"));
+    assert!(inc.contains(
+        "        static_assert(sizeof(Short) == _Layout::size, \
+         \"size of Short differs from Rust\");\n"
+    ));
+
+    // the valueless state takes the first value left when the last one is a variant
+    let inc = fs::read_to_string(ws.path("include/t/edge.inc.hpp")).unwrap();
+    assert!(inc.contains("        Top = 255,\n        _Valueless = 1,\n"));
+
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+    let mut sources = ws.generated_sources();
+    sources.push(ws.write("main.cpp", CPP_MAIN));
+    let exe = ws.compile(&cxx, "c++11", &sources, &[]);
+    let cpp_layout = ws.run(&exe);
+
+    let rustc = match common::rustc() {
+        Some(rustc) => rustc,
+        None => return,
+    };
+    // rustc does not know the generator attributes
+    ws.write("nmea.rs", &INPUT.replace("#[cppust(layout)]\n", ""));
+    let rust_exe = ws.path("rust-layout");
+    let output = Command::new(rustc)
+        .args(["--edition", "2021", "-A", "dead_code", "-o"])
+        .arg(&rust_exe)
+        .arg(ws.write("main.rs", RUST_MAIN))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "rustc failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(cpp_layout, ws.run(&rust_exe));
+}
+
+#[test]
+fn test_invalid_layout() {
+    let ws = Workspace::new("layout-invalid");
+    for input in [
+        "#[cppust(layout)] enum A { B(u8) }",
+        "#[repr(C)] #[cppust(layout)] enum A { B(String) }",
+        "#[repr(C)] #[cppust(layout)] enum A { B(C) } #[repr(C)] enum C { D(u8) }",
+    ] {
+        let input = ws.write("a.rs", input);
+        let output = Command::new(env!("CARGO_BIN_EXE_cppust-gen"))
+            .arg("-i")
+            .arg(&input)
+            .arg("-I")
+            .arg(ws.path("include"))
+            .arg("-O")
+            .arg(ws.path("src"))
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("layout error: [A]"));
+    }
+}
@@ -0,0 +1,148 @@
+//! Generation test: regenerating merges the changes of the user header with the new template,
+//! and reports conflicting changes with markers.
+
+mod common;
+
+use std::fs;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+#[derive(Debug)]
+enum Status {
+    Idle,
+    Busy(u8),
+}
+"#;
+
+// adds `#include <string>` after `#include <utility>`
+const CHANGED_INPUT: &str = r#"
+#[derive(Debug)]
+enum Status {
+    Idle,
+    Busy(u8),
+    Failed(String),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/status.hpp"
+
+int main() {
+    std::cout<<cppust::as_debug(t::Status::Failed("disk"))<<" "<<t::Status::Idle().answer()<<"\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_merge_user_header() {
+    let ws = Workspace::new("merge");
+    let input = ws.write("status.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+    // baselines stay out of the installed header directory
+    assert!(ws.path("src/.cppust-gen/t/status.hpp").exists());
+    assert!(!ws.path("include/.cppust-gen").exists());
+
+    let header_path = ws.path("include/t/status.hpp");
+    let header = fs::read_to_string(&header_path).unwrap();
+    fs::write(
+        &header_path,
+        header
+            .replace(
+                "#include <functional>\n",
+                "#include <functional>\n#include <vector>\n",
+            )
+            .replace(
+                "    // WRITE YOUR OWN CODE HERE\n",
+                "    int answer() const { return 42; }\n",
+            ),
+    )
+    .unwrap();
+
+    // unchanged input keeps the user changes as they are
+    ws.generate(&input, "t", &[]);
+    let edited = fs::read_to_string(&header_path).unwrap();
+    assert!(edited.contains("    int answer() const { return 42; }\n"));
+
+    let input = ws.write("status.rs", CHANGED_INPUT);
+    ws.generate(&input, "t", &[]);
+    let merged = fs::read_to_string(&header_path).unwrap();
+    assert!(merged.contains("#include <functional>\n#include <vector>\n"));
+    assert!(merged.contains("#include <utility>\n#include <string>\n"));
+    assert!(merged.contains("    int answer() const { return 42; }\n"));
+    assert!(!merged.contains("<<<<<<<"));
+
+    ws.compile_and_run(MAIN, &Cfg::UNION, "Failed(disk) 42\n");
+}
+
+#[test]
+fn test_merge_conflicts() {
+    let ws = Workspace::new("merge-conflicts");
+    let input = ws.write("status.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+
+    let header_path = ws.path("include/t/status.hpp");
+    let header = fs::read_to_string(&header_path).unwrap();
+    fs::write(
+        &header_path,
+        header.replace(
+            "#include <utility>\n",
+            "#include <utility>\n#include <map>\n",
+        ),
+    )
+    .unwrap();
+
+    let input = ws.write("status.rs", CHANGED_INPUT);
+    ws.generate(&input, "t", &[]);
+    let merged = fs::read_to_string(&header_path).unwrap();
+    assert!(merged.contains(
+        "#include <utility>\n\
+         <<<<<<< status.hpp\n\
+         #include <map>\n\
+         =======\n\
+         #include <string>\n\
+         >>>>>>> cppust-gen\n"
+    ));
+
+    // the conflict is resolved against the new baseline, so it is not reported again
+    fs::write(
+        &header_path,
+        header.replace(
+            "#include <utility>\n",
+            "#include <utility>\n#include <map>\n#include <string>\n",
+        ),
+    )
+    .unwrap();
+    ws.generate(&input, "t", &[]);
+    assert!(!fs::read_to_string(&header_path)
+        .unwrap()
+        .contains("<<<<<<<"));
+}
+
+#[test]
+fn test_baseline_dir() {
+    let ws = Workspace::new("merge-baseline-dir");
+    let baseline_dir = ws.path("baselines");
+    let args = ["--baseline-dir", baseline_dir.to_str().unwrap()];
+    let input = ws.write("status.rs", INPUT);
+    ws.generate(&input, "t", &args);
+    assert!(ws.path("baselines/t/status.hpp").exists());
+    assert!(!ws.path("src/.cppust-gen").exists());
+
+    let header_path = ws.path("include/t/status.hpp");
+    let header = fs::read_to_string(&header_path).unwrap();
+    fs::write(
+        &header_path,
+        header.replace(
+            "    // WRITE YOUR OWN CODE HERE\n",
+            "    int answer() const { return 42; }\n",
+        ),
+    )
+    .unwrap();
+
+    let input = ws.write("status.rs", CHANGED_INPUT);
+    ws.generate(&input, "t", &args);
+    let merged = fs::read_to_string(&header_path).unwrap();
+    assert!(merged.contains("#include <utility>\n#include <string>\n"));
+    assert!(merged.contains("    int answer() const { return 42; }\n"));
+    assert!(!ws.path("include/.cppust-gen").exists());
+}
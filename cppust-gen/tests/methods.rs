@@ -0,0 +1,140 @@
+//! Compile test: the methods of inherent `impl` blocks are declared into the generated classes,
+//! the user defines them.
+
+mod common;
+
+use std::fs;
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug)]
+enum DecoderStatus {
+    Idle,
+    Done(Frame),
+    Failed(String),
+}
+
+impl DecoderStatus {
+    /// Whether decoding stopped
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, DecoderStatus::Idle)
+    }
+
+    pub fn reset(&mut self) {
+        *self = DecoderStatus::Idle;
+    }
+
+    pub fn into_frame(self) -> Option<Frame> {
+        match self {
+            DecoderStatus::Done(frame) => Some(frame),
+            _ => None,
+        }
+    }
+
+    pub fn describe(&self, prefix: &str) -> String {
+        format!("{}{:?}", prefix, self)
+    }
+
+    pub fn from_fault(fault: &Fault) -> Self {
+        DecoderStatus::Failed(format!("{:?}", fault))
+    }
+
+    #[cppust(delete)]
+    pub fn frame_copy(&self) -> Frame {
+        unimplemented!()
+    }
+
+    #[cppust(skip)]
+    pub fn bytes(&self) -> impl Iterator<Item = u8> {
+        std::iter::empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Frame {
+    Gga(u16),
+}
+
+#[derive(Clone, Debug)]
+enum Fault {
+    Checksum,
+}
+
+impl Fault {
+    pub fn status(&self) -> DecoderStatus {
+        DecoderStatus::from_fault(self)
+    }
+}
+"#;
+
+const DEFINITIONS: &str = r#"
+namespace t {
+
+INLINE bool DecoderStatus::is_terminal() const { return !is_idle(); }
+
+INLINE void DecoderStatus::reset() { *this = Idle(); }
+
+INLINE cppust::Option<Frame> DecoderStatus::into_frame() && {
+    return std::move(*this).into_done();
+}
+
+INLINE std::string DecoderStatus::describe(const std::string& prefix) const {
+    return prefix + (is_failed() ? failed_ref() : "?");
+}
+
+INLINE DecoderStatus DecoderStatus::from_fault(const Fault&) { return Failed("checksum"); }
+
+INLINE DecoderStatus Fault::status() const { return DecoderStatus::from_fault(*this); }
+
+}
+
+int main() {
+    t::DecoderStatus status = t::Fault::Checksum().status();
+    std::cout<<status.is_terminal()<<" "<<status.describe("status: ");
+    status.reset();
+    std::cout<<" "<<status.is_terminal()<<" "
+        <<cppust::as_debug(t::DecoderStatus::Done(t::Frame::Gga(7)).into_frame())<<"\n";
+    return 0;
+}
+"#;
+
+const EXPECTED: &str = "1 status: checksum 0 Some(Gga(7))\n";
+
+#[test]
+fn test_methods() {
+    let ws = Workspace::new("methods");
+    let input = ws.write("decoder.rs", INPUT);
+    ws.generate(&input, "t", &[]);
+
+    let inc = fs::read_to_string(ws.path("include/t/decoder_status.inc.hpp")).unwrap();
+    assert!(inc.contains(
+        "public: // methods\n    /// @brief Whether decoding stopped\n    bool is_terminal() const;\n"
+    ));
+    assert!(inc.contains("    static DecoderStatus from_fault(const Fault& fault);\n"));
+    assert!(inc.contains("    Frame frame_copy() const = delete;\n"));
+    assert!(!inc.contains("bytes"));
+
+    // `Fault` only appears in a signature, so it is declared rather than included
+    let header = fs::read_to_string(ws.path("include/t/decoder_status.hpp")).unwrap();
+    assert!(header.contains("#include \"t/frame.hpp\"\n\nnamespace t { class Fault; }\n"));
+
+    let main = format!(
+        "#include \"t/decoder_status.hpp\"\n#include \"t/fault.hpp\"\n#define INLINE\n{}",
+        DEFINITIONS
+    );
+    ws.compile_and_run(&main, &Cfg::UNION, EXPECTED);
+}
+
+#[test]
+fn test_amalgamated_methods() {
+    let ws = Workspace::new("methods-amalgamated");
+    let input = ws.write("decoder.rs", INPUT);
+    ws.generate_header_only(&input, "t", &["--amalgamate", "decoder"]);
+
+    let main = format!(
+        "#include \"t/decoder.hpp\"\n#define INLINE inline\n{}",
+        DEFINITIONS
+    );
+    ws.compile_and_run(&main, &Cfg::UNION, EXPECTED);
+}
@@ -0,0 +1,94 @@
+//! Compile test: generated moves are `noexcept` when the payloads allow it, and
+//! rvalue factories, per-field ones included, and `emplace_xxx` never copy the payload.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug, PartialEq)]
+enum Payload {
+    Empty,
+    Bytes(Vec<u8>),
+    Pair(u8, Vec<u8>),
+    Maybe(Option<Vec<u8>>),
+}
+
+#[derive(Debug)]
+enum Owned {
+    Nothing,
+    Bytes(Vec<u8>),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/payload.hpp"
+#include "t/owned.hpp"
+#include "check.hpp"
+
+#include <type_traits>
+
+static_assert(std::is_nothrow_move_constructible<t::Payload>::value, "Payload moves must be noexcept");
+static_assert(std::is_nothrow_move_assignable<t::Payload>::value, "Payload moves must be noexcept");
+static_assert(std::is_nothrow_move_constructible<t::Owned>::value, "Owned moves must be noexcept");
+static_assert(!std::is_copy_constructible<t::Owned>::value, "Owned is not Clone");
+
+int main() {
+    // rvalue factories steal the buffer
+    std::vector<cppust::u8> bytes{1, 2, 3};
+    const cppust::u8* data = bytes.data();
+    auto payload = t::Payload::Bytes(std::move(bytes));
+    CHECK(payload.bytes_ref().data() == data);
+
+    std::vector<cppust::u8> second{4, 5};
+    data = second.data();
+    auto pair = t::Payload::Pair(std::make_tuple(cppust::u8(9), std::move(second)));
+    CHECK(std::get<1>(pair.pair_ref()).data() == data);
+
+    // so do the per-field ones, without building a tuple first
+    std::vector<cppust::u8> third{6};
+    data = third.data();
+    pair = t::Payload::Pair(7, std::move(third));
+    CHECK(std::get<0>(pair.pair_ref()) == 7 && std::get<1>(pair.pair_ref()).data() == data);
+
+    // lvalue fields are copied
+    const std::vector<cppust::u8> kept{8};
+    pair = t::Payload::Pair(cppust::u8(1), kept);
+    CHECK(std::get<1>(pair.pair_ref()) == kept && std::get<1>(pair.pair_ref()).data() != kept.data());
+
+    // vector growth moves the elements
+    std::vector<t::Payload> payloads;
+    payloads.push_back(std::move(payload));
+    data = payloads[0].bytes_ref().data();
+    for (int i = 0; i < 32; i++) {
+        payloads.push_back(t::Payload::Empty());
+    }
+    CHECK(payloads[0].bytes_ref().data() == data);
+
+    // emplace constructs the payload from the arguments
+    auto& emplaced = payloads[1].emplace_bytes(3, cppust::u8(7));
+    CHECK(payloads[1].is_bytes());
+    CHECK(emplaced == std::vector<cppust::u8>({7, 7, 7}));
+
+    payloads[1].emplace_pair(cppust::u8(1), std::vector<cppust::u8>{2});
+    CHECK(payloads[1].pair_ref() == std::make_tuple(cppust::u8(1), std::vector<cppust::u8>{2}));
+
+    payloads[1].emplace_maybe(cppust::Option<std::vector<cppust::u8>>::None());
+    CHECK(payloads[1].maybe_ref().is_none());
+    CHECK(payloads[1] == t::Payload::Maybe(cppust::Option<std::vector<cppust::u8>>::None()));
+
+    auto owned = t::Owned::Nothing();
+    owned.emplace_bytes(2, cppust::u8(5));
+    t::Owned moved(std::move(owned));
+    CHECK(moved.bytes_ref() == std::vector<cppust::u8>({5, 5}));
+
+    std::cout<<"ok\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_moves_and_factories() {
+    for (backend, cfg) in [("union", Cfg::UNION), ("variant", Cfg::VARIANT)] {
+        common::compile_and_run(&format!("moves-{}", backend), INPUT, MAIN, &cfg, "ok\n");
+    }
+}
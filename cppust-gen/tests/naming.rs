@@ -0,0 +1,146 @@
+//! Compile test: every naming profile, and a custom naming config, produce code that
+//! compiles and is usable through the styled names.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+enum ByteBuffer {
+    Empty,
+    Bytes(Vec<u8>),
+    Pair(u8, i32),
+}
+"#;
+
+const GOOGLE: &str = r#"#include "t/byte_buffer.hpp"
+
+int main() {
+    auto buffer = t::ByteBuffer::Bytes(std::vector<cppust::u8>{1, 2});
+    if (!buffer.IsBytes() || buffer.BytesRef().size() != 2 || buffer.BytesPtr() == nullptr) return 1;
+    if (buffer.AsPair().is_some() || buffer.ExpectBytes("bytes").size() != 2) return 1;
+    buffer.EmplacePair(cppust::u8(1), 2);
+    if (std::get<1>(buffer.PairRefUnchecked()) != 2) return 1;
+    if (buffer.PartialCmp(t::ByteBuffer::Empty()).is_none()) return 1;
+    std::cout<<cppust::as_debug(t::ByteBuffer::Bytes(std::vector<cppust::u8>{1, 2}))<<"\n";
+    return 0;
+}
+"#;
+
+const STD: &str = r#"#include "t/byte_buffer.hpp"
+
+int main() {
+    auto buffer = t::byte_buffer::bytes(std::vector<cppust::u8>{1, 2});
+    if (!buffer.is_bytes() || buffer.bytes_ref_unchecked().size() != 2) return 1;
+    buffer.emplace_pair(cppust::u8(1), 2);
+    if (std::get<1>(std::move(buffer).into_pair().unwrap()) != 2) return 1;
+    if (t::byte_buffer::empty().partial_cmp(t::byte_buffer::empty()).is_none()) return 1;
+    std::cout<<cppust::as_debug(t::byte_buffer::bytes(std::vector<cppust::u8>{1, 2}))<<"\n";
+    return 0;
+}
+"#;
+
+const LLVM: &str = r#"#include "t/ByteBuffer.hpp"
+
+int main() {
+    auto buffer = t::ByteBuffer::bytes(std::vector<cppust::u8>{1, 2});
+    if (!buffer.isBytes() || buffer.getBytes().size() != 2 || buffer.getIfPair() != nullptr) return 1;
+    buffer.emplacePair(cppust::u8(1), 2);
+    if (std::get<1>(buffer.getPairUnchecked()) != 2) return 1;
+    if (buffer.partialCmp(t::ByteBuffer::empty()).is_none()) return 1;
+    std::cout<<cppust::as_debug(t::ByteBuffer::bytes(std::vector<cppust::u8>{1, 2}))<<"\n";
+    return 0;
+}
+"#;
+
+const CUSTOM_CONFIG: &str = r#"
+# google style, but with accessors closer to std::optional
+base = google
+ref = {Pascal}Value
+ptr = {Pascal}If
+file = {Pascal}
+"#;
+
+const CUSTOM: &str = r#"#include "t/ByteBuffer.hpp"
+
+int main() {
+    auto buffer = t::ByteBuffer::Pair(cppust::u8(1), 2);
+    if (!buffer.IsPair() || std::get<1>(buffer.PairValue()) != 2 || buffer.BytesIf() != nullptr) return 1;
+    std::cout<<cppust::as_debug(t::ByteBuffer::Bytes(std::vector<cppust::u8>{1, 2}))<<"\n";
+    return 0;
+}
+"#;
+
+const KEYWORDS: &str = r#"
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+enum Keyword {
+    Default,
+    Delete(u8),
+    r#Type(i32),
+    Int,
+    r#while,
+}
+
+#[derive(Debug)]
+enum Register {
+    Class(u8),
+    Auto,
+}
+"#;
+
+const KEYWORDS_MAIN: &str = r#"#include "t/keyword.hpp"
+#include "t/register.hpp"
+
+int main() {
+    auto reg = t::register_::class_(1);
+    if (!reg.is_class() || reg.class_ref() != 1 || t::register_::auto_().is_class()) return 1;
+    if (!t::keyword::delete_(1).is_delete()) return 1;
+    if (!(t::keyword::default_() < t::keyword::int_())) return 1;
+    std::cout<<cppust::as_debug(t::keyword::type(3))<<" "<<cppust::as_debug(t::keyword::while_())<<"\n";
+    return 0;
+}
+"#;
+
+#[test]
+fn test_naming_profiles_compile() {
+    for (name, main) in [
+        ("google", GOOGLE),
+        ("std", STD),
+        ("llvm", LLVM),
+        ("custom", CUSTOM),
+    ] {
+        let naming = if name == "custom" {
+            "naming.conf"
+        } else {
+            name
+        };
+        let cfg = Cfg {
+            args: &["--naming", naming],
+            files: &[("naming.conf", CUSTOM_CONFIG)],
+            ..Cfg::UNION
+        };
+        common::compile_and_run(
+            &format!("naming-{}", name),
+            INPUT,
+            main,
+            &cfg,
+            "Bytes(<01 02>)\n",
+        );
+    }
+}
+
+#[test]
+fn test_keywords_compile() {
+    let cfg = Cfg {
+        args: &["--naming", "std"],
+        ..Cfg::UNION
+    };
+    common::compile_and_run(
+        "naming-keywords",
+        KEYWORDS,
+        KEYWORDS_MAIN,
+        &cfg,
+        "Type(3) while\n",
+    );
+}
@@ -0,0 +1,167 @@
+//! Property test: the generated C++ comparison operators must agree with
+//! Rust's derived `PartialEq`/`PartialOrd`/`Ord` on randomly generated values,
+//! NaN payloads compared with themselves included.
+
+mod common;
+
+use common::{Rng, Workspace};
+
+include!("fixtures/ordering.rs");
+
+const FLOATS: [f32; 5] = [0.0, -0.0, 1.5, -2.25, f32::NAN];
+
+fn random_sample(rng: &mut Rng) -> Sample {
+    match rng.below(8) {
+        0 => Sample::Empty,
+        1 => Sample::Int(rng.below(5) as i32 - 2),
+        2 => Sample::Float(*rng.pick(&FLOATS)),
+        3 => Sample::Pair(rng.below(3) as u8, *rng.pick(&FLOATS)),
+        4 => Sample::Bytes((0..rng.below(4)).map(|_| rng.below(3) as u8).collect()),
+        5 => Sample::Floats((0..rng.below(3)).map(|_| *rng.pick(&FLOATS)).collect()),
+        6 => Sample::Maybe(match rng.below(3) {
+            0 => None,
+            _ => Some(rng.below(3) as i16 - 1),
+        }),
+        _ => Sample::Last,
+    }
+}
+
+fn float_to_cpp(v: f32) -> String {
+    if v.is_nan() {
+        "std::numeric_limits<float>::quiet_NaN()".to_owned()
+    } else {
+        format!("{:?}f", v)
+    }
+}
+
+fn sample_to_cpp(sample: &Sample) -> String {
+    match sample {
+        Sample::Empty => "t::Sample::Empty()".to_owned(),
+        Sample::Int(v) => format!("t::Sample::Int({})", v),
+        Sample::Float(v) => format!("t::Sample::Float({})", float_to_cpp(*v)),
+        Sample::Pair(a, b) => format!("t::Sample::Pair({}, {})", a, float_to_cpp(*b)),
+        Sample::Bytes(v) => format!(
+            "t::Sample::Bytes(std::vector<cppust::u8>{{{}}})",
+            v.iter().map(|b| b.to_string()).collect::<Vec<_>>().join(", ")
+        ),
+        Sample::Floats(v) => format!(
+            "t::Sample::Floats(std::vector<cppust::f32>{{{}}})",
+            v.iter().map(|f| float_to_cpp(*f)).collect::<Vec<_>>().join(", ")
+        ),
+        Sample::Maybe(None) => "t::Sample::Maybe(cppust::Option<cppust::i16>::None())".to_owned(),
+        Sample::Maybe(Some(v)) => format!(
+            "t::Sample::Maybe(cppust::Option<cppust::i16>::Some({}))",
+            v
+        ),
+        Sample::Last => "t::Sample::Last()".to_owned(),
+    }
+}
+
+fn reordered_to_cpp(reordered: &Reordered) -> String {
+    format!("t::Reordered::{:?}()", reordered)
+}
+
+/// One line per pair, with `==`, `!=`, then `<`, `<=`, `>`, `>=` as `0`/`1`
+fn expected<T: PartialOrd>(values: &[T], three_way: bool) -> String {
+    let mut output = String::new();
+    let bits = |results: &[bool]| {
+        results
+            .iter()
+            .map(|r| if *r { '1' } else { '0' })
+            .collect::<String>()
+    };
+
+    for a in values {
+        for b in values {
+            output.push_str(&bits(&[a == b, a != b]));
+            let line = bits(&[a < b, a <= b, a > b, a >= b]);
+            output.push_str(&line);
+            if three_way {
+                output.push_str(&line);
+            }
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+fn cpp_main(samples: &[Sample], reordered: &[Reordered]) -> String {
+    format!(
+        r#"#include "t/sample.hpp"
+#include "t/reordered.hpp"
+
+#include <limits>
+
+template <typename T>
+void compare_all(const std::vector<T>& values) {{
+    for (const auto& a : values) {{
+        for (const auto& b : values) {{
+            std::cout<<(a == b)<<(a != b)<<(a < b)<<(a <= b)<<(a > b)<<(a >= b);
+#if __cplusplus > 201703L
+            std::cout<<((a <=> b) < 0)<<((a <=> b) <= 0)<<((a <=> b) > 0)<<((a <=> b) >= 0);
+#endif
+            std::cout<<"\n";
+        }}
+    }}
+}}
+
+int main() {{
+    compare_all(std::vector<t::Sample>{{
+        {}
+    }});
+    compare_all(std::vector<t::Reordered>{{
+        {}
+    }});
+    return 0;
+}}
+"#,
+        samples.iter().map(sample_to_cpp).collect::<Vec<_>>().join(",\n        "),
+        reordered.iter().map(reordered_to_cpp).collect::<Vec<_>>().join(",\n        "),
+    )
+}
+
+#[test]
+fn test_ordering_matches_rust() {
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+
+    let mut rng = Rng::new(0x5eed_0bde);
+    let samples: Vec<_> = (0..48).map(|_| random_sample(&mut rng)).collect();
+    let reordered = vec![Reordered::A, Reordered::B, Reordered::C];
+
+    for (backend, args, stds) in [
+        ("union", vec![], ["c++11", "c++20"]),
+        (
+            "variant",
+            vec!["--cpp-std", "17", "--backend", "variant"],
+            ["c++17", "c++20"],
+        ),
+    ] {
+        let ws = Workspace::new(&format!("ordering-{}", backend));
+        let input = ws.write("ordering.rs", include_str!("fixtures/ordering.rs"));
+        ws.generate(&input, "t", &args);
+        let main = ws.write("main.cpp", &cpp_main(&samples, &reordered));
+
+        let mut sources = ws.generated_sources();
+        sources.push(main);
+
+        for std in stds {
+            let three_way = std == "c++20";
+            let exe = ws.compile(&cxx, std, &sources, &[]);
+            let output = ws.run(&exe);
+
+            let expected = expected(&samples, three_way) + &expected(&reordered, three_way);
+            for (i, (line, expected_line)) in output.lines().zip(expected.lines()).enumerate() {
+                assert_eq!(
+                    line, expected_line,
+                    "{} {}: mismatch at pair {}",
+                    backend, std, i
+                );
+            }
+            assert_eq!(output.lines().count(), expected.lines().count());
+        }
+    }
+}
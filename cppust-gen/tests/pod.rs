@@ -0,0 +1,135 @@
+//! Runtime test: enums of trivially copyable payloads generate trivially copyable classes usable
+//! in constant expressions with both backends, including without exceptions.
+
+mod common;
+
+use common::Cfg;
+
+const INPUT: &str = r#"
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Hash, Default)]
+enum Color {
+    #[default]
+    Black,
+    Rgb([u8; 3]),
+    Gray(u8),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+enum Command {
+    Stop,
+    Speed(i32),
+    Paint(Color),
+    Matrix([[i16; 2]; 2]),
+    Flag(bool),
+}
+
+#[derive(Clone, Debug)]
+enum Mixed {
+    Name(String),
+    Speed(i32),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/command.hpp"
+#include "t/mixed.hpp"
+
+#include <cstdio>
+#include <cstring>
+#include <sstream>
+#include <type_traits>
+
+static_assert(std::is_trivially_copyable<t::Color>::value, "Color");
+static_assert(std::is_trivially_copyable<t::Command>::value, "Command");
+static_assert(!std::is_trivially_copyable<t::Mixed>::value, "Mixed");
+
+constexpr t::Command kTable[] = {
+    t::Command::Stop(),
+    t::Command::Speed(-3),
+    t::Command::Paint(t::Color::Gray(9)),
+    t::Command::Flag(true),
+};
+static_assert(kTable[1].is_speed(), "is");
+static_assert(kTable[1].speed_ref() == -3, "ref");
+static_assert(kTable[2].paint_ref().gray_ref_uncheck() == 9, "nested");
+static_assert(kTable[0].flag_ptr() == nullptr && *kTable[3].flag_ptr(), "ptr");
+static_assert(kTable[3].expect_flag("flag"), "expect");
+static_assert(!kTable[0].valueless_by_exception(), "valueless");
+static_assert(t::Color().is_black(), "default");
+
+int main() {
+    t::Command copy = t::Command::Stop();
+    std::memcpy(&copy, &kTable[2], sizeof(copy));
+    std::ostringstream os;
+    os<<cppust::as_debug(copy)<<" "<<(copy == kTable[2]);
+    os<<" "<<cppust::as_debug(t::Command::Paint(t::Color::Rgb({{1, 2, 255}})));
+
+    t::Command a = t::Command::Speed(1), b = t::Command::Stop();
+    swap(a, b);
+    a.emplace_matrix(std::array<std::array<cppust::i16, 2>, 2>{{{{1, 2}}, {{3, 4}}}});
+    os<<" "<<cppust::as_debug(a)<<" "<<cppust::as_debug(b)<<" "<<(a < b);
+
+    try {
+        kTable[0].speed_ref();
+    } catch (const std::runtime_error& e) {
+        os<<" "<<e.what();
+    }
+    printf("%s\n", os.str().c_str());
+    return 0;
+}
+"#;
+
+const EXPECTED: &str =
+    "Paint(Gray(\t)) 1 Paint(Rgb(<01 02 ff>)) Matrix(<<1,2>,<3,4>>) Speed(1) 0 requires Speed\n";
+
+const EMBEDDED_MAIN: &str = r#"#include "t/command.hpp"
+
+#include <cstdio>
+#include <cstdlib>
+
+void on_panic(const char* msg) {
+    printf("panic: %s\n", msg);
+    exit(0);
+}
+
+constexpr t::Command kSpeed = t::Command::Speed(7);
+static_assert(kSpeed.expect_speed("speed") == 7, "expect");
+
+int main() {
+    t::Command::Stop().expect_flag("expected a flag");
+    return 1;
+}
+"#;
+
+#[test]
+fn test_pod() {
+    for (std, cfg) in [
+        ("union-c++11", Cfg::UNION),
+        (
+            "union-c++14",
+            Cfg {
+                args: &["--cpp-std", "14"],
+                std: "c++14",
+                ..Cfg::UNION
+            },
+        ),
+        ("variant-c++17", Cfg::VARIANT),
+    ] {
+        common::compile_and_run(&format!("pod-{}", std), INPUT, MAIN, &cfg, EXPECTED);
+    }
+}
+
+#[test]
+fn test_pod_no_exceptions() {
+    let cfg = Cfg {
+        args: &["--no-exceptions", "--no-iostream"],
+        cxx_args: &["-fno-exceptions", "-DCPPUST_PANIC_HANDLER=on_panic"],
+        ..Cfg::UNION
+    };
+    common::compile_and_run(
+        "pod-embedded",
+        INPUT,
+        EMBEDDED_MAIN,
+        &cfg,
+        "panic: expected a flag\n",
+    );
+}
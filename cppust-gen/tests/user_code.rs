@@ -0,0 +1,104 @@
+//! Compile test: with `--user-regions` the code in the `BEGIN USER CODE` regions of generated
+//! headers and sources survives regenerating from a changed input.
+
+mod common;
+
+use std::{fs, path::Path};
+
+use common::{Cfg, Workspace};
+
+const INPUT: &str = r#"
+#[derive(Clone, Debug)]
+enum Status {
+    Idle,
+    Busy(u8),
+}
+"#;
+
+const CHANGED_INPUT: &str = r#"
+#[derive(Clone, Debug)]
+enum Status {
+    Idle,
+    Busy(u8),
+    Failed(String),
+}
+"#;
+
+const MAIN: &str = r#"#include "t/status.hpp"
+
+int main() {
+    std::cout<<cppust::as_debug(t::Status::Failed("disk"))<<" "<<t::Status::Busy(3).load()
+        <<" "<<t::describe(t::Status::Idle())<<"\n";
+    return 0;
+}
+"#;
+
+/// Fills the region `id` of the file at `path` with `code`
+fn fill_region(path: &Path, id: &str, code: &str) {
+    let content = fs::read_to_string(path).unwrap();
+    let begin = format!("// BEGIN USER CODE {}\n", id);
+    let pos = content.find(&begin).unwrap() + begin.len();
+    fs::write(
+        path,
+        format!("{}{}{}", &content[..pos], code, &content[pos..]),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_user_regions() {
+    for (mode, header_only) in [("source", false), ("header-only", true)] {
+        let ws = Workspace::new(&format!("user-code-{}", mode));
+        let input = ws.write("status.rs", INPUT);
+        let generate = |input: &Path| {
+            if header_only {
+                ws.generate_header_only(input, "t", &["--user-regions"]);
+            } else {
+                ws.generate(input, "t", &["--user-regions"]);
+            }
+        };
+        generate(&input);
+
+        assert!(!ws.path("include/t/status.inc.hpp").exists(), "{}", mode);
+        assert!(!ws.path("include/t/status.fmt.hpp").exists(), "{}", mode);
+
+        let header = ws.path("include/t/status.hpp");
+        let definitions = if header_only {
+            ws.path("include/t/status.impl.hpp")
+        } else {
+            ws.path("src/status.gen.cpp")
+        };
+        let inline = if header_only { "inline " } else { "" };
+        fill_region(&header, "includes", "#include <vector>\n");
+        fill_region(&header, "members", "    int load() const;\n");
+        fill_region(
+            &header,
+            "declarations",
+            &format!("{}const char* describe(const Status&);\n", inline),
+        );
+        fill_region(
+            &definitions,
+            "definitions",
+            &format!(
+                "{0}int Status::load() const {{ return is_busy() ? busy_ref() : 0; }}\n\
+                 {0}const char* describe(const Status& s) {{ return s.is_idle() ? \"idle\" : \"?\"; }}\n",
+                inline
+            ),
+        );
+
+        let input = ws.write("status.rs", CHANGED_INPUT);
+        generate(&input);
+
+        let header = fs::read_to_string(&header).unwrap();
+        assert!(
+            header.contains("// BEGIN USER CODE includes\n#include <vector>\n// END USER CODE\n")
+        );
+        assert!(header.contains("    // BEGIN USER CODE members\n    int load() const;\n"));
+        assert!(header.contains("is_failed"), "{}", mode);
+        assert!(fs::read_to_string(&definitions)
+            .unwrap()
+            .contains("int Status::load() const"));
+
+        ws.compile_and_run(MAIN, &Cfg::UNION, "Failed(disk) 3 idle\n");
+    }
+}
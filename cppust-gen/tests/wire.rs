@@ -0,0 +1,168 @@
+//! Round-trip test: the Rust counterparts generated with `--rust` write samples of each variant,
+//! which the generated C++ test decodes and encodes back to the same bytes.
+
+mod common;
+
+use std::{fs, path::PathBuf, process::Command};
+
+use common::Workspace;
+
+const INPUT: &str = r#"
+type Res<T> = Result<T, Fault>;
+
+/// State of the decoder
+#[derive(Clone, Debug)]
+enum DecoderStatus {
+    Idle,
+    Done(Frame),
+    Failed(String),
+    Skipped(u16, char),
+    Batch(Vec<Frame>),
+    Poll(Res<Option<i64>>),
+}
+
+#[derive(Clone, Debug)]
+#[cppust(namespace = "t::frames", rename = "Sentence")]
+enum Frame {
+    Nested(Vec<Frame>),
+    Gga(f64),
+    #[cppust(rename = "Flagged")]
+    Flag(bool, usize),
+}
+
+#[derive(Clone, Debug)]
+enum Fault {
+    Timeout,
+    Code(i8),
+}
+"#;
+
+const EXPECTED: &str = "\
+DecoderStatus::Idle ok
+DecoderStatus::Done ok
+DecoderStatus::Failed ok
+DecoderStatus::Skipped ok
+DecoderStatus::Batch ok
+DecoderStatus::Poll ok
+Frame::Nested ok
+Frame::Gga ok
+Frame::Flag ok
+Fault::Timeout ok
+Fault::Code ok
+";
+
+/// Builds and runs the sample writer, returning the path of the samples
+fn write_samples(ws: &Workspace) -> Option<PathBuf> {
+    let rustc = common::rustc()?;
+
+    let exe = ws.path("nmea_samples");
+    let output = Command::new(rustc)
+        .args(["--edition", "2021", "-D", "warnings", "-o"])
+        .arg(&exe)
+        .arg(ws.path("rust/nmea_samples.rs"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "rustc failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let samples = ws.path("samples.bin");
+    assert!(Command::new(exe).arg(&samples).status().unwrap().success());
+    Some(samples)
+}
+
+fn run_test(exe: &std::path::Path, samples: &std::path::Path) -> String {
+    let output = Command::new(exe).arg(samples).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_wire() {
+    let ws = Workspace::new("wire");
+    let input = ws.write("nmea.rs", INPUT);
+    let rust_dir = ws.path("rust");
+    ws.generate(&input, "t", &["--rust", rust_dir.to_str().unwrap()]);
+
+    let rust = fs::read_to_string(ws.path("rust/nmea.rs")).unwrap();
+    assert!(rust.contains(
+        "/// State of the decoder\n#[derive(Clone, Debug, PartialEq)]\npub enum DecoderStatus {\n"
+    ));
+    assert!(rust.contains("    Poll(Result<Option<i64>, Fault>),\n"));
+    // samples of recursive enums end
+    assert!(rust.contains(
+        "sample(\"Frame::Nested\", Frame::Nested(vec![Frame::Nested(Vec::new())])),"
+    ));
+
+    let header = fs::read_to_string(ws.path("include/t/nmea_wire.hpp")).unwrap();
+    assert!(header.contains("template <>\nstruct Codec<::t::frames::Sentence> {\n"));
+    assert!(header.contains(
+        "        return ::t::frames::Sentence::Flagged(std::move(_0), std::move(_1));\n"
+    ));
+
+    let samples = match write_samples(&ws) {
+        Some(samples) => samples,
+        None => return,
+    };
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+
+    let mut sources = ws.generated_sources();
+    sources.push(ws.path("rust/nmea_wire_test.cpp"));
+    let exe = ws.compile(&cxx, "c++11", &sources, &[]);
+    assert_eq!(run_test(&exe, &samples), EXPECTED);
+
+    // `Fault::Code(-7)` turned into `Fault::Timeout` and a trailing byte
+    let mut bytes = fs::read(&samples).unwrap();
+    let index = bytes.len() - 5;
+    bytes[index] = 0;
+    fs::write(&samples, bytes).unwrap();
+    let output = Command::new(&exe).arg(&samples).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).ends_with("Fault::Code FAILED\n"));
+}
+
+#[test]
+fn test_amalgamated_wire() {
+    let ws = Workspace::new("wire-amalgamated");
+    // amalgamated types share one namespace
+    let input = ws.write("nmea.rs", &INPUT.replace("namespace = \"t::frames\", ", ""));
+    let rust_dir = ws.path("rust");
+    ws.generate_header_only(
+        &input,
+        "t",
+        &[
+            "--amalgamate",
+            "nmea",
+            "--backend",
+            "variant",
+            "--cpp-std",
+            "17",
+            "--rust",
+            rust_dir.to_str().unwrap(),
+        ],
+    );
+
+    let header = fs::read_to_string(ws.path("include/t/nmea_wire.hpp")).unwrap();
+    assert!(header.contains("#include \"t/nmea.hpp\"\n"));
+
+    let samples = match write_samples(&ws) {
+        Some(samples) => samples,
+        None => return,
+    };
+    let cxx = match common::cxx() {
+        Some(cxx) => cxx,
+        None => return,
+    };
+
+    let exe = ws.compile(&cxx, "c++17", &[ws.path("rust/nmea_wire_test.cpp")], &[]);
+    assert_eq!(run_test(&exe, &samples), EXPECTED);
+}
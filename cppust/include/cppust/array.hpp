@@ -0,0 +1,82 @@
+/**
+ * @file array.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief `[T; N]` support, as `std::array<T, N>` which is trivially copyable when `T` is
+ * @version 0.1
+ * @date 2022-04-12
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ */
+
+#pragma once
+
+#include "types.hpp"
+#include "fmt.hpp"
+#include "hash.hpp"
+#include "cmp.hpp"
+
+#include <array>
+
+namespace cppust {
+
+    template < size_t N >
+    struct debug<std::array<u8, N>> {
+        template < typename Os >
+        static Os& fmt(const std::array<u8, N>& self, Os& os) {
+            static const char digits[] = "0123456789abcdef";
+
+            os<<"<";
+            for (size_t i = 0; i < N; i++) {
+                if (i != 0) {
+                    os<<" ";
+                }
+
+                os<<digits[self[i] / 16]<<digits[self[i] % 16];
+            }
+            os<<">";
+            return os;
+        }
+    };
+
+    template < typename T, size_t N >
+    struct debug<std::array<T, N>> {
+        template < typename Os >
+        static Os& fmt(const std::array<T, N>& self, Os& os) {
+            os<<"<";
+            for (size_t i = 0; i < N; i++) {
+                if (i != 0) {
+                    os<<",";
+                }
+
+                os<<as_debug(self[i]);
+            }
+            os<<">";
+            return os;
+        }
+    };
+
+    template < typename T, size_t N >
+    struct hasher<std::array<T, N>> {
+        static usize hash(const std::array<T, N>& self) {
+            usize seed = std::hash<usize>()(N);
+            for (const auto& item : self) {
+                seed = hash_combine(seed, hash_of(item));
+            }
+            return seed;
+        }
+    };
+
+    template < typename T, size_t N >
+    struct partial_ord<std::array<T, N>> {
+        static Option<Ordering> partial_cmp(const std::array<T, N>& lhs, const std::array<T, N>& rhs) {
+            for (size_t i = 0; i < N; i++) {
+                auto ord = cppust::partial_cmp(lhs[i], rhs[i]);
+                if (ord != Option<Ordering>::Some(Ordering::Equal)) {
+                    return ord;
+                }
+            }
+            return Option<Ordering>::Some(Ordering::Equal);
+        }
+    };
+
+}
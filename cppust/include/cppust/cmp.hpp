@@ -0,0 +1,132 @@
+/**
+ * @file cmp.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief `std::cmp::PartialOrd` port, used by generated comparison operators
+ * @version 0.1
+ * @date 2022-04-12
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ */
+
+#pragma once
+
+#include "types.hpp"
+#include "option.hpp"
+
+#include <tuple>
+
+#if __cplusplus > 201703L
+#include <compare>
+#endif
+
+namespace cppust {
+
+    enum class Ordering {
+        Less = -1,
+        Equal = 0,
+        Greater = 1,
+    };
+
+    template < typename T >
+    struct partial_ord {
+        static Option<Ordering> partial_cmp(const T& lhs, const T& rhs) {
+            if (lhs < rhs) {
+                return Option<Ordering>::Some(Ordering::Less);
+            } else if (rhs < lhs) {
+                return Option<Ordering>::Some(Ordering::Greater);
+            } else if (lhs == rhs) {
+                return Option<Ordering>::Some(Ordering::Equal);
+            } else {
+                return Option<Ordering>::None();
+            }
+        }
+    };
+
+    template < typename T >
+    Option<Ordering> partial_cmp(const T& lhs, const T& rhs) {
+        return partial_ord<T>::partial_cmp(lhs, rhs);
+    }
+
+    namespace {
+        template < size_t N, typename... Args >
+        struct partial_cmp_tuple_t {
+            static Option<Ordering> partial_cmp(const std::tuple<Args...>& lhs, const std::tuple<Args...>& rhs) {
+                auto ord = partial_cmp_tuple_t<N - 1, Args...>::partial_cmp(lhs, rhs);
+                if (ord != Option<Ordering>::Some(Ordering::Equal)) {
+                    return ord;
+                }
+                return cppust::partial_cmp(std::get<N - 1>(lhs), std::get<N - 1>(rhs));
+            }
+        };
+
+        template < typename... Args >
+        struct partial_cmp_tuple_t<0, Args...> {
+            static Option<Ordering> partial_cmp(const std::tuple<Args...>&, const std::tuple<Args...>&) {
+                return Option<Ordering>::Some(Ordering::Equal);
+            }
+        };
+    }
+
+    template < typename... Args >
+    struct partial_ord<std::tuple<Args...>> {
+        static Option<Ordering> partial_cmp(const std::tuple<Args...>& lhs, const std::tuple<Args...>& rhs) {
+            return partial_cmp_tuple_t<sizeof...(Args), Args...>::partial_cmp(lhs, rhs);
+        }
+    };
+
+    template < typename T >
+    struct partial_ord<Option<T>> {
+        static Option<Ordering> partial_cmp(const Option<T>& lhs, const Option<T>& rhs) {
+            if (lhs.is_some() && rhs.is_some()) {
+                return cppust::partial_cmp(lhs.unwrap_ref(), rhs.unwrap_ref());
+            }
+            return cppust::partial_cmp(lhs.is_some(), rhs.is_some());
+        }
+    };
+
+    // declared only, `result.hpp` pulls in <iostream>
+    template < typename T, typename E >
+    class Result;
+
+    // `Ok` is less than `Err`, as the variants are declared in this order
+    template < typename T, typename E >
+    struct partial_ord<Result<T, E>> {
+        static Option<Ordering> partial_cmp(const Result<T, E>& lhs, const Result<T, E>& rhs) {
+            if (lhs.is_ok() && rhs.is_ok()) {
+                return cppust::partial_cmp(lhs.ok_ref(), rhs.ok_ref());
+            }
+            if (lhs.is_err() && rhs.is_err()) {
+                return cppust::partial_cmp(lhs.err_ref(), rhs.err_ref());
+            }
+            return cppust::partial_cmp(lhs.is_err(), rhs.is_err());
+        }
+    };
+
+#if __cplusplus > 201703L
+    inline std::partial_ordering to_partial_ordering(const Option<Ordering>& ord) {
+        if (ord.is_none()) {
+            return std::partial_ordering::unordered;
+        }
+        switch (ord.unwrap_ref()) {
+            case Ordering::Less:
+                return std::partial_ordering::less;
+            case Ordering::Greater:
+                return std::partial_ordering::greater;
+            default:
+                return std::partial_ordering::equivalent;
+        }
+    }
+
+    inline std::strong_ordering to_strong_ordering(const Option<Ordering>& ord) {
+        switch (ord.expect_ref("Ord requires a total order")) {
+            case Ordering::Less:
+                return std::strong_ordering::less;
+            case Ordering::Greater:
+                return std::strong_ordering::greater;
+            default:
+                return std::strong_ordering::equal;
+        }
+    }
+#endif
+
+}
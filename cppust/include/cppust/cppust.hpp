@@ -14,9 +14,19 @@
 #   error C++ 11 and above required
 #endif
 
+#include "panic.hpp"
 #include "fmt.hpp"
+#include "write.hpp"
 #include "utils.hpp"
 #include "result.hpp"
 #include "option.hpp"
+#include "hash.hpp"
+#include "cmp.hpp"
+#include "vec.hpp"
+#include "array.hpp"
 #include "types.hpp"
 #include "markers.hpp"
+#include "ffi.hpp"
+#include "wire.hpp"
+
+#include <iostream>
@@ -0,0 +1,33 @@
+/**
+ * @file ffi.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief C layouts shared with the `#[repr(C)]` Rust mirrors generated by `cppust-gen --ffi`
+ * @version 0.1
+ * @date 2022-05-02
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ */
+
+#pragma once
+
+#include "types.hpp"
+
+#include <string>
+
+namespace cppust {
+namespace ffi {
+
+    /// A Rust `String` handed over the FFI boundary, its buffer is owned by the Rust allocator
+    struct String {
+        u8* ptr;
+        usize len;
+        usize cap;
+    };
+
+    /// Copies the bytes of `s` into a `std::string`, leaving `s` untouched
+    inline std::string to_string(const String& s) {
+        return std::string(reinterpret_cast<const char*>(s.ptr), s.len);
+    }
+
+}
+}
@@ -12,16 +12,26 @@
 
 #include "types.hpp"
 
-#include <iostream>
-#include <vector>
+/**
+ * `<iostream>` stays included for existing users; define `CPPUST_NO_IOSTREAM` to leave it out,
+ * as the headers generated by `cppust-gen --no-iostream` do.
+ */
+#ifndef CPPUST_NO_IOSTREAM
+#   include <iostream>
+#endif
 #include <tuple>
 
 namespace cppust {
 
+    /**
+     * `Os` is a `std::ostream` or a `cppust::Writer`, the generated code never includes `<iostream>` by itself
+     */
     template < typename T >
     struct debug {
-        static std::ostream& fmt(const T& self, std::ostream& os) {
-            return os << self;
+        template < typename Os >
+        static Os& fmt(const T& self, Os& os) {
+            os << self;
+            return os;
         }
     };
 
@@ -39,63 +49,40 @@ namespace cppust {
         return as_debug_t<T>(to_debug);
     }
 
-    template < typename T >
-    std::ostream& operator<<(std::ostream& os, const as_debug_t<T>& self) {
+    template < typename Os, typename T >
+    Os& operator<<(Os& os, const as_debug_t<T>& self) {
         return debug<T>::fmt(self.to_debug, os);
     }
 
-    template <>
-    struct debug<std::vector<u8>> {
-        static std::ostream& fmt(const std::vector<u8>& self, std::ostream& os) {
-            os<<"<";
-            for (size_t i = 0; i < self.size(); i++) {
-                if (i != 0) {
-                    os<<" ";
-                }
-
-                os<<std::hex<<(int)self[i] / 16<<std::hex<<(int)self[i] % 16;
+    namespace {
+        template < size_t N, typename... Args >
+        struct foreach_fmt_t {
+            template < typename Os >
+            static Os& fmt(const std::tuple<Args...>& self, Os& os) {
+                foreach_fmt_t<N-1, Args...>::fmt(self, os)<<","<<as_debug(std::get<N>(self));
+                return os;
             }
-            return os<<">";
-        }
-    };
-
-    template <typename T>
-    struct debug<std::vector<T>> {
-        static std::ostream& fmt(const std::vector<T>& self, std::ostream& os) {
-            os<<"<";
-            for (size_t i = 0; i < self.size(); i++) {
-                if (i != 0) {
-                    os<<",";
-                }
+        };
 
-                os<<as_debug(self[i]);
+        template < typename... Args >
+        struct foreach_fmt_t<0, Args...> {
+            template < typename Os >
+            static Os& fmt(const std::tuple<Args...>& self, Os& os) {
+                os<<as_debug(std::get<0>(self));
+                return os;
             }
-            return os<<">";
-        }
-    };
+        };
+    }
 
     template <typename...Args>
     struct debug<std::tuple<Args...>> {
-        static std::ostream& fmt(const std::tuple<Args...>& self, std::ostream& os) {
+        template < typename Os >
+        static Os& fmt(const std::tuple<Args...>& self, Os& os) {
             os<<"(";
-            foreach_fmt<sizeof...(Args) - 1>::fmt(self, os);
-            return os<<")";
+            foreach_fmt_t<sizeof...(Args) - 1, Args...>::fmt(self, os);
+            os<<")";
+            return os;
         }
-
-    private:
-        template < size_t N >
-        struct foreach_fmt {
-            static std::ostream& fmt(const std::tuple<Args...>& self, std::ostream& os) {
-                return foreach_fmt<N-1>::fmt(self, os)<<","<<as_debug(std::get<N>(self));
-            }
-        };
-
-        template <>
-        struct foreach_fmt<0> {
-            static std::ostream& fmt(const std::tuple<Args...>& self, std::ostream& os) {
-                return os<<as_debug(std::get<0>(self));
-            }
-        };
     };
 
 }
@@ -0,0 +1,84 @@
+/**
+ * @file hash.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief `std::hash::Hash` port, used by generated `std::hash` specializations
+ * @version 0.1
+ * @date 2022-04-10
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ */
+
+#pragma once
+
+#include "types.hpp"
+#include "option.hpp"
+
+#include <functional>
+#include <tuple>
+
+namespace cppust {
+
+    inline usize hash_combine(usize seed, usize value) {
+        return seed ^ (value + 0x9e3779b9 + (seed << 6) + (seed >> 2));
+    }
+
+    template < typename T >
+    struct hasher {
+        static usize hash(const T& self) {
+            return std::hash<T>()(self);
+        }
+    };
+
+    template < typename T >
+    usize hash_of(const T& self) {
+        return hasher<T>::hash(self);
+    }
+
+    namespace {
+        template < size_t N, typename... Args >
+        struct hash_tuple_t {
+            static usize hash(const std::tuple<Args...>& self) {
+                return hash_combine(hash_tuple_t<N - 1, Args...>::hash(self), hash_of(std::get<N - 1>(self)));
+            }
+        };
+
+        template < typename... Args >
+        struct hash_tuple_t<0, Args...> {
+            static usize hash(const std::tuple<Args...>&) {
+                return std::hash<usize>()(sizeof...(Args));
+            }
+        };
+    }
+
+    template < typename... Args >
+    struct hasher<std::tuple<Args...>> {
+        static usize hash(const std::tuple<Args...>& self) {
+            return hash_tuple_t<sizeof...(Args), Args...>::hash(self);
+        }
+    };
+
+    template < typename T >
+    struct hasher<Option<T>> {
+        static usize hash(const Option<T>& self) {
+            if (self.is_none()) {
+                return std::hash<int>()(0);
+            }
+            return hash_combine(std::hash<int>()(1), hash_of(self.unwrap_ref()));
+        }
+    };
+
+    // declared only, `result.hpp` pulls in <iostream>
+    template < typename T, typename E >
+    class Result;
+
+    template < typename T, typename E >
+    struct hasher<Result<T, E>> {
+        static usize hash(const Result<T, E>& self) {
+            if (self.is_ok()) {
+                return hash_combine(std::hash<int>()(0), hash_of(self.ok_ref()));
+            }
+            return hash_combine(std::hash<int>()(1), hash_of(self.err_ref()));
+        }
+    };
+
+}
@@ -40,7 +40,7 @@ namespace cppust {
     template < typename T >
     inline constexpr bool is_send_v = is_send<T>::value;
 
-    tempalte < typename T >
+    template < typename T >
     inline constexpr bool is_sync_v = is_sync<T>::value;
 #endif
 
@@ -15,7 +15,10 @@
 
 #include <optional>
 #include <functional>
-#include <iostream>
+#include <iosfwd>
+#ifndef CPPUST_NO_IOSTREAM
+#   include <iostream>
+#endif
 
 namespace cppust {
 
@@ -41,7 +44,8 @@ namespace cppust {
     
     public:
         Option(const Option& rhs): Option(rhs.tag_, rhs.union_) {}
-        Option(Option&& rhs): Option(rhs.tag_, std::move(rhs.union_)) {}
+        Option(Option&& rhs) noexcept(utils::is_nothrow_move_constructible_union<T>())
+            : Option(rhs.tag_, std::move(rhs.union_)) {}
         
         Option& operator=(const Option& rhs) {
             if (this == &rhs) { return *this; }
@@ -63,7 +67,7 @@ namespace cppust {
             return *this;
         }
         
-        Option& operator=(Option&& rhs) {
+        Option& operator=(Option&& rhs) noexcept(utils::is_nothrow_move_assignable_union<T>()) {
             if (this == &rhs) { return *this; }
 
             if (tag_ == rhs.tag_) {
@@ -106,26 +110,23 @@ namespace cppust {
 
         T expect(const char* msg) const {
             if (tag_ == _Tag::None) {
-                throw std::runtime_error(msg);
-            } else {
-                return union_.some_val;
+                CPPUST_PANIC(msg);
             }
+            return union_.some_val;
         }
 
         const T& expect_ref(const char* msg) const {
             if (tag_ == _Tag::None) {
-                throw std::runtime_error(msg);
-            } else {
-                return union_.some_val;
+                CPPUST_PANIC(msg);
             }
+            return union_.some_val;
         }
 
         T& expect_ref(const char* msg) {
             if (tag_ == _Tag::None) {
-                throw std::runtime_error(msg);
-            } else {
-                return union_.some_val;
+                CPPUST_PANIC(msg);
             }
+            return union_.some_val;
         }
 
         T unwrap() const {
@@ -158,9 +159,6 @@ namespace cppust {
         }
     
     public:
-        template < typename U >
-        friend std::ostream& operator<<(std::ostream& os, const Option<U>& option);
-
         bool operator==(const Option<T>& rhs) const {
             if (this == &rhs) return true;
 
@@ -226,15 +224,21 @@ namespace cppust {
     };
 
     template < typename T >
-    std::ostream& operator<<(std::ostream& os, const cppust::Option<T>& option) {
-        switch (option.tag_) {
-            case cppust::Option<T>::_Tag::None:
-                return os<<"None";
-            case cppust::Option<T>::_Tag::Some:
-                return os<<"Some("<<option.union_.some_val<<")";
-            default:
-                return os<<"<?>("<<int(option.tag_)<<")";
+    struct debug<Option<T>> {
+        template < typename Os >
+        static Os& fmt(const Option<T>& self, Os& os) {
+            if (self.is_none()) {
+                os<<"None";
+            } else {
+                os<<"Some("<<as_debug(self.unwrap_ref())<<")";
+            }
+            return os;
         }
+    };
+
+    template < typename T >
+    std::ostream& operator<<(std::ostream& os, const cppust::Option<T>& option) {
+        return debug<Option<T>>::fmt(option, os);
     }
 
 }
@@ -0,0 +1,39 @@
+/**
+ * @file panic.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief `panic!()` port, the hook called on unrecoverable errors like unwrapping `None`
+ * @version 0.1
+ * @date 2022-04-12
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ */
+
+#pragma once
+
+/**
+ * Panics can be hooked on targets built with `-fno-exceptions`, consistently in every
+ * translation unit, either by defining `CPPUST_PANIC_HANDLER` to the name of a
+ * `[[noreturn]] void handler(const char* msg)` function, or by defining `CPPUST_PANIC(msg)`.
+ * By default it throws `std::runtime_error` when exceptions are enabled and aborts otherwise.
+ */
+#ifndef CPPUST_PANIC
+#   if defined(CPPUST_PANIC_HANDLER)
+#       define CPPUST_PANIC(msg) ::CPPUST_PANIC_HANDLER(msg)
+
+[[noreturn]] void CPPUST_PANIC_HANDLER(const char* msg);
+#   elif defined(__cpp_exceptions) || defined(__EXCEPTIONS)
+#       include <stdexcept>
+#       define CPPUST_PANIC(msg) throw std::runtime_error(msg)
+#   else
+#       include <cstdlib>
+#       define CPPUST_PANIC(msg) ::cppust::panic_abort(msg)
+
+namespace cppust {
+
+    [[noreturn]] inline void panic_abort(const char*) {
+        std::abort();
+    }
+
+}
+#   endif
+#endif
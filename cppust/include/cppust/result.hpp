@@ -15,6 +15,10 @@
 
 #include <optional>
 #include <functional>
+#include <iosfwd>
+#ifndef CPPUST_NO_IOSTREAM
+#   include <iostream>
+#endif
 
 namespace cppust {
 
@@ -171,9 +175,6 @@ namespace cppust {
         }
     
     public:
-        template < typename U, typename V >
-        friend std::ostream& operator<<(std::ostream& os, const Result<U, V>&);
-
         bool operator==(const Result<T, E>& rhs) const {
             if (this == &rhs) return true;
 
@@ -197,15 +198,21 @@ namespace cppust {
     };
 
     template < typename T, typename E >
-    std::ostream& operator<<(std::ostream& os, const Result<T, E>& result) {
-        switch (result.tag_) {
-            case Result<T, E>::_Tag::Ok:
-                return os<<"Ok("<<result.union_.ok_val<<")";
-            case Result<T, E>::_Tag::Err:
-                return os<<"Err("<<result.union_.err_val<<")";
-            default:
-                return os<<"<?>("<<int(result.tag_)<<")";
+    struct debug<Result<T, E>> {
+        template < typename Os >
+        static Os& fmt(const Result<T, E>& self, Os& os) {
+            if (self.is_ok()) {
+                os<<"Ok("<<as_debug(self.ok_ref())<<")";
+            } else {
+                os<<"Err("<<as_debug(self.err_ref())<<")";
+            }
+            return os;
         }
+    };
+
+    template < typename T, typename E >
+    std::ostream& operator<<(std::ostream& os, const Result<T, E>& result) {
+        return debug<Result<T, E>>::fmt(result, os);
     }
 
 }
@@ -11,11 +11,9 @@
 #pragma once
 
 #include <stddef.h>
-#include <unistd.h>
 
+#include <cstddef>
 #include <cstdint>
-#include <vector>
-#include <tuple>
 
 namespace cppust {
 
@@ -33,6 +31,10 @@ namespace cppust {
     using f64 = double;
 
     using usize = size_t;
-    using isize = ssize_t;
+    using isize = std::ptrdiff_t;
+
+    // named for pseudo-destructor calls, which keywords cannot name
+    using bool_ = bool;
+    using char_ = char32_t;
 
 }
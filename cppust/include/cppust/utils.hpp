@@ -10,8 +10,13 @@
 
 #pragma once
 
+#include "panic.hpp"
+
 #include <stddef.h>
 
+#include <cassert>
+#include <type_traits>
+
 namespace cppust { namespace utils {
 
     namespace {
@@ -35,6 +40,44 @@ namespace cppust { namespace utils {
         struct align_of_union_value_t<T> {
             static constexpr const size_t value = alignof(T);
         };
+
+        template < typename... Types >
+        struct nothrow_move_t;
+
+        template < typename T, typename... TRest >
+        struct nothrow_move_t<T, TRest...> {
+            static constexpr const bool constructible =
+                std::is_nothrow_move_constructible<T>::value && nothrow_move_t<TRest...>::constructible;
+            static constexpr const bool assignable =
+                std::is_nothrow_move_assignable<T>::value && nothrow_move_t<TRest...>::assignable;
+        };
+
+        template <>
+        struct nothrow_move_t<> {
+            static constexpr const bool constructible = true;
+            static constexpr const bool assignable = true;
+        };
+
+        constexpr size_t round_up(size_t size, size_t align) {
+            return (size + align - 1) / align * align;
+        }
+
+        constexpr size_t max_of(size_t a, size_t b) {
+            return a > b ? a : b;
+        }
+
+        template < typename... Types >
+        struct size_of_union_value_t;
+
+        template < typename T, typename... TRest >
+        struct size_of_union_value_t<T, TRest...> {
+            static constexpr const size_t value = max_of(sizeof(T), size_of_union_value_t<TRest...>::value);
+        };
+
+        template <>
+        struct size_of_union_value_t<> {
+            static constexpr const size_t value = 0;
+        };
     }
 
     template < typename... Types >
@@ -42,6 +85,31 @@ namespace cppust { namespace utils {
         return align_of_union_value_t<Types...>::value;
     }
 
+    // whether moving a tagged union of `Types` into a new one never throws
+    template < typename... Types >
+    constexpr bool is_nothrow_move_constructible_union() {
+        return nothrow_move_t<Types...>::constructible;
+    }
+
+    // whether move-assigning a tagged union of `Types` never throws,
+    // which may either move-assign or move-construct the value
+    template < typename... Types >
+    constexpr bool is_nothrow_move_assignable_union() {
+        return nothrow_move_t<Types...>::constructible && nothrow_move_t<Types...>::assignable;
+    }
+
+    // layout of a `#[repr(C, Tag)]` Rust enum with the payloads `Types` (RFC 2195):
+    // the tag, then a union of the payloads
+    template < typename Tag, typename... Types >
+    struct repr_c_layout {
+        static constexpr const size_t payload_align = align_of_union_value_t<Types...>::value;
+        static constexpr const size_t payload_offset = round_up(sizeof(Tag), payload_align);
+        static constexpr const size_t payload_size =
+            round_up(size_of_union_value_t<Types...>::value, payload_align);
+        static constexpr const size_t align = max_of(alignof(Tag), payload_align);
+        static constexpr const size_t size = round_up(payload_offset + payload_size, align);
+    };
+
 } }
 
 #define CPPUST_DEFINE_REF_UNCHECK_CONST(name, tag, type) \
@@ -62,13 +130,13 @@ namespace cppust { namespace utils {
 
 #define CPPUST_DEFINE_REF_CONST(name, tag, type) \
     const type& name ## _ref() const { \
-        if (tag_ != _Tag::tag) { throw std::runtime_error("tag is not " #tag); } \
+        if (tag_ != _Tag::tag) { CPPUST_PANIC("tag is not " #tag); } \
         return union_.name ## _val; \
     }
 
 #define CPPUST_DEFINE_REF_MUT(name, tag, type) \
     type& name ## _ref() { \
-        if (tag_ != _Tag::tag) { throw std::runtime_error("tag is not " #tag); } \
+        if (tag_ != _Tag::tag) { CPPUST_PANIC("tag is not " #tag); } \
         return union_.name ## _val; \
     }
 
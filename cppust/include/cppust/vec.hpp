@@ -0,0 +1,82 @@
+/**
+ * @file vec.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief `Vec<T>` support, kept apart so `<vector>` is only included when needed
+ * @version 0.1
+ * @date 2022-04-12
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ */
+
+#pragma once
+
+#include "types.hpp"
+#include "fmt.hpp"
+#include "hash.hpp"
+#include "cmp.hpp"
+
+#include <vector>
+
+namespace cppust {
+
+    template <>
+    struct debug<std::vector<u8>> {
+        template < typename Os >
+        static Os& fmt(const std::vector<u8>& self, Os& os) {
+            static const char digits[] = "0123456789abcdef";
+
+            os<<"<";
+            for (size_t i = 0; i < self.size(); i++) {
+                if (i != 0) {
+                    os<<" ";
+                }
+
+                os<<digits[self[i] / 16]<<digits[self[i] % 16];
+            }
+            os<<">";
+            return os;
+        }
+    };
+
+    template < typename T >
+    struct debug<std::vector<T>> {
+        template < typename Os >
+        static Os& fmt(const std::vector<T>& self, Os& os) {
+            os<<"<";
+            for (size_t i = 0; i < self.size(); i++) {
+                if (i != 0) {
+                    os<<",";
+                }
+
+                os<<as_debug(self[i]);
+            }
+            os<<">";
+            return os;
+        }
+    };
+
+    template < typename T >
+    struct hasher<std::vector<T>> {
+        static usize hash(const std::vector<T>& self) {
+            usize seed = std::hash<usize>()(self.size());
+            for (const auto& item : self) {
+                seed = hash_combine(seed, hash_of(item));
+            }
+            return seed;
+        }
+    };
+
+    template < typename T >
+    struct partial_ord<std::vector<T>> {
+        static Option<Ordering> partial_cmp(const std::vector<T>& lhs, const std::vector<T>& rhs) {
+            for (size_t i = 0; i < lhs.size() && i < rhs.size(); i++) {
+                auto ord = cppust::partial_cmp(lhs[i], rhs[i]);
+                if (ord != Option<Ordering>::Some(Ordering::Equal)) {
+                    return ord;
+                }
+            }
+            return cppust::partial_cmp(lhs.size(), rhs.size());
+        }
+    };
+
+}
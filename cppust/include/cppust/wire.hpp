@@ -0,0 +1,204 @@
+/**
+ * @file wire.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief Binary encoding shared with the Rust counterparts generated by `cppust-gen --rust`
+ * @version 0.1
+ * @date 2022-05-02
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ *
+ * Numbers are little-endian with their Rust width, `usize` and `isize` have the width of the
+ * platform, `bool` is one byte and `char` is its `u32` code point. Strings and vectors are a
+ * `u64` length followed by the bytes or items, `Option` and `Result` a one byte tag (`None` and
+ * `Ok` are 0) followed by the payload, and enums the `u32` index of the variant followed by its
+ * fields.
+ */
+
+#pragma once
+
+#include "option.hpp"
+#include "panic.hpp"
+#include "result.hpp"
+#include "types.hpp"
+
+#include <cstring>
+#include <string>
+#include <type_traits>
+#include <vector>
+
+namespace cppust { namespace wire {
+
+    /**
+     * Encoded bytes being read, which are borrowed
+     */
+    class Decoder {
+    public:
+        Decoder(const u8* data, usize len): data_(data), len_(len) {}
+
+        // the next `n` bytes, panics when the input is shorter
+        const u8* take(usize n) {
+            if (n > len_) {
+                CPPUST_PANIC("unexpected end of the wire input");
+            }
+            const u8* bytes = data_;
+            data_ += n;
+            len_ -= n;
+            return bytes;
+        }
+
+        bool is_empty() const { return len_ == 0; }
+
+    private:
+        const u8* data_;
+        usize len_;
+    };
+
+    /**
+     * Encoded bytes being written
+     */
+    class Encoder {
+    public:
+        void put(const u8* bytes, usize n) {
+            bytes_.insert(bytes_.end(), bytes, bytes + n);
+        }
+
+        const std::vector<u8>& bytes() const { return bytes_; }
+
+    private:
+        std::vector<u8> bytes_;
+    };
+
+    // encodes and decodes `T`, the generated `<module>_wire.hpp` specializes it for the classes
+    template < typename T, typename Enable = void >
+    struct Codec;
+
+    template < typename T >
+    void encode(Encoder& encoder, const T& value) {
+        Codec<T>::encode(encoder, value);
+    }
+
+    template < typename T >
+    T decode(Decoder& decoder) {
+        return Codec<T>::decode(decoder);
+    }
+
+    // numbers and `char32_t`, through the unsigned integer of the same width
+    template < typename T >
+    struct Codec<T, typename std::enable_if<std::is_arithmetic<T>::value && !std::is_same<T, bool>::value>::type> {
+        static void encode(Encoder& encoder, const T& value) {
+            unsigned long long bits = 0;
+            memcpy(&bits, &value, sizeof(T));
+            u8 bytes[sizeof(T)];
+            for (usize i = 0; i < sizeof(T); ++i) {
+                bytes[i] = static_cast<u8>(bits >> (8 * i));
+            }
+            encoder.put(bytes, sizeof(T));
+        }
+
+        static T decode(Decoder& decoder) {
+            const u8* bytes = decoder.take(sizeof(T));
+            unsigned long long bits = 0;
+            for (usize i = 0; i < sizeof(T); ++i) {
+                bits |= static_cast<unsigned long long>(bytes[i]) << (8 * i);
+            }
+            T value;
+            memcpy(&value, &bits, sizeof(T));
+            return value;
+        }
+    };
+
+    template <>
+    struct Codec<bool> {
+        static void encode(Encoder& encoder, const bool& value) {
+            wire::encode<u8>(encoder, value ? 1 : 0);
+        }
+
+        static bool decode(Decoder& decoder) {
+            switch (wire::decode<u8>(decoder)) {
+            case 0:
+                return false;
+            case 1:
+                return true;
+            default:
+                CPPUST_PANIC("invalid bool on the wire");
+            }
+        }
+    };
+
+    template <>
+    struct Codec<std::string> {
+        static void encode(Encoder& encoder, const std::string& value) {
+            wire::encode<u64>(encoder, value.size());
+            encoder.put(reinterpret_cast<const u8*>(value.data()), value.size());
+        }
+
+        static std::string decode(Decoder& decoder) {
+            usize len = wire::decode<u64>(decoder);
+            return std::string(reinterpret_cast<const char*>(decoder.take(len)), len);
+        }
+    };
+
+    template < typename T >
+    struct Codec<std::vector<T>> {
+        static void encode(Encoder& encoder, const std::vector<T>& value) {
+            wire::encode<u64>(encoder, value.size());
+            for (const T& item : value) {
+                wire::encode(encoder, item);
+            }
+        }
+
+        static std::vector<T> decode(Decoder& decoder) {
+            usize len = wire::decode<u64>(decoder);
+            std::vector<T> value;
+            for (usize i = 0; i < len; ++i) {
+                value.push_back(wire::decode<T>(decoder));
+            }
+            return value;
+        }
+    };
+
+    template < typename T >
+    struct Codec<Option<T>> {
+        static void encode(Encoder& encoder, const Option<T>& value) {
+            wire::encode<u8>(encoder, value.is_some() ? 1 : 0);
+            if (value.is_some()) {
+                wire::encode(encoder, value.unwrap_ref());
+            }
+        }
+
+        static Option<T> decode(Decoder& decoder) {
+            switch (wire::decode<u8>(decoder)) {
+            case 0:
+                return Option<T>::None();
+            case 1:
+                return Option<T>::Some(wire::decode<T>(decoder));
+            default:
+                CPPUST_PANIC("invalid Option on the wire");
+            }
+        }
+    };
+
+    template < typename T, typename E >
+    struct Codec<Result<T, E>> {
+        static void encode(Encoder& encoder, const Result<T, E>& value) {
+            wire::encode<u8>(encoder, value.is_ok() ? 0 : 1);
+            if (value.is_ok()) {
+                wire::encode(encoder, value.ok_ref());
+            } else {
+                wire::encode(encoder, value.err_ref());
+            }
+        }
+
+        static Result<T, E> decode(Decoder& decoder) {
+            switch (wire::decode<u8>(decoder)) {
+            case 0:
+                return Result<T, E>::Ok(wire::decode<T>(decoder));
+            case 1:
+                return Result<T, E>::Err(wire::decode<E>(decoder));
+            default:
+                CPPUST_PANIC("invalid Result on the wire");
+            }
+        }
+    };
+
+} }
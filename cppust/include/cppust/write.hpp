@@ -0,0 +1,110 @@
+/**
+ * @file write.hpp
+ * @author Tony Huang (cnwzhjs@gmail.com)
+ * @brief `std::fmt::Write` port, a minimal output interface for targets without iostream
+ * @version 0.1
+ * @date 2022-04-12
+ *
+ * @copyright Copyright (c) 2022 Tony Huang
+ */
+
+#pragma once
+
+#include "types.hpp"
+
+#include <cstdio>
+#include <cstring>
+
+namespace cppust {
+
+    class Writer {
+    public:
+        virtual void write_str(const char* str, usize len) = 0;
+
+    protected:
+        ~Writer() {}
+    };
+
+    /**
+     * Writes into a fixed buffer, always null terminated, truncating what does not fit
+     */
+    class SliceWriter : public Writer {
+    public:
+        SliceWriter(char* buf, usize capacity): buf_(buf), capacity_(capacity), len_(0) {
+            if (capacity_ > 0) {
+                buf_[0] = '\0';
+            }
+        }
+
+        void write_str(const char* str, usize len) override {
+            if (capacity_ == 0) {
+                return;
+            }
+            usize n = capacity_ - 1 - len_ < len ? capacity_ - 1 - len_ : len;
+            memcpy(buf_ + len_, str, n);
+            len_ += n;
+            buf_[len_] = '\0';
+        }
+
+        const char* c_str() const { return buf_; }
+        usize len() const { return len_; }
+
+    private:
+        char* buf_;
+        usize capacity_;
+        usize len_;
+    };
+
+    namespace {
+        inline Writer& write_unsigned(Writer& w, unsigned long long v, bool negative) {
+            char buf[24];
+            usize i = sizeof(buf);
+            do {
+                buf[--i] = char('0' + v % 10);
+                v /= 10;
+            } while (v != 0);
+            if (negative) {
+                buf[--i] = '-';
+            }
+            w.write_str(buf + i, sizeof(buf) - i);
+            return w;
+        }
+
+        inline Writer& write_signed(Writer& w, long long v) {
+            return v < 0
+                ? write_unsigned(w, 0ull - (unsigned long long)v, true)
+                : write_unsigned(w, (unsigned long long)v, false);
+        }
+    }
+
+    inline Writer& operator<<(Writer& w, const char* str) {
+        w.write_str(str, strlen(str));
+        return w;
+    }
+
+    inline Writer& operator<<(Writer& w, char c) {
+        w.write_str(&c, 1);
+        return w;
+    }
+
+    inline Writer& operator<<(Writer& w, bool v) {
+        return w<<(v ? "true" : "false");
+    }
+
+    inline Writer& operator<<(Writer& w, int v) { return write_signed(w, v); }
+    inline Writer& operator<<(Writer& w, long v) { return write_signed(w, v); }
+    inline Writer& operator<<(Writer& w, long long v) { return write_signed(w, v); }
+    inline Writer& operator<<(Writer& w, unsigned v) { return write_unsigned(w, v, false); }
+    inline Writer& operator<<(Writer& w, unsigned long v) { return write_unsigned(w, v, false); }
+    inline Writer& operator<<(Writer& w, unsigned long long v) { return write_unsigned(w, v, false); }
+
+    inline Writer& operator<<(Writer& w, double v) {
+        char buf[32];
+        int len = snprintf(buf, sizeof(buf), "%g", v);
+        if (len > 0) {
+            w.write_str(buf, usize(len) < sizeof(buf) ? usize(len) : sizeof(buf) - 1);
+        }
+        return w;
+    }
+
+}
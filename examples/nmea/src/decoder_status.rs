@@ -1,3 +1,4 @@
+#[derive(Clone, Debug, PartialEq)]
 enum DecoderStatus {
     NeedMoreBytes,
     SentenceReady(Vec<u8>),